@@ -15,6 +15,20 @@ trait IERC20<TState> {
         ref self: TState, sender: ContractAddress, recipient: ContractAddress, amount: u256
     ) -> bool;
     fn approve(ref self: TState, spender: ContractAddress, amount: u256) -> bool;
+    // Owner
+    fn owner(self: @TState) -> ContractAddress;
+    fn mint(ref self: TState, recipient: ContractAddress, amount: u256);
+    fn burn(ref self: TState, account: ContractAddress, amount: u256);
+    // Permit
+    fn nonces(self: @TState, owner: ContractAddress) -> felt252;
+    fn permit(
+        ref self: TState,
+        owner: ContractAddress,
+        spender: ContractAddress,
+        value: u256,
+        deadline: u256,
+        signature: Array<felt252>
+    );
 }
 
 #[starknet::contract]
@@ -22,13 +36,27 @@ mod ERC20 {
     use super::IERC20;
 
     use integer::BoundedInt;
-    use starknet::ContractAddress;
-    use starknet::get_caller_address;
+    use starknet::{
+        ContractAddress, get_block_timestamp, get_caller_address, get_contract_address, get_tx_info
+    };
+
+    use yas_core::utils::typed_data::TypedData::{
+        StarknetDomain, compute_hash_on_elements, hash_u256, hash_message, is_valid_signature
+    };
+
+    // sn_keccak('Permit(spender:felt,value:u256,nonce:felt,deadline:u256,verifying_contract:felt)u256(low:felt,high:felt)')
+    const PERMIT_TYPE_HASH: felt252 =
+        0x1d9f943c233387a5fff70487d33144ed93a639fa31efbc4f693bc3b5170c999;
+    // the permit domain is the token name and this version
+    const PERMIT_VERSION: felt252 = '1';
 
     #[storage]
     struct Storage {
         ERC20_name: felt252,
         ERC20_symbol: felt252,
+        ERC20_decimals: u8,
+        ERC20_owner: ContractAddress,
+        ERC20_nonces: LegacyMap<ContractAddress, felt252>,
         ERC20_total_supply: u256,
         ERC20_balances: LegacyMap<ContractAddress, u256>,
         ERC20_allowances: LegacyMap<(ContractAddress, ContractAddress), u256>,
@@ -62,6 +90,9 @@ mod ERC20 {
         const TRANSFER_TO_ZERO: felt252 = 'ERC20: transfer to 0';
         const BURN_FROM_ZERO: felt252 = 'ERC20: burn from 0';
         const MINT_TO_ZERO: felt252 = 'ERC20: mint to 0';
+        const NOT_OWNER: felt252 = 'ERC20: caller is not the owner';
+        const PERMIT_EXPIRED: felt252 = 'ERC20: permit expired';
+        const INVALID_SIGNATURE: felt252 = 'ERC20: invalid signature';
     }
 
     #[constructor]
@@ -69,10 +100,13 @@ mod ERC20 {
         ref self: ContractState,
         name: felt252,
         symbol: felt252,
+        decimals: u8,
         initial_supply: u256,
-        recipient: ContractAddress
+        recipient: ContractAddress,
+        owner: ContractAddress
     ) {
-        self.initializer(name, symbol);
+        self.initializer(name, symbol, decimals);
+        self.ERC20_owner.write(owner);
         self._mint(recipient, initial_supply);
     }
 
@@ -91,7 +125,7 @@ mod ERC20 {
         }
 
         fn decimals(self: @ContractState) -> u8 {
-            18
+            self.ERC20_decimals.read()
         }
 
         fn totalSupply(self: @ContractState) -> u256 {
@@ -131,6 +165,67 @@ mod ERC20 {
             self._approve(caller, spender, amount);
             true
         }
+
+        fn owner(self: @ContractState) -> ContractAddress {
+            self.ERC20_owner.read()
+        }
+
+        fn mint(ref self: ContractState, recipient: ContractAddress, amount: u256) {
+            self.assert_only_owner();
+            self._mint(recipient, amount);
+        }
+
+        fn burn(ref self: ContractState, account: ContractAddress, amount: u256) {
+            self.assert_only_owner();
+            self._burn(account, amount);
+        }
+
+        fn nonces(self: @ContractState, owner: ContractAddress) -> felt252 {
+            self.ERC20_nonces.read(owner)
+        }
+
+        /// Sets `value` as the allowance of `spender` over the tokens of `owner`, with a signature
+        /// of `owner` instead of a transaction.
+        /// `owner` signs the SNIP-12 typed data
+        /// Permit(spender: felt, value: u256, nonce: felt, deadline: u256), in the domain
+        /// { name: name(), version: '1', chainId }. The signature is checked by the `owner`
+        /// account through `is_valid_signature`, and can only be used once.
+        fn permit(
+            ref self: ContractState,
+            owner: ContractAddress,
+            spender: ContractAddress,
+            value: u256,
+            deadline: u256,
+            signature: Array<felt252>
+        ) {
+            let block_timestamp: u256 = get_block_timestamp().into();
+            assert(block_timestamp <= deadline, Errors::PERMIT_EXPIRED);
+
+            let nonce = self.ERC20_nonces.read(owner);
+            self.ERC20_nonces.write(owner, nonce + 1);
+
+            let domain = StarknetDomain {
+                name: self.ERC20_name.read(),
+                version: PERMIT_VERSION,
+                chain_id: get_tx_info().unbox().chain_id
+            };
+            // tokens can share a name, bind the permit to this one
+            let struct_hash = compute_hash_on_elements(
+                array![
+                    PERMIT_TYPE_HASH,
+                    spender.into(),
+                    hash_u256(value),
+                    nonce,
+                    hash_u256(deadline),
+                    get_contract_address().into()
+                ]
+                    .span()
+            );
+            let hash = hash_message(domain, owner, struct_hash);
+            assert(is_valid_signature(owner, hash, signature), Errors::INVALID_SIGNATURE);
+
+            self._approve(owner, spender, value);
+        }
     }
 
     #[external(v0)]
@@ -167,9 +262,14 @@ mod ERC20 {
 
     #[generate_trait]
     impl InternalImpl of InternalTrait {
-        fn initializer(ref self: ContractState, name: felt252, symbol: felt252) {
+        fn initializer(ref self: ContractState, name: felt252, symbol: felt252, decimals: u8) {
             self.ERC20_name.write(name);
             self.ERC20_symbol.write(symbol);
+            self.ERC20_decimals.write(decimals);
+        }
+
+        fn assert_only_owner(self: @ContractState) {
+            assert(get_caller_address() == self.ERC20_owner.read(), Errors::NOT_OWNER);
         }
 
         fn _increase_allowance(
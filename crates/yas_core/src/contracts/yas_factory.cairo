@@ -1,4 +1,4 @@
-use starknet::ContractAddress;
+use starknet::{ClassHash, ContractAddress};
 use yas_core::numbers::signed_integer::i32::i32;
 
 /// @title The interface for the YAS Factory
@@ -6,16 +6,71 @@ use yas_core::numbers::signed_integer::i32::i32;
 #[starknet::interface]
 trait IYASFactory<TContractState> {
     /// @notice Returns the current owner of the factory
-    /// @dev Can be changed by the current owner via set_owner
+    /// @dev Can be changed via transfer_ownership + accept_ownership, or renounced
     /// @return The address of the factory owner
     fn owner(self: @TContractState) -> ContractAddress;
 
+    /// @notice Returns the address nominated to become the owner, or address 0 if there is none
+    /// @return The address of the pending owner
+    fn pending_owner(self: @TContractState) -> ContractAddress;
+
+    /// @notice Returns the address allowed to enable new fee amounts
+    /// @dev Can be changed by the owner via set_fee_amount_manager
+    /// @return The address of the fee amount manager
+    fn fee_amount_manager(self: @TContractState) -> ContractAddress;
+
+    /// @notice Returns the address allowed to set and collect the protocol fees of the pools
+    /// @dev Can be changed by the owner via set_protocol_fee_manager
+    /// @return The address of the protocol fee manager
+    fn protocol_fee_manager(self: @TContractState) -> ContractAddress;
+
     /// @notice Returns the tick spacing for a given fee amount, if enabled, or 0 if not enabled
-    /// @dev A fee amount can never be removed, so this value should be hard coded or cached in the calling context
+    /// @dev A fee amount can never be removed, so this value should be hard coded or cached in the calling context.
+    /// The fee amount manager can still change it once for fee amounts enabled in permissionless mode,
+    /// as long as no pool was created with them
     /// @param fee The enabled fee, denominated in hundredths of a bip. Returns 0 in case of unenabled fee
     /// @return The tick spacing
     fn fee_amount_tick_spacing(self: @TContractState, fee: u32) -> i32;
 
+    /// @notice Returns the address allowed to pause pools, individually or globally
+    /// @dev Can be changed by the owner via set_guardian
+    /// @return The address of the guardian
+    fn guardian(self: @TContractState) -> ContractAddress;
+
+    /// @notice Returns true if all the pools of the factory are paused
+    fn is_paused(self: @TContractState) -> bool;
+
+    /// @notice Returns true if anyone can enable a fee amount using an allowed tick spacing
+    /// @dev Can be changed by the owner via set_permissionless_fee_amounts
+    fn permissionless_fee_amounts(self: @TContractState) -> bool;
+
+    /// @notice Returns true if tick_spacing can be used to enable fee amounts in permissionless mode
+    /// @dev Can be changed by the owner via set_tick_spacing_allowed
+    /// @param tick_spacing The tick spacing to check
+    fn is_tick_spacing_allowed(self: @TContractState, tick_spacing: i32) -> bool;
+
+    /// @notice Returns the minimum liquidity a mint must add to pools of permissionless fee amounts
+    /// @dev Can be changed by the owner via set_permissionless_min_liquidity
+    fn permissionless_min_liquidity(self: @TContractState) -> u128;
+
+    /// @notice Returns the minimum liquidity a mint must add to the pools of a given fee amount
+    /// @dev Only fee amounts enabled in permissionless mode have a minimum, so that their pools
+    /// can't be spammed with dust positions initializing ticks
+    /// @param fee The fee amount, denominated in hundredths of a bip
+    /// @return The minimum liquidity, 0 for fee amounts enabled by the fee amount manager
+    fn fee_amount_min_liquidity(self: @TContractState, fee: u32) -> u128;
+
+    /// @notice Returns the class hash new pools are deployed from
+    /// @dev Can be changed by the current owner via set_pool_class_hash
+    /// @return The pool class hash
+    fn pool_class_hash(self: @TContractState) -> ClassHash;
+
+    /// @notice Returns every class hash pools have been deployed from, oldest first
+    /// @dev The last one is the current pool_class_hash. Pool addresses depend on the class hash
+    /// active when the pool was created, so off-chain address checks must try all of them
+    /// @return The pool class hashes set since the factory was deployed
+    fn pool_class_hashes(self: @TContractState) -> Array<ClassHash>;
+
     /// @notice Returns the pool address for a given pair of tokens and a fee, or address 0 if it does not exist
     /// @dev token_a and token_b may be passed in either token_0/token_1 or token_1/token_0 order
     /// @param token_a The contract address of either token0 or token1
@@ -38,13 +93,70 @@ trait IYASFactory<TContractState> {
         ref self: TContractState, token_a: ContractAddress, token_b: ContractAddress, fee: u32
     ) -> ContractAddress;
 
-    /// @notice Updates the owner of the factory
+    /// @notice Starts the transfer of the factory ownership to new_owner
+    /// @dev Must be called by the current owner. The transfer is completed when new_owner calls accept_ownership,
+    /// a later call replaces the pending owner
+    /// @param new_owner The nominated owner of the factory
+    fn transfer_ownership(ref self: TContractState, new_owner: ContractAddress);
+
+    /// @notice Completes the ownership transfer started by transfer_ownership
+    /// @dev Must be called by the pending owner
+    fn accept_ownership(ref self: TContractState);
+
+    /// @notice Leaves the factory without owner, the roles can't be reassigned afterwards
+    /// @dev Must be called by the current owner
+    fn renounce_ownership(ref self: TContractState);
+
+    /// @notice Updates the class hash new pools are deployed from
+    /// @dev Must be called by the current owner. Existing pools keep their class until upgraded
+    /// @param new_class_hash The new pool class hash
+    fn set_pool_class_hash(ref self: TContractState, new_class_hash: ClassHash);
+
+    /// @notice Updates the guardian of the factory
+    /// @dev Must be called by the current owner
+    /// @param new_guardian The new guardian
+    fn set_guardian(ref self: TContractState, new_guardian: ContractAddress);
+
+    /// @notice Pauses swaps and mints on every pool, burns and collects keep working
+    /// @dev Must be called by the guardian
+    fn pause(ref self: TContractState);
+
+    /// @notice Lifts the global pause, pools paused individually stay paused
+    /// @dev Must be called by the guardian
+    fn unpause(ref self: TContractState);
+
+    /// @notice Updates the fee amount manager of the factory
+    /// @dev Must be called by the current owner
+    /// @param new_manager The new fee amount manager
+    fn set_fee_amount_manager(ref self: TContractState, new_manager: ContractAddress);
+
+    /// @notice Updates the protocol fee manager of the factory
+    /// @dev Must be called by the current owner
+    /// @param new_manager The new protocol fee manager
+    fn set_protocol_fee_manager(ref self: TContractState, new_manager: ContractAddress);
+
+    /// @notice Turns the permissionless fee amount mode on or off
     /// @dev Must be called by the current owner
-    /// @param new_owner The new owner of the factory
-    fn set_owner(ref self: TContractState, new_owner: ContractAddress);
+    /// @param enabled Whether anyone can enable fee amounts with an allowed tick spacing
+    fn set_permissionless_fee_amounts(ref self: TContractState, enabled: bool);
+
+    /// @notice Adds or removes a tick spacing from the permissionless allowlist
+    /// @dev Must be called by the current owner. Fee amounts already enabled are not affected
+    /// @param tick_spacing The tick spacing to update
+    /// @param allowed Whether the tick spacing can be used in permissionless mode
+    fn set_tick_spacing_allowed(ref self: TContractState, tick_spacing: i32, allowed: bool);
+
+    /// @notice Updates the minimum liquidity a mint must add to pools of permissionless fee amounts
+    /// @dev Must be called by the current owner. Applies to existing pools too
+    /// @param min_liquidity The new minimum liquidity, 0 to disable the guard
+    fn set_permissionless_min_liquidity(ref self: TContractState, min_liquidity: u128);
 
     /// @notice Enables a fee amount with the given tick_spacing
-    /// @dev Fee amounts may never be removed once enabled
+    /// @dev Must be called by the fee amount manager, or by anyone if the permissionless mode is on
+    /// and tick_spacing is allowed. Fee amounts may never be removed once enabled, but the fee amount
+    /// manager can change once the tick spacing of those enabled in permissionless mode, so they
+    /// can't be squatted. The tick spacing is part of the pool address, so it is final once a pool
+    /// was created with the fee amount
     /// @param fee The fee amount to enable, denominated in hundredths of a bip (i.e. 1e-6)
     /// @param tick_spacing The spacing between ticks to be enforced for all pools created with the given fee amount
     fn enable_fee_amount(ref self: TContractState, fee: u32, tick_spacing: i32);
@@ -70,6 +182,16 @@ mod YASFactory {
     #[derive(Drop, starknet::Event)]
     enum Event {
         OwnerChanged: OwnerChanged,
+        OwnershipTransferStarted: OwnershipTransferStarted,
+        FeeAmountManagerChanged: FeeAmountManagerChanged,
+        ProtocolFeeManagerChanged: ProtocolFeeManagerChanged,
+        PoolClassHashChanged: PoolClassHashChanged,
+        GuardianChanged: GuardianChanged,
+        Paused: Paused,
+        Unpaused: Unpaused,
+        PermissionlessFeeAmountsSet: PermissionlessFeeAmountsSet,
+        TickSpacingAllowed: TickSpacingAllowed,
+        PermissionlessMinLiquiditySet: PermissionlessMinLiquiditySet,
         PoolCreated: PoolCreated,
         FeeAmountEnabled: FeeAmountEnabled
     }
@@ -83,6 +205,88 @@ mod YASFactory {
         new_owner: ContractAddress
     }
 
+    /// @notice Emitted when the owner nominates a new owner
+    /// @param previous_owner The current owner
+    /// @param new_owner The nominated owner, who has to accept the ownership
+    #[derive(Drop, starknet::Event)]
+    struct OwnershipTransferStarted {
+        previous_owner: ContractAddress,
+        new_owner: ContractAddress
+    }
+
+    /// @notice Emitted when the fee amount manager of the factory is changed
+    /// @param old_manager The fee amount manager before the change
+    /// @param new_manager The fee amount manager after the change
+    #[derive(Drop, starknet::Event)]
+    struct FeeAmountManagerChanged {
+        old_manager: ContractAddress,
+        new_manager: ContractAddress
+    }
+
+    /// @notice Emitted when the protocol fee manager of the factory is changed
+    /// @param old_manager The protocol fee manager before the change
+    /// @param new_manager The protocol fee manager after the change
+    #[derive(Drop, starknet::Event)]
+    struct ProtocolFeeManagerChanged {
+        old_manager: ContractAddress,
+        new_manager: ContractAddress
+    }
+
+    /// @notice Emitted when the class hash new pools are deployed from is changed
+    /// @param old_class_hash The pool class hash before the change
+    /// @param new_class_hash The pool class hash after the change
+    #[derive(Drop, starknet::Event)]
+    struct PoolClassHashChanged {
+        old_class_hash: ClassHash,
+        new_class_hash: ClassHash
+    }
+
+    /// @notice Emitted when the guardian of the factory is changed
+    /// @param old_guardian The guardian before the change
+    /// @param new_guardian The guardian after the change
+    #[derive(Drop, starknet::Event)]
+    struct GuardianChanged {
+        old_guardian: ContractAddress,
+        new_guardian: ContractAddress
+    }
+
+    /// @notice Emitted when the guardian pauses all the pools
+    /// @param account The guardian that paused the pools
+    #[derive(Drop, starknet::Event)]
+    struct Paused {
+        account: ContractAddress
+    }
+
+    /// @notice Emitted when the guardian lifts the global pause
+    /// @param account The guardian that unpaused the pools
+    #[derive(Drop, starknet::Event)]
+    struct Unpaused {
+        account: ContractAddress
+    }
+
+    /// @notice Emitted when the owner turns the permissionless fee amount mode on or off
+    /// @param enabled Whether anyone can enable fee amounts with an allowed tick spacing
+    #[derive(Drop, starknet::Event)]
+    struct PermissionlessFeeAmountsSet {
+        enabled: bool
+    }
+
+    /// @notice Emitted when the owner updates the permissionless tick spacing allowlist
+    /// @param tick_spacing The updated tick spacing
+    /// @param allowed Whether the tick spacing can be used in permissionless mode
+    #[derive(Drop, starknet::Event)]
+    struct TickSpacingAllowed {
+        tick_spacing: i32,
+        allowed: bool
+    }
+
+    /// @notice Emitted when the owner updates the minimum liquidity of permissionless fee amounts
+    /// @param min_liquidity The minimum liquidity a mint must add to their pools
+    #[derive(Drop, starknet::Event)]
+    struct PermissionlessMinLiquiditySet {
+        min_liquidity: u128
+    }
+
     /// @notice Emitted when a pool is created
     /// @param token_0 The first token of the pool by address sort order
     /// @param token_1 The second token of the pool by address sort order
@@ -110,9 +314,24 @@ mod YASFactory {
     #[storage]
     struct Storage {
         owner: ContractAddress,
+        pending_owner: ContractAddress,
+        fee_amount_manager: ContractAddress,
+        protocol_fee_manager: ContractAddress,
+        guardian: ContractAddress,
+        paused: bool,
+        permissionless_fee_amounts: bool,
+        // keyed by the magnitude, valid tick spacings are always positive
+        allowed_tick_spacing: LegacyMap::<u32, bool>,
+        permissionless_min_liquidity: u128,
         fee_amount_tick_spacing: LegacyMap::<u32, i32>,
+        // fee amounts enabled by anyone in permissionless mode, the fee amount manager can rebind them
+        fee_amount_permissionless: LegacyMap::<u32, bool>,
+        // the tick spacing of a fee amount with pools can't change, their addresses depend on it
+        fee_amount_has_pools: LegacyMap::<u32, bool>,
         pool: LegacyMap<(ContractAddress, ContractAddress, u32), ContractAddress>,
-        pool_class_hash: ClassHash
+        pool_class_hash: ClassHash,
+        pool_class_hashes_len: u32,
+        pool_class_hashes: LegacyMap::<u32, ClassHash>
     }
 
     #[constructor]
@@ -120,8 +339,19 @@ mod YASFactory {
         self.owner.write(owner);
         self.emit(OwnerChanged { old_owner: Zeroable::zero(), new_owner: owner });
 
+        // all roles start with the owner, who can hand them over later
+        self.fee_amount_manager.write(owner);
+        self.emit(FeeAmountManagerChanged { old_manager: Zeroable::zero(), new_manager: owner });
+        self.protocol_fee_manager.write(owner);
+        self
+            .emit(
+                ProtocolFeeManagerChanged { old_manager: Zeroable::zero(), new_manager: owner }
+            );
+        self.guardian.write(owner);
+        self.emit(GuardianChanged { old_guardian: Zeroable::zero(), new_guardian: owner });
+
         assert(pool_class_hash.is_non_zero(), 'pool class hash can not be zero');
-        self.pool_class_hash.write(pool_class_hash);
+        self.write_pool_class_hash(pool_class_hash);
 
         // value derived from a simple proportion. 
         // fee %0.01 -> tick_spacing 2
@@ -153,6 +383,64 @@ mod YASFactory {
             self.owner.read()
         }
 
+        fn pending_owner(self: @ContractState) -> ContractAddress {
+            self.pending_owner.read()
+        }
+
+        fn fee_amount_manager(self: @ContractState) -> ContractAddress {
+            self.fee_amount_manager.read()
+        }
+
+        fn protocol_fee_manager(self: @ContractState) -> ContractAddress {
+            self.protocol_fee_manager.read()
+        }
+
+        fn guardian(self: @ContractState) -> ContractAddress {
+            self.guardian.read()
+        }
+
+        fn is_paused(self: @ContractState) -> bool {
+            self.paused.read()
+        }
+
+        fn permissionless_fee_amounts(self: @ContractState) -> bool {
+            self.permissionless_fee_amounts.read()
+        }
+
+        fn is_tick_spacing_allowed(self: @ContractState, tick_spacing: i32) -> bool {
+            !tick_spacing.sign && self.allowed_tick_spacing.read(tick_spacing.mag)
+        }
+
+        fn permissionless_min_liquidity(self: @ContractState) -> u128 {
+            self.permissionless_min_liquidity.read()
+        }
+
+        fn fee_amount_min_liquidity(self: @ContractState, fee: u32) -> u128 {
+            if self.fee_amount_permissionless.read(fee) {
+                self.permissionless_min_liquidity()
+            } else {
+                0
+            }
+        }
+
+        fn pool_class_hash(self: @ContractState) -> ClassHash {
+            self.pool_class_hash.read()
+        }
+
+        fn pool_class_hashes(self: @ContractState) -> Array<ClassHash> {
+            let mut class_hashes = array![];
+            let len = self.pool_class_hashes_len.read();
+            let mut i = 0;
+            loop {
+                if i == len {
+                    break;
+                }
+                class_hashes.append(self.pool_class_hashes.read(i));
+                i += 1;
+            };
+            class_hashes
+        }
+
         fn fee_amount_tick_spacing(self: @ContractState, fee: u32) -> i32 {
             self.fee_amount_tick_spacing.read(fee)
         }
@@ -182,7 +470,7 @@ mod YASFactory {
 
             assert(self.pool(token_0, token_1, fee).is_zero(), 'token pair already created');
 
-            let contract_address_salt = generate_salt(@token_0, @token_1);
+            let contract_address_salt = generate_salt(@token_0, @token_1, fee, @tick_spacing);
             let calldata = serialize_calldata(@token_0, @token_1, fee, @tick_spacing);
 
             let (pool, _) = deploy_syscall(
@@ -193,6 +481,7 @@ mod YASFactory {
             self.pool.write((token_0, token_1, fee), pool);
             // populate mapping in the reverse direction, deliberate choice to avoid the cost of comparing addresses
             self.pool.write((token_1, token_0, fee), pool);
+            self.fee_amount_has_pools.write(fee, true);
 
             self.emit(PoolCreated { token_0, token_1, fee, tick_spacing, pool });
 
@@ -200,27 +489,115 @@ mod YASFactory {
         }
 
         fn enable_fee_amount(ref self: ContractState, fee: u32, tick_spacing: i32) {
-            self.assert_only_owner();
+            let permissionless = get_caller_address() != self.fee_amount_manager();
+            if permissionless {
+                assert(self.permissionless_fee_amounts(), 'only fee amount manager');
+                assert(self.is_tick_spacing_allowed(tick_spacing), 'tick spacing not allowed');
+            }
             assert(fee < 1000000, 'fee cannot be gt 1000000');
-
-            // tick spacing is capped at 16384 to prevent the situation where tick_spacing is so large that
-            // TickBitmap#nextInitializedTickWithinOneWord overflows int24 container from a valid tick
-            // 16384 ticks represents a >5x price change with ticks of 1 bips
+            assert_valid_tick_spacing(tick_spacing);
             assert(
-                tick_spacing > Zeroable::zero()
-                    && tick_spacing < IntegerTrait::<i32>::new(16384, false),
-                'wrong tick_spacing (0<ts<16384)'
+                self.fee_amount_tick_spacing(fee).is_zero()
+                    || (!permissionless && self.fee_amount_permissionless.read(fee)),
+                'fee amount already initialized'
             );
-            assert(self.fee_amount_tick_spacing(fee).is_zero(), 'fee amount already initialized');
+            assert(!self.fee_amount_has_pools.read(fee), 'fee amount already has pools');
 
             self.fee_amount_tick_spacing.write(fee, tick_spacing);
+            self.fee_amount_permissionless.write(fee, permissionless);
             self.emit(FeeAmountEnabled { fee, tick_spacing });
         }
 
-        fn set_owner(ref self: ContractState, new_owner: ContractAddress) {
+        fn transfer_ownership(ref self: ContractState, new_owner: ContractAddress) {
             self.assert_only_owner();
-            self.emit(OwnerChanged { old_owner: self.owner(), new_owner });
-            self.owner.write(new_owner);
+            assert(new_owner.is_non_zero(), 'new owner cannot be zero');
+            self.pending_owner.write(new_owner);
+            self.emit(OwnershipTransferStarted { previous_owner: self.owner(), new_owner });
+        }
+
+        fn accept_ownership(ref self: ContractState) {
+            let new_owner = get_caller_address();
+            assert(new_owner == self.pending_owner(), 'only pending owner can accept');
+            self.set_owner(new_owner);
+        }
+
+        fn renounce_ownership(ref self: ContractState) {
+            self.assert_only_owner();
+            self.set_owner(Zeroable::zero());
+        }
+
+        fn set_pool_class_hash(ref self: ContractState, new_class_hash: ClassHash) {
+            self.assert_only_owner();
+            assert(new_class_hash.is_non_zero(), 'pool class hash can not be zero');
+            self
+                .emit(
+                    PoolClassHashChanged {
+                        old_class_hash: self.pool_class_hash.read(), new_class_hash
+                    }
+                );
+            self.write_pool_class_hash(new_class_hash);
+        }
+
+        fn set_permissionless_fee_amounts(ref self: ContractState, enabled: bool) {
+            self.assert_only_owner();
+            self.permissionless_fee_amounts.write(enabled);
+            self.emit(PermissionlessFeeAmountsSet { enabled });
+        }
+
+        fn set_tick_spacing_allowed(ref self: ContractState, tick_spacing: i32, allowed: bool) {
+            self.assert_only_owner();
+            assert_valid_tick_spacing(tick_spacing);
+            self.allowed_tick_spacing.write(tick_spacing.mag, allowed);
+            self.emit(TickSpacingAllowed { tick_spacing, allowed });
+        }
+
+        fn set_permissionless_min_liquidity(ref self: ContractState, min_liquidity: u128) {
+            self.assert_only_owner();
+            self.permissionless_min_liquidity.write(min_liquidity);
+            self.emit(PermissionlessMinLiquiditySet { min_liquidity });
+        }
+
+        fn set_guardian(ref self: ContractState, new_guardian: ContractAddress) {
+            self.assert_only_owner();
+            assert(new_guardian.is_non_zero(), 'new guardian cannot be zero');
+            self.emit(GuardianChanged { old_guardian: self.guardian(), new_guardian });
+            self.guardian.write(new_guardian);
+        }
+
+        fn pause(ref self: ContractState) {
+            self.assert_only_guardian();
+            assert(!self.is_paused(), 'already paused');
+            self.paused.write(true);
+            self.emit(Paused { account: get_caller_address() });
+        }
+
+        fn unpause(ref self: ContractState) {
+            self.assert_only_guardian();
+            assert(self.is_paused(), 'not paused');
+            self.paused.write(false);
+            self.emit(Unpaused { account: get_caller_address() });
+        }
+
+        fn set_fee_amount_manager(ref self: ContractState, new_manager: ContractAddress) {
+            self.assert_only_owner();
+            assert(new_manager.is_non_zero(), 'new manager cannot be zero');
+            self
+                .emit(
+                    FeeAmountManagerChanged { old_manager: self.fee_amount_manager(), new_manager }
+                );
+            self.fee_amount_manager.write(new_manager);
+        }
+
+        fn set_protocol_fee_manager(ref self: ContractState, new_manager: ContractAddress) {
+            self.assert_only_owner();
+            assert(new_manager.is_non_zero(), 'new manager cannot be zero');
+            self
+                .emit(
+                    ProtocolFeeManagerChanged {
+                        old_manager: self.protocol_fee_manager(), new_manager
+                    }
+                );
+            self.protocol_fee_manager.write(new_manager);
         }
     }
 
@@ -229,6 +606,36 @@ mod YASFactory {
         fn assert_only_owner(self: @ContractState) {
             assert(get_caller_address() == self.owner(), 'only owner can do this action!');
         }
+
+        fn assert_only_guardian(self: @ContractState) {
+            assert(get_caller_address() == self.guardian(), 'only guardian');
+        }
+
+        // also discards any pending ownership transfer
+        fn set_owner(ref self: ContractState, new_owner: ContractAddress) {
+            self.emit(OwnerChanged { old_owner: self.owner(), new_owner });
+            self.owner.write(new_owner);
+            self.pending_owner.write(Zeroable::zero());
+        }
+
+        // keeps the previous class hashes, pools deployed from them keep their address
+        fn write_pool_class_hash(ref self: ContractState, class_hash: ClassHash) {
+            self.pool_class_hash.write(class_hash);
+            let len = self.pool_class_hashes_len.read();
+            self.pool_class_hashes.write(len, class_hash);
+            self.pool_class_hashes_len.write(len + 1);
+        }
+    }
+
+    fn assert_valid_tick_spacing(tick_spacing: i32) {
+        // tick spacing is capped at 16384 to prevent the situation where tick_spacing is so large that
+        // TickBitmap#nextInitializedTickWithinOneWord overflows int24 container from a valid tick
+        // 16384 ticks represents a >5x price change with ticks of 1 bips
+        assert(
+            tick_spacing > Zeroable::zero()
+                && tick_spacing < IntegerTrait::<i32>::new(16384, false),
+            'wrong tick_spacing (0<ts<16384)'
+        );
     }
 
     fn serialize_calldata(
@@ -244,10 +651,15 @@ mod YASFactory {
         calldata
     }
 
-    fn generate_salt(token_0: @ContractAddress, token_1: @ContractAddress) -> felt252 {
+    // the salt covers the whole pool key so every fee tier of a pair gets its own address
+    fn generate_salt(
+        token_0: @ContractAddress, token_1: @ContractAddress, fee: u32, tick_spacing: @i32
+    ) -> felt252 {
         let mut data = array![];
         Serde::serialize(token_0, ref data);
         Serde::serialize(token_1, ref data);
+        data.append(fee.into());
+        Serde::serialize(tick_spacing, ref data);
         poseidon_hash_span(data.span())
     }
 }
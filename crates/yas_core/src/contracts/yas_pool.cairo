@@ -1,9 +1,9 @@
-use starknet::ContractAddress;
+use starknet::{ClassHash, ContractAddress};
 
 use yas_core::libraries::position::{Info as PositionInfo, PositionKey};
 use yas_core::libraries::tick::Info as TickInfo;
 use yas_core::numbers::fixed_point::implementations::impl_64x96::FixedType;
-use yas_core::numbers::signed_integer::{i32::i32, i256::i256};
+use yas_core::numbers::signed_integer::{i16::i16, i32::i32, i256::i256};
 
 #[derive(Copy, Drop, Serde, starknet::Store)]
 struct Slot0 {
@@ -16,6 +16,27 @@ struct Slot0 {
     fee_protocol: u8,
 }
 
+// snapshot of the pool state, returned by get_pool_state
+#[derive(Copy, Drop, Serde)]
+struct PoolState {
+    factory: ContractAddress,
+    token_0: ContractAddress,
+    token_1: ContractAddress,
+    fee: u32,
+    tick_spacing: i32,
+    max_liquidity_per_tick: u128,
+    slot_0: Slot0,
+    // the currently in range liquidity available to the pool
+    liquidity: u128,
+    fee_growth_global_0_X128: u256,
+    fee_growth_global_1_X128: u256,
+    // accumulated protocol fees in token_0/token_1 units
+    protocol_fees_token_0: u128,
+    protocol_fees_token_1: u128,
+    // whether the pool is paused, either on its own or by a global pause
+    paused: bool
+}
+
 #[starknet::interface]
 trait IYASPool<TContractState> {
     fn initialize(ref self: TContractState, sqrt_price_X96: FixedType);
@@ -38,21 +59,58 @@ trait IYASPool<TContractState> {
     fn burn(
         ref self: TContractState, tick_lower: i32, tick_upper: i32, amount: u128
     ) -> (u256, u256);
+    fn collect(
+        ref self: TContractState,
+        recipient: ContractAddress,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount_0_requested: u128,
+        amount_1_requested: u128
+    ) -> (u128, u128);
+    fn set_fee_protocol(ref self: TContractState, fee_protocol_0: u8, fee_protocol_1: u8);
+    fn collect_protocol(
+        ref self: TContractState,
+        recipient: ContractAddress,
+        amount_0_requested: u128,
+        amount_1_requested: u128
+    ) -> (u128, u128);
     fn token_0(self: @TContractState) -> ContractAddress;
     fn token_1(self: @TContractState) -> ContractAddress;
+    fn get_factory(self: @TContractState) -> ContractAddress;
+    fn get_fee(self: @TContractState) -> u32;
+    fn get_liquidity(self: @TContractState) -> u128;
+    fn get_protocol_fees(self: @TContractState) -> (u128, u128);
+    fn get_pool_state(self: @TContractState) -> PoolState;
     fn get_fee_growth_globals(self: @TContractState) -> (u256, u256);
     fn get_slot_0(self: @TContractState) -> Slot0;
     fn get_max_liquidity_per_tick(self: @TContractState) -> u128;
     fn get_position(self: @TContractState, position_key: PositionKey) -> PositionInfo;
     fn get_tick_spacing(self: @TContractState) -> i32;
     fn get_tick(self: @TContractState, tick: i32) -> TickInfo;
+    fn get_tick_bitmap(self: @TContractState, word_pos: i16) -> u256;
+    fn get_seconds_per_liquidity_inside(
+        self: @TContractState, tick_lower: i32, tick_upper: i32
+    ) -> u256;
+    fn is_paused(self: @TContractState) -> bool;
+    fn pause(ref self: TContractState);
+    fn unpause(ref self: TContractState);
+    fn get_pending_upgrade(self: @TContractState) -> (ClassHash, u64);
+    fn schedule_upgrade(ref self: TContractState, new_class_hash: ClassHash);
+    fn cancel_upgrade(ref self: TContractState);
+    fn upgrade(ref self: TContractState, new_class_hash: ClassHash);
 }
 
 #[starknet::contract]
 mod YASPool {
-    use super::{IYASPool, Slot0};
+    use super::{IYASPool, PoolState, Slot0};
 
-    use starknet::{ContractAddress, get_block_timestamp, get_caller_address, get_contract_address};
+    use starknet::{
+        ClassHash, ContractAddress, SyscallResultTrait, get_block_timestamp, get_caller_address,
+        get_contract_address
+    };
+    use starknet::syscalls::replace_class_syscall;
+
+    use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
 
     use yas_core::interfaces::interface_ERC20::{IERC20DispatcherTrait, IERC20Dispatcher};
     use yas_core::interfaces::interface_yas_mint_callback::{
@@ -76,7 +134,7 @@ mod YASPool {
         FixedType, FixedTrait, FP64x96PartialOrd, FP64x96PartialEq, FP64x96Impl, FP64x96Zeroable
     };
     use yas_core::numbers::signed_integer::{
-        i32::i32, i64::i64, i128::{i128, u128Intoi128}, i256::{i256, i256TryIntou256},
+        i16::i16, i32::i32, i64::i64, i128::{i128, u128Intoi128}, i256::{i256, i256TryIntou256},
         integer_trait::IntegerTrait
     };
     use yas_core::utils::math_utils::Constants::Q128;
@@ -89,9 +147,20 @@ mod YASPool {
         Initialize: Initialize,
         SwapExecuted: SwapExecuted,
         Mint: Mint,
-        Burn: Burn
+        Burn: Burn,
+        Collect: Collect,
+        SetFeeProtocol: SetFeeProtocol,
+        CollectProtocol: CollectProtocol,
+        Paused: Paused,
+        Unpaused: Unpaused,
+        UpgradeScheduled: UpgradeScheduled,
+        UpgradeCancelled: UpgradeCancelled,
+        Upgraded: Upgraded
     }
 
+    /// @notice Time the factory owner has to wait between scheduling and applying a pool upgrade
+    const UPGRADE_DELAY: u64 = 172800; // 2 days
+
     /// @notice Emitted exactly once by a pool when #initialize is first called on the pool
     /// @dev Mint/Burn/Swap cannot be emitted by the pool before Initialize
     /// @param sqrt_price_X96 The initial sqrt price of the pool, as a Q64.96
@@ -134,6 +203,86 @@ mod YASPool {
         amount_1: u256
     }
 
+    /// @notice Emitted when fees are collected by the owner of a position
+    /// @param owner The owner of the position for which fees are collected
+    /// @param recipient The address that received the collected tokens
+    /// @param tick_lower The lower tick of the position
+    /// @param tick_upper The upper tick of the position
+    /// @param amount_0 The amount of token0 fees collected
+    /// @param amount_1 The amount of token1 fees collected
+    #[derive(Drop, starknet::Event)]
+    struct Collect {
+        owner: ContractAddress,
+        recipient: ContractAddress,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount_0: u128,
+        amount_1: u128
+    }
+
+    /// @notice Emitted when the protocol fee is changed by the factory protocol fee manager
+    /// @param fee_protocol_0_old The previous value of the token0 protocol fee
+    /// @param fee_protocol_1_old The previous value of the token1 protocol fee
+    /// @param fee_protocol_0_new The updated value of the token0 protocol fee
+    /// @param fee_protocol_1_new The updated value of the token1 protocol fee
+    #[derive(Drop, starknet::Event)]
+    struct SetFeeProtocol {
+        fee_protocol_0_old: u8,
+        fee_protocol_1_old: u8,
+        fee_protocol_0_new: u8,
+        fee_protocol_1_new: u8
+    }
+
+    /// @notice Emitted when the collected protocol fees are withdrawn by the factory protocol fee manager
+    /// @param sender The address that collects the protocol fees
+    /// @param recipient The address that receives the collected protocol fees
+    /// @param amount_0 The amount of token0 protocol fees that is withdrawn
+    /// @param amount_1 The amount of token1 protocol fees that is withdrawn
+    #[derive(Drop, starknet::Event)]
+    struct CollectProtocol {
+        sender: ContractAddress,
+        recipient: ContractAddress,
+        amount_0: u128,
+        amount_1: u128
+    }
+
+    /// @notice Emitted when the factory guardian pauses the pool
+    /// @param account The guardian that paused the pool
+    #[derive(Drop, starknet::Event)]
+    struct Paused {
+        account: ContractAddress
+    }
+
+    /// @notice Emitted when the factory guardian unpauses the pool
+    /// @param account The guardian that unpaused the pool
+    #[derive(Drop, starknet::Event)]
+    struct Unpaused {
+        account: ContractAddress
+    }
+
+    /// @notice Emitted when the factory owner schedules an upgrade of the pool class
+    /// @param class_hash The class hash the pool will be upgraded to
+    /// @param ready_at The timestamp from which the upgrade can be applied
+    #[derive(Drop, starknet::Event)]
+    struct UpgradeScheduled {
+        class_hash: ClassHash,
+        ready_at: u64
+    }
+
+    /// @notice Emitted when the factory owner cancels a scheduled upgrade
+    /// @param class_hash The class hash of the cancelled upgrade
+    #[derive(Drop, starknet::Event)]
+    struct UpgradeCancelled {
+        class_hash: ClassHash
+    }
+
+    /// @notice Emitted when the pool class is replaced
+    /// @param class_hash The new class hash of the pool
+    #[derive(Drop, starknet::Event)]
+    struct Upgraded {
+        class_hash: ClassHash
+    }
+
     #[derive(Copy, Drop)]
     struct SwapCache {
         // the protocol fee for the input token
@@ -190,7 +339,16 @@ mod YASPool {
         fee_growth_global_1_X128: u256,
         protocol_fees: ProtocolFees,
         tick_spacing: i32,
-        unlocked: bool
+        unlocked: bool,
+        paused: bool,
+        // the all-time seconds per max(1, liquidity) of the pool, as of the last update
+        seconds_per_liquidity_cumulative_X128: u256,
+        seconds_per_liquidity_updated_at: u64,
+        // false for pools initialized by a class without the accumulator and upgraded since,
+        // their ticks don't record the seconds per liquidity outside of them
+        seconds_per_liquidity_tracked: bool,
+        pending_class_hash: ClassHash,
+        upgrade_ready_at: u64
     }
 
     #[constructor]
@@ -202,6 +360,8 @@ mod YASPool {
         fee: u32,
         tick_spacing: i32,
     ) {
+        // pools deployed out of band would be unknown to the factory and its periphery
+        assert(get_caller_address() == factory, 'only factory can deploy');
         self.factory.write(factory);
         self.token_0.write(token_0);
         self.token_1.write(token_1);
@@ -224,6 +384,45 @@ mod YASPool {
             self.token_1.read()
         }
 
+        fn get_factory(self: @ContractState) -> ContractAddress {
+            self.factory.read()
+        }
+
+        fn get_fee(self: @ContractState) -> u32 {
+            self.fee.read()
+        }
+
+        /// @notice Returns the currently in range liquidity available to the pool
+        fn get_liquidity(self: @ContractState) -> u128 {
+            self.liquidity.read()
+        }
+
+        /// @notice Returns the protocol fees accumulated in token_0 and token_1
+        fn get_protocol_fees(self: @ContractState) -> (u128, u128) {
+            let protocol_fees = self.protocol_fees.read();
+            (protocol_fees.token_0, protocol_fees.token_1)
+        }
+
+        /// @notice Returns every value needed to snapshot the pool off-chain in a single call
+        fn get_pool_state(self: @ContractState) -> PoolState {
+            let protocol_fees = self.protocol_fees.read();
+            PoolState {
+                factory: self.factory.read(),
+                token_0: self.token_0.read(),
+                token_1: self.token_1.read(),
+                fee: self.fee.read(),
+                tick_spacing: self.tick_spacing.read(),
+                max_liquidity_per_tick: self.max_liquidity_per_tick.read(),
+                slot_0: self.slot_0.read(),
+                liquidity: self.liquidity.read(),
+                fee_growth_global_0_X128: self.fee_growth_global_0_X128.read(),
+                fee_growth_global_1_X128: self.fee_growth_global_1_X128.read(),
+                protocol_fees_token_0: protocol_fees.token_0,
+                protocol_fees_token_1: protocol_fees.token_1,
+                paused: self.is_paused()
+            }
+        }
+
         fn get_slot_0(self: @ContractState) -> Slot0 {
             self.slot_0.read()
         }
@@ -240,6 +439,114 @@ mod YASPool {
             let tick_state = Tick::unsafe_new_contract_state();
             TickImpl::get_tick(@tick_state, tick)
         }
+
+        /// @notice Returns a snapshot of the seconds per liquidity inside a tick range
+        /// @dev Snapshots must only be compared to other snapshots, taken over a period for which a
+        /// position existed, i.e. while both ticks were initialized. Reverts for pools initialized
+        /// before the seconds per liquidity were tracked, whose ticks would give wrong snapshots
+        /// @param tick_lower The lower tick of the range
+        /// @param tick_upper The upper tick of the range
+        /// @return The snapshot of seconds per liquidity for the range, as a Q128.128
+        fn get_seconds_per_liquidity_inside(
+            self: @ContractState, tick_lower: i32, tick_upper: i32
+        ) -> u256 {
+            match check_ticks(tick_lower, tick_upper) {
+                Result::Ok(()) => {},
+                Result::Err(err) => {
+                    panic_with_felt252(err)
+                },
+            }
+            assert(self.seconds_per_liquidity_tracked.read(), 'seconds not tracked');
+            let tick_state = Tick::unsafe_new_contract_state();
+            assert(TickImpl::get_tick(@tick_state, tick_lower).initialized, 'TLI');
+            assert(TickImpl::get_tick(@tick_state, tick_upper).initialized, 'TUI');
+
+            TickImpl::get_seconds_per_liquidity_inside(
+                @tick_state,
+                tick_lower,
+                tick_upper,
+                self.slot_0.read().tick,
+                self.seconds_per_liquidity_cumulative(get_block_timestamp())
+            )
+        }
+
+        fn get_tick_bitmap(self: @ContractState, word_pos: i16) -> u256 {
+            let bitmap_state = TickBitmap::unsafe_new_contract_state();
+            TickBitmapImpl::get_word(@bitmap_state, word_pos)
+        }
+
+        /// @notice Returns true if the pool is paused, either on its own or by a global pause
+        fn is_paused(self: @ContractState) -> bool {
+            self.paused.read() || self.factory_dispatcher().is_paused()
+        }
+
+        /// @notice Pauses swaps and mints on the pool, burns and collects keep working
+        /// @dev Must be called by the factory guardian
+        fn pause(ref self: ContractState) {
+            self.assert_only_factory_guardian();
+            assert(!self.paused.read(), 'already paused');
+            self.paused.write(true);
+            self.emit(Paused { account: get_caller_address() });
+        }
+
+        /// @notice Lifts the pause of the pool, a global pause still applies
+        /// @dev Must be called by the factory guardian
+        fn unpause(ref self: ContractState) {
+            self.assert_only_factory_guardian();
+            assert(self.paused.read(), 'not paused');
+            self.paused.write(false);
+            self.emit(Unpaused { account: get_caller_address() });
+        }
+
+        fn get_pending_upgrade(self: @ContractState) -> (ClassHash, u64) {
+            (self.pending_class_hash.read(), self.upgrade_ready_at.read())
+        }
+
+        /// @notice Schedules an upgrade of the pool class, replacing any previously scheduled one
+        /// @dev Must be called by the factory owner. The new class must keep the storage layout of
+        /// the current one. Classes built before the tick bitmap floored negative ticks into their
+        /// words stored some of them one word too low, e.g. -414 in word -3. Pools of such a class
+        /// with a negative tick initialized must not be upgraded in place: the new class would not
+        /// find that tick, nor cross it in swaps
+        /// @param new_class_hash The class hash the pool will be upgraded to
+        fn schedule_upgrade(ref self: ContractState, new_class_hash: ClassHash) {
+            self.assert_only_factory_owner();
+            assert(new_class_hash.is_non_zero(), 'class hash can not be zero');
+
+            let ready_at = get_block_timestamp() + UPGRADE_DELAY;
+            self.pending_class_hash.write(new_class_hash);
+            self.upgrade_ready_at.write(ready_at);
+            self.emit(UpgradeScheduled { class_hash: new_class_hash, ready_at });
+        }
+
+        /// @notice Cancels the scheduled upgrade
+        /// @dev Must be called by the factory owner
+        fn cancel_upgrade(ref self: ContractState) {
+            self.assert_only_factory_owner();
+            let class_hash = self.pending_class_hash.read();
+            assert(class_hash.is_non_zero(), 'no upgrade scheduled');
+
+            self.clear_pending_upgrade();
+            self.emit(UpgradeCancelled { class_hash });
+        }
+
+        /// @notice Replaces the pool class with the scheduled one once the delay has passed
+        /// @dev Must be called by the factory owner. new_class_hash must match the scheduled upgrade
+        /// @param new_class_hash The class hash the pool is upgraded to
+        fn upgrade(ref self: ContractState, new_class_hash: ClassHash) {
+            self.assert_only_factory_owner();
+            let pending_class_hash = self.pending_class_hash.read();
+            assert(
+                pending_class_hash.is_non_zero() && pending_class_hash == new_class_hash,
+                'upgrade not scheduled'
+            );
+            assert(get_block_timestamp() >= self.upgrade_ready_at.read(), 'upgrade not ready');
+
+            self.clear_pending_upgrade();
+            replace_class_syscall(new_class_hash).unwrap_syscall();
+            self.emit(Upgraded { class_hash: new_class_hash });
+        }
+
         fn get_fee_growth_globals(self: @ContractState) -> (u256, u256) {
             (self.fee_growth_global_0_X128.read(), self.fee_growth_global_1_X128.read())
         }
@@ -263,6 +570,8 @@ mod YASPool {
             slot_0.tick = get_tick_at_sqrt_ratio(sqrt_price_X96);
             slot_0.fee_protocol = 0;
             self.slot_0.write(slot_0);
+            self.seconds_per_liquidity_updated_at.write(get_block_timestamp());
+            self.seconds_per_liquidity_tracked.write(true);
 
             self.unlocked.write(true);
 
@@ -292,6 +601,10 @@ mod YASPool {
                 'SPL'
             );
             self.check_and_lock();
+            self.assert_not_paused();
+
+            // liquidity only changes while crossing ticks below, all within this block
+            let seconds_per_liquidity_cumulative_X128 = self.accumulate_seconds_per_liquidity();
 
             let cache = SwapCache {
                 liquidity_start: self.liquidity.read(),
@@ -334,50 +647,34 @@ mod YASPool {
                     break;
                 }
 
-                let step_sqrt_price_start_X96 = state.sqrt_price_X96;
-
-                // TODO: test mut
-                let (mut step_tick_next, step_initialized) =
+                let (step_tick_next, step_initialized) =
                     TickBitmapImpl::next_initialized_tick_within_one_word(
                     @state_tick_bitmap, state.tick, self.tick_spacing.read(), zero_for_one
                 );
 
-                // ensure that we do not overshoot the min/max tick, as the tick bitmap is not aware of these bounds
-                if step_tick_next < MIN_TICK() {
-                    step_tick_next = MIN_TICK();
-                } else if step_tick_next > MAX_TICK() {
-                    step_tick_next = MAX_TICK();
-                };
-
-                // get the price for the next tick
-                let step_sqrt_price_next_X96 = get_sqrt_ratio_at_tick(step_tick_next);
-
-                // compute values to swap to the target tick, price limit, or point where input/output amount is exhausted
-                let (ret_sqrt_price_X96, step_amount_in, step_amount_out, mut step_fee_amount) =
-                    SwapMath::compute_swap_step(
+                let step = SwapMath::compute_swap_step_to_tick(
                     state.sqrt_price_X96,
-                    if (zero_for_one && step_sqrt_price_next_X96 < sqrt_price_limit_X96)
-                        || (!zero_for_one && step_sqrt_price_next_X96 > sqrt_price_limit_X96) {
-                        sqrt_price_limit_X96
-                    } else {
-                        step_sqrt_price_next_X96
-                    },
+                    state.tick,
+                    step_tick_next,
+                    sqrt_price_limit_X96,
                     state.liquidity,
                     state.amount_specified_remaining,
-                    self.fee.read()
+                    self.fee.read(),
+                    zero_for_one
                 );
+                let mut step_fee_amount = step.fee_amount;
 
-                state.sqrt_price_X96 = ret_sqrt_price_X96;
+                state.sqrt_price_X96 = step.sqrt_price_X96;
 
                 if exact_input {
                     state
                         .amount_specified_remaining -=
-                            IntegerTrait::<i256>::new(step_amount_in + step_fee_amount, false);
-                    state.amount_calculated -= step_amount_out.into();
+                            IntegerTrait::<i256>::new(step.amount_in + step_fee_amount, false);
+                    state.amount_calculated -= step.amount_out.into();
                 } else {
-                    state.amount_specified_remaining += step_amount_out.into();
+                    state.amount_specified_remaining += step.amount_out.into();
                     state.amount_calculated = state.amount_calculated
-                        + IntegerTrait::<i256>::new(step_amount_in + step_fee_amount, false);
+                        + IntegerTrait::<i256>::new(step.amount_in + step_fee_amount, false);
                 };
 
                 // if the protocol fee is on, calculate how much is owed, decrement feeAmount, and increment protocolFee
@@ -394,49 +691,37 @@ mod YASPool {
                             FullMath::mul_div(step_fee_amount, Q128, state.liquidity.into());
                 };
 
-                // shift tick if we reached the next price
-                if state.sqrt_price_X96 == step_sqrt_price_next_X96 {
-                    // if the tick is initialized, run the tick transition
-                    if step_initialized {
-                        // crosses an initialized tick
-                        let mut liquidity_net = TickImpl::cross(
-                            ref state_tick,
-                            step_tick_next,
-                            if zero_for_one {
-                                state.fee_growth_global_X128
-                            } else {
-                                self.fee_growth_global_0_X128.read()
-                            },
-                            if zero_for_one {
-                                self.fee_growth_global_1_X128.read()
-                            } else {
-                                state.fee_growth_global_X128
-                            },
-                            0, // TODO: Remove in the future
-                            IntegerTrait::<i64>::new(0, false),
-                            0
-                        );
-
-                        // if we're moving leftward, we interpret liquidityNet as the opposite sign
-                        // safe because liquidityNet cannot be type(int128).min
+                // if the tick is initialized, run the tick transition
+                if step.reached_tick_next && step_initialized {
+                    // crosses an initialized tick
+                    let mut liquidity_net = TickImpl::cross(
+                        ref state_tick,
+                        step.tick_next,
+                        if zero_for_one {
+                            state.fee_growth_global_X128
+                        } else {
+                            self.fee_growth_global_0_X128.read()
+                        },
                         if zero_for_one {
-                            liquidity_net = -liquidity_net;
-                        };
+                            self.fee_growth_global_1_X128.read()
+                        } else {
+                            state.fee_growth_global_X128
+                        },
+                        seconds_per_liquidity_cumulative_X128,
+                        IntegerTrait::<i64>::new(0, false),
+                        cache.block_timestamp
+                    );
 
-                        state.liquidity = LiquidityMath::add_delta(state.liquidity, liquidity_net);
+                    // if we're moving leftward, we interpret liquidityNet as the opposite sign
+                    // safe because liquidityNet cannot be type(int128).min
+                    if zero_for_one {
+                        liquidity_net = -liquidity_net;
                     };
 
-                    state
-                        .tick =
-                            if zero_for_one {
-                                step_tick_next - IntegerTrait::<i32>::new(1, false)
-                            } else {
-                                step_tick_next
-                            };
-                } else if state.sqrt_price_X96 != step_sqrt_price_start_X96 {
-                    // recompute unless we're on a lower tick boundary (i.e. already transitioned ticks), and haven't moved
-                    state.tick = get_tick_at_sqrt_ratio(state.sqrt_price_X96);
+                    state.liquidity = LiquidityMath::add_delta(state.liquidity, liquidity_net);
                 };
+
+                state.tick = step.tick;
             };
 
             // update tick and write an oracle entry if the tick change
@@ -532,8 +817,11 @@ mod YASPool {
             data: Array<felt252>
         ) -> (u256, u256) {
             self.check_and_lock();
+            self.assert_not_paused();
 
             assert(amount > 0, 'amount must be greater than 0');
+            let min_liquidity = self.factory_dispatcher().fee_amount_min_liquidity(self.fee.read());
+            assert(amount >= min_liquidity, 'liquidity below minimum');
             let (_, amount_0, amount_1) = self
                 .modify_position(
                     ModifyPositionParams {
@@ -629,6 +917,154 @@ mod YASPool {
             self.unlock();
             (amount_0, amount_1)
         }
+
+        /// @notice Collects tokens owed to a position
+        /// @dev Does not recompute fees earned, which must be done either via mint or burn of any amount of liquidity.
+        /// Collect must be called by the position owner. To withdraw only token0 or only token1, amount_0_requested or
+        /// amount_1_requested may be set to zero. To withdraw all tokens owed, caller may pass any value greater than the
+        /// actual tokens owed, e.g. BoundedInt::max(). Tokens owed may be from accumulated swap fees or burned liquidity.
+        /// @param recipient The address which should receive the fees collected
+        /// @param tick_lower The lower tick of the position for which to collect fees
+        /// @param tick_upper The upper tick of the position for which to collect fees
+        /// @param amount_0_requested How much token0 should be withdrawn from the fees owed
+        /// @param amount_1_requested How much token1 should be withdrawn from the fees owed
+        /// @return amount_0 The amount of fees collected in token0
+        /// @return amount_1 The amount of fees collected in token1
+        fn collect(
+            ref self: ContractState,
+            recipient: ContractAddress,
+            tick_lower: i32,
+            tick_upper: i32,
+            amount_0_requested: u128,
+            amount_1_requested: u128
+        ) -> (u128, u128) {
+            self.check_and_lock();
+
+            // we don't need to check_ticks here, because invalid positions will never have non-zero tokens_owed
+            let position_key = PositionKey { owner: get_caller_address(), tick_lower, tick_upper };
+            let mut position_state = Position::unsafe_new_contract_state();
+            let position = PositionImpl::get(@position_state, position_key);
+
+            let amount_0 = if amount_0_requested > position.tokens_owed_0 {
+                position.tokens_owed_0
+            } else {
+                amount_0_requested
+            };
+            let amount_1 = if amount_1_requested > position.tokens_owed_1 {
+                position.tokens_owed_1
+            } else {
+                amount_1_requested
+            };
+
+            PositionImpl::update_tokens_owed(
+                ref position_state,
+                position_key,
+                position.tokens_owed_0 - amount_0,
+                position.tokens_owed_1 - amount_1
+            );
+
+            if amount_0 > 0 {
+                IERC20Dispatcher { contract_address: self.token_0.read() }
+                    .transfer(recipient, amount_0.into());
+            }
+            if amount_1 > 0 {
+                IERC20Dispatcher { contract_address: self.token_1.read() }
+                    .transfer(recipient, amount_1.into());
+            }
+
+            self
+                .emit(
+                    Collect {
+                        owner: get_caller_address(),
+                        recipient,
+                        tick_lower,
+                        tick_upper,
+                        amount_0,
+                        amount_1
+                    }
+                );
+            self.unlock();
+            (amount_0, amount_1)
+        }
+
+        /// @notice Sets the denominator of the protocol's % share of the fees
+        /// @dev Must be called by the factory protocol fee manager
+        /// @param fee_protocol_0 new protocol fee for token0 of the pool, 0 or between 4 and 10
+        /// @param fee_protocol_1 new protocol fee for token1 of the pool, 0 or between 4 and 10
+        fn set_fee_protocol(ref self: ContractState, fee_protocol_0: u8, fee_protocol_1: u8) {
+            self.check_and_lock();
+            self.assert_only_factory_protocol_fee_manager();
+            assert(
+                is_valid_fee_protocol(fee_protocol_0) && is_valid_fee_protocol(fee_protocol_1),
+                'invalid fee protocol'
+            );
+
+            let mut slot_0 = self.slot_0.read();
+            let fee_protocol_old = slot_0.fee_protocol;
+            slot_0.fee_protocol = fee_protocol_0 + fee_protocol_1 * 16;
+            self.slot_0.write(slot_0);
+
+            self
+                .emit(
+                    SetFeeProtocol {
+                        fee_protocol_0_old: fee_protocol_old % 16,
+                        fee_protocol_1_old: fee_protocol_old.shr(4),
+                        fee_protocol_0_new: fee_protocol_0,
+                        fee_protocol_1_new: fee_protocol_1
+                    }
+                );
+            self.unlock();
+        }
+
+        /// @notice Collects the protocol fees accrued to the pool
+        /// @dev Must be called by the factory protocol fee manager
+        /// @param recipient The address to which collected protocol fees should be sent
+        /// @param amount_0_requested The maximum amount of token0 to send, can be 0 to collect fees in only token1
+        /// @param amount_1_requested The maximum amount of token1 to send, can be 0 to collect fees in only token0
+        /// @return The protocol fees collected in token0 and token1
+        fn collect_protocol(
+            ref self: ContractState,
+            recipient: ContractAddress,
+            amount_0_requested: u128,
+            amount_1_requested: u128
+        ) -> (u128, u128) {
+            self.check_and_lock();
+            self.assert_only_factory_protocol_fee_manager();
+
+            let mut protocol_fees = self.protocol_fees.read();
+            let amount_0 = if amount_0_requested > protocol_fees.token_0 {
+                protocol_fees.token_0
+            } else {
+                amount_0_requested
+            };
+            let amount_1 = if amount_1_requested > protocol_fees.token_1 {
+                protocol_fees.token_1
+            } else {
+                amount_1_requested
+            };
+
+            protocol_fees.token_0 -= amount_0;
+            protocol_fees.token_1 -= amount_1;
+            self.protocol_fees.write(protocol_fees);
+
+            if amount_0 > 0 {
+                IERC20Dispatcher { contract_address: self.token_0.read() }
+                    .transfer(recipient, amount_0.into());
+            }
+            if amount_1 > 0 {
+                IERC20Dispatcher { contract_address: self.token_1.read() }
+                    .transfer(recipient, amount_1.into());
+            }
+
+            self
+                .emit(
+                    CollectProtocol {
+                        sender: get_caller_address(), recipient, amount_0, amount_1
+                    }
+                );
+            self.unlock();
+            (amount_0, amount_1)
+        }
     }
 
     #[generate_trait]
@@ -647,6 +1083,9 @@ mod YASPool {
 
             let fee_growth_global_0_X128 = self.fee_growth_global_0_X128.read();
             let fee_growth_global_1_X128 = self.fee_growth_global_1_X128.read();
+            let seconds_per_liquidity_cumulative_X128 = self
+                .seconds_per_liquidity_cumulative_X128
+                .read();
 
             let max_liquidity_per_tick = self.max_liquidity_per_tick.read();
 
@@ -665,6 +1104,7 @@ mod YASPool {
                         liquidity_delta,
                         fee_growth_global_0_X128,
                         fee_growth_global_1_X128,
+                        seconds_per_liquidity_cumulative_X128,
                         time,
                         false,
                         max_liquidity_per_tick
@@ -678,6 +1118,7 @@ mod YASPool {
                         liquidity_delta,
                         fee_growth_global_0_X128,
                         fee_growth_global_1_X128,
+                        seconds_per_liquidity_cumulative_X128,
                         time,
                         true,
                         max_liquidity_per_tick
@@ -741,6 +1182,8 @@ mod YASPool {
                     panic_with_felt252(err)
                 },
             }
+            // the in range liquidity may change below
+            self.accumulate_seconds_per_liquidity();
 
             let slot_0 = self.slot_0.read();
             let position = self
@@ -848,6 +1291,57 @@ mod YASPool {
             self.unlocked.write(true);
         }
 
+        fn factory_dispatcher(self: @ContractState) -> IYASFactoryDispatcher {
+            IYASFactoryDispatcher { contract_address: self.factory.read() }
+        }
+
+        fn assert_only_factory_owner(self: @ContractState) {
+            assert(get_caller_address() == self.factory_dispatcher().owner(), 'only factory owner');
+        }
+
+        fn assert_only_factory_protocol_fee_manager(self: @ContractState) {
+            let protocol_fee_manager = self.factory_dispatcher().protocol_fee_manager();
+            assert(get_caller_address() == protocol_fee_manager, 'only protocol fee manager');
+        }
+
+        fn assert_only_factory_guardian(self: @ContractState) {
+            let guardian = self.factory_dispatcher().guardian();
+            assert(get_caller_address() == guardian, 'only factory guardian');
+        }
+
+        fn assert_not_paused(self: @ContractState) {
+            assert(!self.is_paused(), 'paused');
+        }
+
+        /// @dev Returns the all-time seconds per max(1, liquidity) of the pool at time, given that
+        /// the in range liquidity did not change since the last update
+        fn seconds_per_liquidity_cumulative(self: @ContractState, time: u64) -> u256 {
+            let elapsed: u256 = (time - self.seconds_per_liquidity_updated_at.read()).into();
+            let liquidity = self.liquidity.read();
+            let liquidity: u256 = if liquidity > 0 {
+                liquidity.into()
+            } else {
+                1
+            };
+            self.seconds_per_liquidity_cumulative_X128.read() + elapsed * Q128 / liquidity
+        }
+
+        /// @dev Brings the seconds per liquidity up to date, it must be called before any change
+        /// of the in range liquidity
+        /// @return The up to date seconds per liquidity
+        fn accumulate_seconds_per_liquidity(ref self: ContractState) -> u256 {
+            let time = get_block_timestamp();
+            let seconds_per_liquidity_cumulative_X128 = self.seconds_per_liquidity_cumulative(time);
+            self.seconds_per_liquidity_cumulative_X128.write(seconds_per_liquidity_cumulative_X128);
+            self.seconds_per_liquidity_updated_at.write(time);
+            seconds_per_liquidity_cumulative_X128
+        }
+
+        fn clear_pending_upgrade(ref self: ContractState) {
+            self.pending_class_hash.write(Zeroable::zero());
+            self.upgrade_ready_at.write(0);
+        }
+
         fn balance_0(self: @ContractState) -> u256 {
             IERC20Dispatcher { contract_address: self.token_0.read() }
                 .balanceOf(get_contract_address())
@@ -859,6 +1353,11 @@ mod YASPool {
         }
     }
 
+    // the protocol takes 1/fee_protocol of the swap fees, from 10% to 25%, or nothing
+    fn is_valid_fee_protocol(fee_protocol: u8) -> bool {
+        fee_protocol == 0 || (fee_protocol >= 4 && fee_protocol <= 10)
+    }
+
     fn is_valid_callback_contract(callback_contract: ContractAddress) -> bool {
         callback_contract.is_non_zero()
     }
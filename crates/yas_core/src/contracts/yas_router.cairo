@@ -41,18 +41,136 @@ trait IYASRouter<TContractState> {
         recipient: ContractAddress,
         sqrt_price_limit_X96: FixedType
     ) -> (i256, i256);
+    fn swap_0_for_exact_1(
+        self: @TContractState,
+        pool: ContractAddress,
+        amount_out: u256,
+        amount_in_maximum: u256,
+        recipient: ContractAddress,
+        sqrt_price_limit_X96: FixedType
+    ) -> (i256, i256);
+    fn swap_1_for_exact_0(
+        self: @TContractState,
+        pool: ContractAddress,
+        amount_out: u256,
+        amount_in_maximum: u256,
+        recipient: ContractAddress,
+        sqrt_price_limit_X96: FixedType
+    ) -> (i256, i256);
+    fn exact_input(
+        ref self: TContractState,
+        path: Array<felt252>,
+        recipient: ContractAddress,
+        amount_in: u256,
+        amount_out_minimum: u256,
+        deadline: u256
+    ) -> u256;
+    fn exact_output(
+        ref self: TContractState,
+        path: Array<felt252>,
+        recipient: ContractAddress,
+        amount_out: u256,
+        amount_in_maximum: u256,
+        deadline: u256
+    ) -> u256;
+    fn exact_input_single(ref self: TContractState, params: ExactInputSingleParams) -> u256;
+    fn exact_output_single(ref self: TContractState, params: ExactOutputSingleParams) -> u256;
+    fn get_factory(self: @TContractState) -> ContractAddress;
+    fn sweep_token(
+        ref self: TContractState,
+        token: ContractAddress,
+        amount_minimum: u256,
+        recipient: ContractAddress
+    );
+    fn self_permit(
+        ref self: TContractState,
+        token: ContractAddress,
+        value: u256,
+        deadline: u256,
+        signature: Array<felt252>
+    );
+    fn multicall(ref self: TContractState, calls: Array<Span<felt252>>) -> Array<Span<felt252>>;
+}
+
+#[derive(Copy, Drop, Serde)]
+struct ExactInputSingleParams {
+    token_in: ContractAddress,
+    token_out: ContractAddress,
+    fee: u32,
+    recipient: ContractAddress,
+    deadline: u256,
+    amount_in: u256,
+    amount_out_minimum: u256,
+    sqrt_price_limit_X96: FixedType
+}
+
+#[derive(Copy, Drop, Serde)]
+struct ExactOutputSingleParams {
+    token_in: ContractAddress,
+    token_out: ContractAddress,
+    fee: u32,
+    recipient: ContractAddress,
+    deadline: u256,
+    amount_out: u256,
+    amount_in_maximum: u256,
+    sqrt_price_limit_X96: FixedType
+}
+
+#[derive(Copy, Drop, Serde)]
+struct SwapCallbackData {
+    // the encoded path of the swap, empty for swaps against an explicitly passed pool
+    path: Span<felt252>,
+    // the address paying the tokens owed to the pool
+    payer: ContractAddress
 }
 
 #[starknet::contract]
 mod YASRouter {
-    use super::IYASRouter;
+    use super::{IYASRouter, SwapCallbackData, ExactInputSingleParams, ExactOutputSingleParams};
 
-    use starknet::{ContractAddress, get_caller_address, get_contract_address};
+    use integer::BoundedInt;
+    use starknet::{ContractAddress, get_block_timestamp, get_caller_address, get_contract_address};
 
+    use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
     use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
-    use yas_core::interfaces::interface_ERC20::{IERC20Dispatcher, IERC20DispatcherTrait};
-    use yas_core::numbers::fixed_point::implementations::impl_64x96::FixedType;
+    use yas_core::interfaces::interface_ERC20::{
+        IERC20Dispatcher, IERC20DispatcherTrait, IERC20PermitDispatcher, IERC20PermitDispatcherTrait
+    };
+    use yas_core::libraries::path::Path;
+    use yas_core::libraries::tick_math::TickMath::{MAX_SQRT_RATIO, MIN_SQRT_RATIO};
+    use yas_core::numbers::fixed_point::implementations::impl_64x96::{
+        FixedType, FixedTrait, FP64x96Impl, FP64x96Zeroable
+    };
     use yas_core::numbers::signed_integer::{i32::i32, i256::i256, integer_trait::IntegerTrait};
+    use yas_core::utils::utils::ContractAddressPartialOrd;
+
+    mod Errors {
+        const TRANSACTION_TOO_OLD: felt252 = 'Transaction too old';
+        const TOO_LITTLE_RECEIVED: felt252 = 'Too little received';
+        const TOO_MUCH_REQUESTED: felt252 = 'Too much requested';
+        const INVALID_CALLBACK_DATA: felt252 = 'invalid callback data';
+        const INVALID_CALLER: felt252 = 'invalid caller';
+        const PAUSED: felt252 = 'paused';
+        const INVALID_CALLDATA: felt252 = 'invalid calldata';
+        const UNSUPPORTED_SELECTOR: felt252 = 'unsupported selector';
+        const INSUFFICIENT_TOKEN: felt252 = 'Insufficient token';
+    }
+
+    // entry points that can be batched with multicall, as sn_keccak of their names
+    mod Selectors {
+        const EXACT_INPUT: felt252 =
+            0x376405dd63c185db5333eef556ab9904a8108b0b8b851de892fa1b331a73b8;
+        const EXACT_OUTPUT: felt252 =
+            0x33a6970df600f982009fe389216d81ed0d4a01c74e7f8742b8bd0609e4012c2;
+        const EXACT_INPUT_SINGLE: felt252 =
+            0x8b15073164d9faa52b205fa2eecbf6004827f35a3730e91da3990d533ccfe8;
+        const EXACT_OUTPUT_SINGLE: felt252 =
+            0x110800993026ce11a3aab7d203715926f83270559cd74604fafe0405d2f383c;
+        const SWEEP_TOKEN: felt252 =
+            0x1c9c1338fd590ddc293f6bb4434088a29824a2137ebb854e5105b124f2f0f9f;
+        const SELF_PERMIT: felt252 =
+            0x383d59b57ea46ccf10e51e3cc6e970c3d474f7064f66473d3409c0f520e2491;
+    }
 
     #[event]
     #[derive(Drop, starknet::Event)]
@@ -74,7 +192,18 @@ mod YASRouter {
     }
 
     #[storage]
-    struct Storage {}
+    struct Storage {
+        factory: ContractAddress,
+        // used as the placeholder value for amount_in_cached, because the computed amount in for
+        // an exact output swap can never actually be this value
+        amount_in_cached: u256
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, factory: ContractAddress) {
+        self.factory.write(factory);
+        self.amount_in_cached.write(BoundedInt::max());
+    }
 
     #[external(v0)]
     impl YASRouterCallbackImpl of IYASRouter<ContractState> {
@@ -96,24 +225,21 @@ mod YASRouter {
             ref self: ContractState, amount_0_owed: u256, amount_1_owed: u256, data: Array<felt252>
         ) {
             let msg_sender = get_caller_address();
+            self.verify_callback_pool(msg_sender);
 
-            // TODO: we need verify if data has a valid ContractAddress
-            let mut sender: ContractAddress = Zeroable::zero();
-            if !data.is_empty() {
-                sender = (*data[0]).try_into().unwrap();
-            }
+            let sender: ContractAddress = (*data[0])
+                .try_into()
+                .expect(Errors::INVALID_CALLBACK_DATA);
 
             self.emit(MintCallback { amount_0_owed, amount_1_owed });
 
             if amount_0_owed > 0 {
                 let token_0 = IYASPoolDispatcher { contract_address: msg_sender }.token_0();
-                IERC20Dispatcher { contract_address: token_0 }
-                    .transferFrom(sender, msg_sender, amount_0_owed);
+                self.pay(token_0, sender, msg_sender, amount_0_owed);
             }
             if amount_1_owed > 0 {
                 let token_1 = IYASPoolDispatcher { contract_address: msg_sender }.token_1();
-                IERC20Dispatcher { contract_address: token_1 }
-                    .transferFrom(sender, msg_sender, amount_1_owed);
+                self.pay(token_1, sender, msg_sender, amount_1_owed);
             }
         }
 
@@ -131,7 +257,9 @@ mod YASRouter {
                     zero_for_one,
                     amount_specified,
                     sqrt_price_limit_X96,
-                    array![get_caller_address().into()]
+                    serialize_callback_data(
+                        SwapCallbackData { path: array![].span(), payer: get_caller_address() }
+                    )
                 )
         }
 
@@ -143,30 +271,66 @@ mod YASRouter {
         ) {
             let msg_sender = get_caller_address();
 
-            // TODO: we need verify if data has a valid ContractAddress
-            let mut sender: ContractAddress = Zeroable::zero();
-            if !data.is_empty() {
-                sender = (*data[0]).try_into().unwrap();
-            }
+            let mut data_span = data.span();
+            let callback_data = Serde::<SwapCallbackData>::deserialize(ref data_span)
+                .expect(Errors::INVALID_CALLBACK_DATA);
 
             self.emit(SwapCallback { amount_0_delta, amount_1_delta });
 
-            if amount_0_delta > Zeroable::zero() {
-                let token_0 = IYASPoolDispatcher { contract_address: msg_sender }.token_0();
-                IERC20Dispatcher { contract_address: token_0 }
-                    .transferFrom(sender, msg_sender, amount_0_delta.try_into().unwrap());
-            } else if amount_1_delta > Zeroable::zero() {
-                let token_1 = IYASPoolDispatcher { contract_address: msg_sender }.token_1();
-                IERC20Dispatcher { contract_address: token_1 }
-                    .transferFrom(sender, msg_sender, amount_1_delta.try_into().unwrap());
+            // swap against an explicitly passed pool, pay whichever token is owed
+            if callback_data.path.is_empty() {
+                self.verify_callback_pool(msg_sender);
+                if amount_0_delta > Zeroable::zero() {
+                    let token_0 = IYASPoolDispatcher { contract_address: msg_sender }.token_0();
+                    self.pay(token_0, callback_data.payer, msg_sender, amount_0_delta.mag);
+                } else if amount_1_delta > Zeroable::zero() {
+                    let token_1 = IYASPoolDispatcher { contract_address: msg_sender }.token_1();
+                    self.pay(token_1, callback_data.payer, msg_sender, amount_1_delta.mag);
+                } else {
+                    // if both are not gt 0, both must be 0.
+                    assert(
+                        amount_0_delta == Zeroable::zero() && amount_1_delta == Zeroable::zero(),
+                        'both amount deltas are negative'
+                    );
+                }
+                return;
+            }
+
+            // swaps entirely within 0-liquidity regions are not supported
+            assert(
+                amount_0_delta > Zeroable::zero() || amount_1_delta > Zeroable::zero(),
+                'both amount deltas are negative'
+            );
+
+            let (token_in, token_out, fee) = Path::decode_first_pool(callback_data.path);
+            self.verify_callback(token_in, token_out, fee);
+
+            let (is_exact_input, amount_to_pay) = if amount_0_delta > Zeroable::zero() {
+                (token_in < token_out, amount_0_delta.mag)
             } else {
-                // if both are not gt 0, both must be 0.
-                assert(
-                    amount_0_delta == Zeroable::zero() && amount_1_delta == Zeroable::zero(),
-                    'both amount deltas are negative'
-                );
+                (token_out < token_in, amount_1_delta.mag)
+            };
+
+            if is_exact_input {
+                self.pay(token_in, callback_data.payer, msg_sender, amount_to_pay);
+            } else if Path::has_multiple_pools(callback_data.path) {
+                // initiate the next swap, its pool will be paid from the nested callback frame
+                self
+                    .exact_output_internal(
+                        amount_to_pay,
+                        msg_sender,
+                        Zeroable::zero(),
+                        SwapCallbackData {
+                            path: Path::skip_token(callback_data.path), payer: callback_data.payer
+                        }
+                    );
+            } else {
+                self.amount_in_cached.write(amount_to_pay);
+                // exact output swaps are executed in reverse order, so token_out is actually the input token
+                self.pay(token_out, callback_data.payer, msg_sender, amount_to_pay);
             }
         }
+
         fn swap_exact_0_for_1(
             self: @ContractState,
             pool: ContractAddress,
@@ -174,13 +338,13 @@ mod YASRouter {
             recipient: ContractAddress,
             sqrt_price_limit_X96: FixedType
         ) -> (i256, i256) {
-            IYASPoolDispatcher { contract_address: pool }
+            self
                 .swap(
+                    pool,
                     recipient,
                     true,
                     IntegerTrait::<i256>::new(amount_in, false),
-                    sqrt_price_limit_X96,
-                    array![get_caller_address().into()]
+                    sqrt_price_limit_X96
                 )
         }
 
@@ -191,14 +355,480 @@ mod YASRouter {
             recipient: ContractAddress,
             sqrt_price_limit_X96: FixedType
         ) -> (i256, i256) {
-            IYASPoolDispatcher { contract_address: pool }
+            self
                 .swap(
+                    pool,
                     recipient,
-                    true,
-                    IntegerTrait::<i256>::new(amount_in, true),
-                    sqrt_price_limit_X96,
-                    array![get_caller_address().into()]
+                    false,
+                    IntegerTrait::<i256>::new(amount_in, false),
+                    sqrt_price_limit_X96
                 )
         }
+
+        /// @notice Swaps as little token_0 as possible for exactly `amount_out` of token_1
+        /// @dev The swap stops early if `sqrt_price_limit_X96` is reached, so less than `amount_out`
+        /// may be received
+        /// @param amount_in_maximum The maximum amount of token_0 that can be paid
+        /// @return The pool's amount deltas, positive for the token paid in
+        fn swap_0_for_exact_1(
+            self: @ContractState,
+            pool: ContractAddress,
+            amount_out: u256,
+            amount_in_maximum: u256,
+            recipient: ContractAddress,
+            sqrt_price_limit_X96: FixedType
+        ) -> (i256, i256) {
+            let (amount_0, amount_1) = self
+                .swap(
+                    pool,
+                    recipient,
+                    true,
+                    IntegerTrait::<i256>::new(amount_out, true),
+                    sqrt_price_limit_X96
+                );
+            assert(amount_0.mag <= amount_in_maximum, Errors::TOO_MUCH_REQUESTED);
+            (amount_0, amount_1)
+        }
+
+        /// @notice Swaps as little token_1 as possible for exactly `amount_out` of token_0
+        /// @dev The swap stops early if `sqrt_price_limit_X96` is reached, so less than `amount_out`
+        /// may be received
+        /// @param amount_in_maximum The maximum amount of token_1 that can be paid
+        /// @return The pool's amount deltas, positive for the token paid in
+        fn swap_1_for_exact_0(
+            self: @ContractState,
+            pool: ContractAddress,
+            amount_out: u256,
+            amount_in_maximum: u256,
+            recipient: ContractAddress,
+            sqrt_price_limit_X96: FixedType
+        ) -> (i256, i256) {
+            let (amount_0, amount_1) = self
+                .swap(
+                    pool,
+                    recipient,
+                    false,
+                    IntegerTrait::<i256>::new(amount_out, true),
+                    sqrt_price_limit_X96
+                );
+            assert(amount_1.mag <= amount_in_maximum, Errors::TOO_MUCH_REQUESTED);
+            (amount_0, amount_1)
+        }
+
+        /// @notice Swaps `amount_in` of one token for as much as possible of another along the specified path
+        /// @param path The encoded path [token_in, fee, token, fee, ..., token_out]
+        /// @param recipient The address that receives the output tokens
+        /// @param amount_in The amount of the first token of the path to be swapped
+        /// @param amount_out_minimum The minimum amount of the last token of the path that must be received
+        /// @param deadline The timestamp after which the swap reverts
+        /// @return amount_out The amount of the received token
+        fn exact_input(
+            ref self: ContractState,
+            path: Array<felt252>,
+            recipient: ContractAddress,
+            amount_in: u256,
+            amount_out_minimum: u256,
+            deadline: u256
+        ) -> u256 {
+            self.check_deadline(deadline);
+            self.check_not_paused();
+
+            // the caller pays for the first hop
+            let mut payer = get_caller_address();
+            let mut path = path.span();
+            let mut amount_in = amount_in;
+            let mut amount_out = 0;
+
+            loop {
+                let has_multiple_pools = Path::has_multiple_pools(path);
+
+                // the outputs of prior swaps become the inputs to subsequent ones
+                amount_out = self
+                    .exact_input_internal(
+                        amount_in,
+                        // for intermediate swaps, this contract custodies
+                        if has_multiple_pools {
+                            get_contract_address()
+                        } else {
+                            recipient
+                        },
+                        Zeroable::zero(),
+                        SwapCallbackData { path: Path::get_first_pool(path), payer }
+                    );
+
+                if !has_multiple_pools {
+                    break;
+                }
+
+                // at this point, the caller has paid
+                payer = get_contract_address();
+                path = Path::skip_token(path);
+                amount_in = amount_out;
+            };
+
+            assert(amount_out >= amount_out_minimum, Errors::TOO_LITTLE_RECEIVED);
+            amount_out
+        }
+
+        /// @notice Swaps as little as possible of one token for `amount_out` of another along the specified path (reversed)
+        /// @param path The encoded path in reverse order [token_out, fee, token, fee, ..., token_in]
+        /// @param recipient The address that receives the output tokens
+        /// @param amount_out The amount of the first token of the path to be received
+        /// @param amount_in_maximum The maximum amount of the last token of the path that can be spent
+        /// @param deadline The timestamp after which the swap reverts
+        /// @return amount_in The amount of the input token
+        fn exact_output(
+            ref self: ContractState,
+            path: Array<felt252>,
+            recipient: ContractAddress,
+            amount_out: u256,
+            amount_in_maximum: u256,
+            deadline: u256
+        ) -> u256 {
+            self.check_deadline(deadline);
+            self.check_not_paused();
+
+            // it's okay that the payer is fixed to the caller here, as they're only paying for the "final"
+            // exact output swap, which happens first, and subsequent swaps are paid for within nested callback frames
+            self
+                .exact_output_internal(
+                    amount_out,
+                    recipient,
+                    Zeroable::zero(),
+                    SwapCallbackData { path: path.span(), payer: get_caller_address() }
+                );
+
+            let amount_in = self.amount_in_cached.read();
+            assert(amount_in <= amount_in_maximum, Errors::TOO_MUCH_REQUESTED);
+            self.amount_in_cached.write(BoundedInt::max());
+            amount_in
+        }
+
+        /// @notice Swaps `amount_in` of one token for as much as possible of another token
+        /// @param params The parameters necessary for the swap, encoded as `ExactInputSingleParams`
+        /// @return amount_out The amount of the received token
+        fn exact_input_single(ref self: ContractState, params: ExactInputSingleParams) -> u256 {
+            self.check_deadline(params.deadline);
+            self.check_not_paused();
+
+            let path: Array<felt252> = array![
+                params.token_in.into(), params.fee.into(), params.token_out.into()
+            ];
+            let amount_out = self
+                .exact_input_internal(
+                    params.amount_in,
+                    params.recipient,
+                    params.sqrt_price_limit_X96,
+                    SwapCallbackData { path: path.span(), payer: get_caller_address() }
+                );
+
+            assert(amount_out >= params.amount_out_minimum, Errors::TOO_LITTLE_RECEIVED);
+            amount_out
+        }
+
+        /// @notice Swaps as little as possible of one token for `amount_out` of another token
+        /// @param params The parameters necessary for the swap, encoded as `ExactOutputSingleParams`
+        /// @return amount_in The amount of the input token
+        fn exact_output_single(ref self: ContractState, params: ExactOutputSingleParams) -> u256 {
+            self.check_deadline(params.deadline);
+            self.check_not_paused();
+
+            // exact output paths are encoded in reverse order
+            let path: Array<felt252> = array![
+                params.token_out.into(), params.fee.into(), params.token_in.into()
+            ];
+            let amount_in = self
+                .exact_output_internal(
+                    params.amount_out,
+                    params.recipient,
+                    params.sqrt_price_limit_X96,
+                    SwapCallbackData { path: path.span(), payer: get_caller_address() }
+                );
+
+            assert(amount_in <= params.amount_in_maximum, Errors::TOO_MUCH_REQUESTED);
+            // has to be reset even though we don't use it in the single hop case
+            self.amount_in_cached.write(BoundedInt::max());
+            amount_in
+        }
+
+        fn get_factory(self: @ContractState) -> ContractAddress {
+            self.factory.read()
+        }
+
+        /// @notice Transfers the full amount of a token held by this contract to recipient
+        /// @dev The router should never hold tokens between transactions. This recovers the
+        /// output of swaps sent to the router itself and any dust left behind, so it should be
+        /// batched with those swaps through multicall rather than called on its own
+        /// @param token The token to transfer
+        /// @param amount_minimum The minimum amount of token required for the transfer
+        /// @param recipient The destination address of the token
+        fn sweep_token(
+            ref self: ContractState,
+            token: ContractAddress,
+            amount_minimum: u256,
+            recipient: ContractAddress
+        ) {
+            let token = IERC20Dispatcher { contract_address: token };
+            let balance = token.balanceOf(get_contract_address());
+            assert(balance >= amount_minimum, Errors::INSUFFICIENT_TOKEN);
+
+            if balance > 0 {
+                token.transfer(recipient, balance);
+            }
+        }
+
+        /// @notice Approves the router to spend `value` of the caller's `token` with a permit
+        /// signature, so that a swap doesn't need a prior `approve` transaction
+        /// @dev Batch it with the swap through multicall. The token must implement `permit`
+        /// @param token The token the caller signed the permit for
+        /// @param value The amount the router is allowed to spend
+        /// @param deadline The timestamp after which the permit is no longer valid
+        /// @param signature The signature of the caller over the permit, with the router as spender
+        fn self_permit(
+            ref self: ContractState,
+            token: ContractAddress,
+            value: u256,
+            deadline: u256,
+            signature: Array<felt252>
+        ) {
+            IERC20PermitDispatcher { contract_address: token }
+                .permit(get_caller_address(), get_contract_address(), value, deadline, signature);
+        }
+
+        /// @notice Calls several entry points of this contract in a single transaction
+        /// @dev Each call is encoded as [selector, ...calldata] and is executed in place, so the
+        /// caller stays the same for every call. Reverts if any of the calls reverts
+        /// @param calls The encoded calls, see `Selectors` for the entry points that can be batched
+        /// @return results The serialized return value of each call
+        fn multicall(
+            ref self: ContractState, calls: Array<Span<felt252>>
+        ) -> Array<Span<felt252>> {
+            let mut calls = calls.span();
+            let mut results = array![];
+            loop {
+                match calls.pop_front() {
+                    Option::Some(call) => {
+                        results.append(self.execute_call(*call));
+                    },
+                    Option::None => {
+                        break;
+                    }
+                };
+            };
+            results
+        }
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        /// @dev Fails early while the factory guardian keeps every pool paused
+        fn check_not_paused(self: @ContractState) {
+            let factory = IYASFactoryDispatcher { contract_address: self.factory.read() };
+            assert(!factory.is_paused(), Errors::PAUSED);
+        }
+
+        /// @dev Decodes a multicall entry and runs the entry point it selects
+        fn execute_call(ref self: ContractState, call: Span<felt252>) -> Span<felt252> {
+            let mut calldata = call;
+            let selector = *calldata.pop_front().expect(Errors::INVALID_CALLDATA);
+
+            let mut result = array![];
+            if selector == Selectors::EXACT_INPUT {
+                let path = Serde::<Array<felt252>>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let recipient = Serde::<ContractAddress>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let amount_in = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let amount_out_minimum = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let deadline = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let amount_out = self
+                    .exact_input(path, recipient, amount_in, amount_out_minimum, deadline);
+                Serde::serialize(@amount_out, ref result);
+            } else if selector == Selectors::EXACT_OUTPUT {
+                let path = Serde::<Array<felt252>>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let recipient = Serde::<ContractAddress>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let amount_out = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let amount_in_maximum = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let deadline = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let amount_in = self
+                    .exact_output(path, recipient, amount_out, amount_in_maximum, deadline);
+                Serde::serialize(@amount_in, ref result);
+            } else if selector == Selectors::EXACT_INPUT_SINGLE {
+                let params = Serde::<ExactInputSingleParams>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                Serde::serialize(@self.exact_input_single(params), ref result);
+            } else if selector == Selectors::EXACT_OUTPUT_SINGLE {
+                let params = Serde::<ExactOutputSingleParams>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                Serde::serialize(@self.exact_output_single(params), ref result);
+            } else if selector == Selectors::SWEEP_TOKEN {
+                let token = Serde::<ContractAddress>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let amount_minimum = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let recipient = Serde::<ContractAddress>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                self.sweep_token(token, amount_minimum, recipient);
+            } else if selector == Selectors::SELF_PERMIT {
+                let token = Serde::<ContractAddress>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let value = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let deadline = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let signature = Serde::<Array<felt252>>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                self.self_permit(token, value, deadline, signature);
+            } else {
+                panic_with_felt252(Errors::UNSUPPORTED_SELECTOR);
+            }
+
+            assert(calldata.is_empty(), Errors::INVALID_CALLDATA);
+            result.span()
+        }
+
+        fn check_deadline(self: @ContractState, deadline: u256) {
+            let block_timestamp: u256 = get_block_timestamp().into();
+            assert(block_timestamp <= deadline, Errors::TRANSACTION_TOO_OLD);
+        }
+
+        /// @dev Asserts that the caller is the pool deployed by the factory for the given token pair and fee
+        fn verify_callback(
+            self: @ContractState, token_a: ContractAddress, token_b: ContractAddress, fee: u32
+        ) {
+            let pool = self.get_pool(token_a, token_b, fee);
+            assert(pool.contract_address == get_caller_address(), Errors::INVALID_CALLER);
+        }
+
+        /// @dev Asserts that `pool` was deployed by the factory. The pool key is read from `pool` itself,
+        /// which is safe because a contract impersonating a pool can't be registered under that key
+        fn verify_callback_pool(self: @ContractState, pool: ContractAddress) {
+            let pool_dispatcher = IYASPoolDispatcher { contract_address: pool };
+            self
+                .verify_callback(
+                    pool_dispatcher.token_0(), pool_dispatcher.token_1(), pool_dispatcher.get_fee()
+                );
+        }
+
+        /// @dev Returns the pool for the given token pair and fee. The pool contract may or may not exist.
+        fn get_pool(
+            self: @ContractState, token_a: ContractAddress, token_b: ContractAddress, fee: u32
+        ) -> IYASPoolDispatcher {
+            IYASPoolDispatcher {
+                contract_address: IYASFactoryDispatcher { contract_address: self.factory.read() }
+                    .pool(token_a, token_b, fee)
+            }
+        }
+
+        /// @dev Performs a single exact input swap
+        fn exact_input_internal(
+            ref self: ContractState,
+            amount_in: u256,
+            recipient: ContractAddress,
+            sqrt_price_limit_X96: FixedType,
+            data: SwapCallbackData
+        ) -> u256 {
+            let (token_in, token_out, fee) = Path::decode_first_pool(data.path);
+
+            let zero_for_one = token_in < token_out;
+
+            let (amount_0, amount_1) = self
+                .get_pool(token_in, token_out, fee)
+                .swap(
+                    recipient,
+                    zero_for_one,
+                    IntegerTrait::<i256>::new(amount_in, false),
+                    if sqrt_price_limit_X96.is_zero() {
+                        default_sqrt_price_limit(zero_for_one)
+                    } else {
+                        sqrt_price_limit_X96
+                    },
+                    serialize_callback_data(data)
+                );
+
+            if zero_for_one {
+                amount_1.mag
+            } else {
+                amount_0.mag
+            }
+        }
+
+        /// @dev Performs a single exact output swap
+        fn exact_output_internal(
+            ref self: ContractState,
+            amount_out: u256,
+            recipient: ContractAddress,
+            sqrt_price_limit_X96: FixedType,
+            data: SwapCallbackData
+        ) -> u256 {
+            let (token_out, token_in, fee) = Path::decode_first_pool(data.path);
+
+            let zero_for_one = token_in < token_out;
+
+            let (amount_0_delta, amount_1_delta) = self
+                .get_pool(token_in, token_out, fee)
+                .swap(
+                    recipient,
+                    zero_for_one,
+                    IntegerTrait::<i256>::new(amount_out, true),
+                    if sqrt_price_limit_X96.is_zero() {
+                        default_sqrt_price_limit(zero_for_one)
+                    } else {
+                        sqrt_price_limit_X96
+                    },
+                    serialize_callback_data(data)
+                );
+
+            let (amount_in, amount_out_received) = if zero_for_one {
+                (amount_0_delta.mag, amount_1_delta.mag)
+            } else {
+                (amount_1_delta.mag, amount_0_delta.mag)
+            };
+
+            // it's technically possible to not receive the full output amount,
+            // so if no price limit has been specified, require this possibility away
+            if sqrt_price_limit_X96.is_zero() {
+                assert(amount_out_received == amount_out, 'invalid amount out');
+            }
+            amount_in
+        }
+
+        /// @dev Pays `value` of `token` to `recipient`, pulling it from `payer` or from this
+        /// contract's own balance when the router itself is the payer (intermediate hops)
+        fn pay(
+            ref self: ContractState,
+            token: ContractAddress,
+            payer: ContractAddress,
+            recipient: ContractAddress,
+            value: u256
+        ) {
+            if payer == get_contract_address() {
+                IERC20Dispatcher { contract_address: token }.transfer(recipient, value);
+            } else {
+                IERC20Dispatcher { contract_address: token }.transferFrom(payer, recipient, value);
+            }
+        }
+    }
+
+    fn serialize_callback_data(data: SwapCallbackData) -> Array<felt252> {
+        let mut serialized = array![];
+        Serde::serialize(@data, ref serialized);
+        serialized
+    }
+
+    fn default_sqrt_price_limit(zero_for_one: bool) -> FixedType {
+        if zero_for_one {
+            FP64x96Impl::new(MIN_SQRT_RATIO + 1, false)
+        } else {
+            FP64x96Impl::new(MAX_SQRT_RATIO - 1, false)
+        }
     }
 }
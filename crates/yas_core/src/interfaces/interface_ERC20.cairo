@@ -8,3 +8,15 @@ trait IERC20<TContractState> {
         ref self: TContractState, sender: ContractAddress, recipient: ContractAddress, amount: u256
     ) -> bool;
 }
+
+#[starknet::interface]
+trait IERC20Permit<TContractState> {
+    fn permit(
+        ref self: TContractState,
+        owner: ContractAddress,
+        spender: ContractAddress,
+        value: u256,
+        deadline: u256,
+        signature: Array<felt252>
+    );
+}
@@ -0,0 +1,7 @@
+// SRC6 signature validation, implemented by Starknet accounts
+#[starknet::interface]
+trait IAccount<TContractState> {
+    fn is_valid_signature(
+        self: @TContractState, hash: felt252, signature: Array<felt252>
+    ) -> felt252;
+}
@@ -6,6 +6,7 @@ mod contracts {
 }
 
 mod interfaces {
+    mod interface_account;
     mod interface_ERC20;
     mod interface_yas_mint_callback;
     mod interface_yas_swap_callback;
@@ -14,6 +15,7 @@ mod interfaces {
 mod libraries {
     mod bit_math;
     mod liquidity_math;
+    mod path;
     mod position;
     mod tick_math;
     mod swap_math;
@@ -29,19 +31,23 @@ mod numbers {
 
 mod utils {
     mod math_utils;
+    mod typed_data;
     mod utils;
 }
 
 #[cfg(test)]
 mod tests {
     mod test_contracts {
+        mod test_yas_erc20;
         mod test_yas_factory;
         mod test_yas_pool;
+        mod test_yas_router;
     }
 
     mod test_libraries {
         mod test_bit_math;
         mod test_liquidity_math;
+        mod test_path;
         mod test_tick_math;
         mod test_position;
         mod test_sqrt_price_math;
@@ -57,6 +63,7 @@ mod tests {
 
     mod test_utils {
         mod test_math_utils;
+        mod test_typed_data;
         mod test_utils;
     }
 
@@ -64,5 +71,8 @@ mod tests {
         mod constants;
         mod swap_cases;
         mod pool_1;
+        mod mock_yas_pool_v2;
+        mod mock_account;
+        mod deployers;
     }
 }
@@ -0,0 +1,61 @@
+/// Functions for manipulating path data for multihop swaps.
+/// A path is a flat list of felts alternating tokens and fees, starting and ending with a token:
+/// [token_a, fee_ab, token_b, fee_bc, token_c, ...]
+mod Path {
+    use starknet::ContractAddress;
+
+    /// The number of felts used to encode a token address and a fee
+    const NEXT_OFFSET: u32 = 2;
+    /// The number of felts used to encode a single pool key (token, fee, token)
+    const POP_OFFSET: u32 = 3;
+    /// The minimum length of a path that contains 2 or more pools
+    const MULTIPLE_POOLS_MIN_LENGTH: u32 = 5;
+
+    /// Returns true iff the path contains two or more pools
+    /// Parameters:
+    /// - path: The encoded swap path
+    fn has_multiple_pools(path: Span<felt252>) -> bool {
+        path.len() >= MULTIPLE_POOLS_MIN_LENGTH
+    }
+
+    /// Returns the number of pools in the path
+    /// Parameters:
+    /// - path: The encoded swap path
+    fn num_pools(path: Span<felt252>) -> u32 {
+        assert(is_valid(path), 'invalid path');
+        (path.len() - 1) / NEXT_OFFSET
+    }
+
+    /// Decodes the first pool in path
+    /// Parameters:
+    /// - path: The encoded swap path
+    /// Returns:
+    /// - token_a: The first token of the given pool
+    /// - token_b: The second token of the given pool
+    /// - fee: The fee level of the pool
+    fn decode_first_pool(path: Span<felt252>) -> (ContractAddress, ContractAddress, u32) {
+        assert(is_valid(path), 'invalid path');
+        let token_a: ContractAddress = (*path[0]).try_into().expect('invalid path token');
+        let fee: u32 = (*path[1]).try_into().expect('invalid path fee');
+        let token_b: ContractAddress = (*path[2]).try_into().expect('invalid path token');
+        (token_a, token_b, fee)
+    }
+
+    /// Gets the segment corresponding to the first pool in the path
+    /// Parameters:
+    /// - path: The encoded swap path
+    fn get_first_pool(path: Span<felt252>) -> Span<felt252> {
+        path.slice(0, POP_OFFSET)
+    }
+
+    /// Skips a token + fee element from the path and returns the remainder
+    /// Parameters:
+    /// - path: The encoded swap path
+    fn skip_token(path: Span<felt252>) -> Span<felt252> {
+        path.slice(NEXT_OFFSET, path.len() - NEXT_OFFSET)
+    }
+
+    fn is_valid(path: Span<felt252>) -> bool {
+        path.len() >= POP_OFFSET && path.len() % NEXT_OFFSET == 1
+    }
+}
@@ -11,11 +11,107 @@ mod SwapMath {
         get_amount_0_delta, get_amount_1_delta, get_next_sqrt_price_from_input,
         get_next_sqrt_price_from_output
     };
+    use yas_core::libraries::tick_math::TickMath::{
+        get_sqrt_ratio_at_tick, get_tick_at_sqrt_ratio, MAX_TICK, MIN_TICK
+    };
     use integer::{u256_overflowing_add, u256_overflow_mul};
+    use yas_core::numbers::signed_integer::i32::i32;
     use yas_core::numbers::signed_integer::integer_trait::IntegerTrait;
 
     const _1e6: u256 = 1000000; // 10 ** 6 
 
+    // the result of one iteration of a swap loop
+    #[derive(Copy, Drop)]
+    struct SwapStep {
+        // the tick the step swapped towards, within the min/max tick
+        tick_next: i32,
+        // whether the step reached the price of tick_next, whose liquidity must then be crossed
+        reached_tick_next: bool,
+        // the sqrt(price) after the step
+        sqrt_price_X96: FixedType,
+        // the tick associated with the price after the step
+        tick: i32,
+        // how much is being swapped in in this step
+        amount_in: u256,
+        // how much is being swapped out
+        amount_out: u256,
+        // how much fee is being paid in
+        fee_amount: u256
+    }
+
+    /// Computes one iteration of the `YASPool::swap` loop, swapping towards the next tick of the
+    /// tick bitmap or the price limit, whichever comes first. Shared with the swap simulations
+    /// @param sqrt_price_X96 The current sqrt price of the swap
+    /// @param tick The tick associated with the current price
+    /// @param tick_next The next tick in the direction of the swap, as returned by the tick bitmap
+    /// @param sqrt_price_limit_X96 The price the swap can't go past
+    /// @param liquidity The usable liquidity
+    /// @param amount_remaining How much input or output amount is remaining to be swapped in/out
+    /// @param fee_pips The fee taken from the input amount, expressed in hundredths of a bip
+    /// @param zero_for_one The direction of the swap, true for token_0 to token_1
+    /// @return The step, the liquidity of tick_next is left to the caller to cross
+    fn compute_swap_step_to_tick(
+        sqrt_price_X96: FixedType,
+        tick: i32,
+        tick_next: i32,
+        sqrt_price_limit_X96: FixedType,
+        liquidity: u128,
+        amount_remaining: i256,
+        fee_pips: u32,
+        zero_for_one: bool
+    ) -> SwapStep {
+        // ensure that we do not overshoot the min/max tick, as the tick bitmap is not aware of these bounds
+        let tick_next = if tick_next < MIN_TICK() {
+            MIN_TICK()
+        } else if tick_next > MAX_TICK() {
+            MAX_TICK()
+        } else {
+            tick_next
+        };
+
+        // get the price for the next tick
+        let sqrt_price_next_X96 = get_sqrt_ratio_at_tick(tick_next);
+
+        // compute values to swap to the target tick, price limit, or point where input/output amount is exhausted
+        let (sqrt_price_after_X96, amount_in, amount_out, fee_amount) = compute_swap_step(
+            sqrt_price_X96,
+            if (zero_for_one && sqrt_price_next_X96 < sqrt_price_limit_X96)
+                || (!zero_for_one && sqrt_price_next_X96 > sqrt_price_limit_X96) {
+                sqrt_price_limit_X96
+            } else {
+                sqrt_price_next_X96
+            },
+            liquidity,
+            amount_remaining,
+            fee_pips
+        );
+
+        let reached_tick_next = sqrt_price_after_X96 == sqrt_price_next_X96;
+        let tick_after = if reached_tick_next {
+            // shift tick if we reached the next price
+            if zero_for_one {
+                tick_next - IntegerTrait::<i32>::new(1, false)
+            } else {
+                tick_next
+            }
+        } else if sqrt_price_after_X96 != sqrt_price_X96 {
+            // recompute unless we're on a lower tick boundary (i.e. already transitioned ticks), and haven't moved
+            get_tick_at_sqrt_ratio(sqrt_price_after_X96)
+        } else {
+            tick
+        };
+
+        SwapStep {
+            tick_next,
+            reached_tick_next,
+            sqrt_price_X96: sqrt_price_after_X96,
+            tick: tick_after,
+            amount_in,
+            amount_out,
+            fee_amount
+        }
+    }
+
     /// Computes the result of swapping some amount in, or amount out, given the parameters of the swap
     /// @param sqrt_ratio_currentX96 The current sqrt price of the pool
     /// @param sqrt_ratio_targetX96 The price that cannot be exceeded, from which the direction of the swap is inferred
@@ -68,10 +68,18 @@ trait ITick<TContractState> {
         liquidity_delta: i128,
         fee_growth_global_0X128: u256,
         fee_growth_global_1X128: u256,
+        seconds_per_liquidity_cumulative_X128: u256,
         time: u64,
         upper: bool,
         max_liquidity: u128
     ) -> bool;
+    fn get_seconds_per_liquidity_inside(
+        self: @TContractState,
+        tick_lower: i32,
+        tick_upper: i32,
+        tick_current: i32,
+        seconds_per_liquidity_cumulative_X128: u256
+    ) -> u256;
     fn get_tick(self: @TContractState, tick: i32) -> Info;
 }
 
@@ -124,13 +132,14 @@ mod Tick {
         }
 
         /// @notice Transitions to next tick as needed by price movement
-        /// The parameters seconds_per_liquidity_cumulative_X128 and tick_cumulative 
-        /// were removed because the module related to the Oracle is not yet implemented
+        /// The parameter tick_cumulative is unused because the module related to the Oracle
+        /// is not yet implemented
         ///
         /// @param self The mapping containing all tick information for initialized ticks
         /// @param tick The destination tick of the transition
         /// @param fee_growth_global_0X128 The all-time global fee growth, per unit of liquidity, in token0
         /// @param fee_growth_global_1X128 The all-time global fee growth, per unit of liquidity, in token1
+        /// @param seconds_per_liquidity_cumulative_X128 The current seconds per liquidity
         /// @param time The current block.timestamp
         /// @return liquidity_net The amount of liquidity added (subtracted) when tick is crossed from left to right (right to left)
         fn cross(
@@ -231,6 +240,7 @@ mod Tick {
             liquidity_delta: i128,
             fee_growth_global_0X128: u256,
             fee_growth_global_1X128: u256,
+            seconds_per_liquidity_cumulative_X128: u256,
             time: u64,
             upper: bool,
             max_liquidity: u128
@@ -251,6 +261,7 @@ mod Tick {
                 if (tick <= tick_current) {
                     info.fee_growth_outside_0X128 = fee_growth_global_0X128;
                     info.fee_growth_outside_1X128 = fee_growth_global_1X128;
+                    info.seconds_per_liquidity_outside_X128 = seconds_per_liquidity_cumulative_X128;
                     info.seconds_outside = time;
                 }
                 info.initialized = true;
@@ -271,6 +282,48 @@ mod Tick {
             flipped
         }
 
+        /// @notice Retrieves the seconds per liquidity spent inside a tick range
+        /// @param self The mapping containing all tick information for initialized ticks
+        /// @param tick_lower The lower tick boundary of the range
+        /// @param tick_upper The upper tick boundary of the range
+        /// @param tick_current The current tick
+        /// @param seconds_per_liquidity_cumulative_X128 The all-time seconds per max(1, liquidity) of the pool
+        /// @return The all-time seconds per liquidity inside the range, only has relative meaning
+        fn get_seconds_per_liquidity_inside(
+            self: @ContractState,
+            tick_lower: i32,
+            tick_upper: i32,
+            tick_current: i32,
+            seconds_per_liquidity_cumulative_X128: u256
+        ) -> u256 {
+            let lower: Info = self
+                .ticks
+                .read(PoseidonTrait::new().update_with(tick_lower).finalize());
+            let upper: Info = self
+                .ticks
+                .read(PoseidonTrait::new().update_with(tick_upper).finalize());
+
+            let seconds_per_liquidity_below_X128 = if tick_current >= tick_lower {
+                lower.seconds_per_liquidity_outside_X128
+            } else {
+                seconds_per_liquidity_cumulative_X128 - lower.seconds_per_liquidity_outside_X128
+            };
+
+            let seconds_per_liquidity_above_X128 = if tick_current < tick_upper {
+                upper.seconds_per_liquidity_outside_X128
+            } else {
+                seconds_per_liquidity_cumulative_X128 - upper.seconds_per_liquidity_outside_X128
+            };
+
+            // this function mimics the u256 overflow that occurs in Solidity
+            mod_subtraction(
+                mod_subtraction(
+                    seconds_per_liquidity_cumulative_X128, seconds_per_liquidity_below_X128
+                ),
+                seconds_per_liquidity_above_X128
+            )
+        }
+
         fn get_tick(self: @ContractState, tick: i32) -> Info {
             let hashed_tick = PoseidonTrait::new().update_with(tick).finalize();
             self.ticks.read(hashed_tick)
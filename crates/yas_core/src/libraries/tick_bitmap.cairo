@@ -1,4 +1,4 @@
-use yas_core::numbers::signed_integer::i32::i32;
+use yas_core::numbers::signed_integer::{i16::i16, i32::i32};
 
 #[starknet::interface]
 trait ITickBitmap<TContractState> {
@@ -6,6 +6,7 @@ trait ITickBitmap<TContractState> {
     fn next_initialized_tick_within_one_word(
         self: @TContractState, tick: i32, tick_spacing: i32, lte: bool
     ) -> (i32, bool);
+    fn get_word(self: @TContractState, word_pos: i16) -> u256;
 }
 
 #[starknet::contract]
@@ -18,7 +19,7 @@ mod TickBitmap {
 
     use yas_core::libraries::bit_math::BitMath;
     use yas_core::numbers::signed_integer::{
-        i16::i16, i32::{i32, u8Intoi32, i32TryIntoi16, i32TryIntou8, mod_i32},
+        i16::i16, i32::{i32, u8Intoi32, i32TryIntoi16, i32TryIntou8, mod_i32, i32_div_no_round},
         integer_trait::IntegerTrait
     };
     use yas_core::utils::math_utils::{BitShift::BitShiftTrait, pow};
@@ -58,58 +59,16 @@ mod TickBitmap {
         fn next_initialized_tick_within_one_word(
             self: @ContractState, tick: i32, tick_spacing: i32, lte: bool
         ) -> (i32, bool) {
-            let mut compressed: i32 = tick / tick_spacing;
-            if (tick < IntegerTrait::<i32>::new(0, false)
-                && tick % tick_spacing != IntegerTrait::<i32>::new(0, false)) {
-                compressed -= IntegerTrait::<i32>::new(1, false); // round towards negative infinity
-            };
+            let word = self.get_word(word_to_search(tick, tick_spacing, lte));
+            next_initialized_tick_in_word(word, tick, tick_spacing, lte)
+        }
 
-            if lte {
-                let (word_pos, bit_pos) = position(compressed);
-                let word: u256 = self
-                    .bitmap
-                    .read(PoseidonTrait::new().update_with(word_pos).finalize());
-                // all the 1s at or to the right of the current bitPos
-                let mask: u256 = 1_u256.shl(bit_pos.into()) - 1 + 1_u256.shl(bit_pos.into());
-                let masked: u256 = word & mask;
-
-                // if there are no initialized ticks to the right of or at the current tick, return rightmost in the word
-                let initialized = masked != 0;
-                // overflow/underflow is possible, but prevented externally by limiting both tickSpacing and tick
-                let next = if initialized {
-                    // (compressed - int24(bit_pos - BitMath.most_significant_bit(masked))) * tick_spacing
-                    (compressed - (bit_pos - BitMath::most_significant_bit(masked)).into())
-                        * tick_spacing
-                } else {
-                    // (compressed - int24(bit_pos)) * tick_spacing
-                    (compressed - bit_pos.into()) * tick_spacing
-                };
-                (next, initialized)
-            } else {
-                // start from the word of the next tick, since the current tick state doesn't matter
-                let (word_pos, bit_pos) = position(compressed + IntegerTrait::<i32>::new(1, false));
-                let word = self.bitmap.read(PoseidonTrait::new().update_with(word_pos).finalize());
-                // all the 1s at or to the left of the bitPos
-                let mask: u256 = ~(1_u256.shl(bit_pos.into()) - 1);
-                let masked: u256 = word & mask;
-
-                // if there are no initialized ticks to the left of the current tick, return leftmost in the word
-                let initialized = masked != 0;
-                // overflow/underflow is possible, but prevented externally by limiting both tickSpacing and tick
-                let next = if initialized {
-                    // (compressed + 1 + int24(BitMath::least_significant_bit(masked) - bit_pos)) * tick_spacing
-                    (compressed
-                        + IntegerTrait::<i32>::new(1, false)
-                        + (BitMath::least_significant_bit(masked) - bit_pos).into())
-                        * tick_spacing
-                } else {
-                    // (compressed + 1 + int24(type(uint8).max - bit_pos)) * tick_spacing
-                    let max_u8: u8 = BoundedInt::max();
-                    (compressed + IntegerTrait::<i32>::new(1, false) + (max_u8 - bit_pos).into())
-                        * tick_spacing
-                };
-                (next, initialized)
-            }
+        /// @notice Returns the packed initialized states of the 256 ticks stored in a word
+        /// @param self The @ContractState
+        /// @param word_pos The key in the mapping containing the word, as returned by `position`
+        /// @return The word in which each bit represents whether the tick is initialized
+        fn get_word(self: @ContractState, word_pos: i16) -> u256 {
+            self.bitmap.read(PoseidonTrait::new().update_with(word_pos).finalize())
         }
     }
 
@@ -129,10 +88,94 @@ mod TickBitmap {
         }
     }
 
-    /// Calculates the word value based on a tick input.
-    /// - For ticks between 0 and 255 inclusive, it returns 0.
-    /// - For ticks greater than 255, it divides the tick by 256.
-    /// - For ticks less than 0 but greater than or equal to -256, it returns -1.
+    /// @notice Returns the key of the word `next_initialized_tick_within_one_word` searches
+    /// @dev Lets contracts reading the bitmap of a pool through its getters search it the same way
+    /// @param tick The starting tick
+    /// @param tick_spacing The spacing between usable ticks
+    /// @param lte Whether to search for the next initialized tick to the left of the starting tick
+    /// @return The key of the word to pass to `next_initialized_tick_in_word`
+    fn word_to_search(tick: i32, tick_spacing: i32, lte: bool) -> i16 {
+        let compressed = compress(tick, tick_spacing);
+        let (word_pos, _) = if lte {
+            position(compressed)
+        } else {
+            // start from the word of the next tick, since the current tick state doesn't matter
+            position(compressed + IntegerTrait::<i32>::new(1, false))
+        };
+        word_pos
+    }
+
+    /// @notice Same as `next_initialized_tick_within_one_word`, searching the given word
+    /// @param word The word stored under the key returned by `word_to_search` for the same arguments
+    /// @param tick The starting tick
+    /// @param tick_spacing The spacing between usable ticks
+    /// @param lte Whether to search for the next initialized tick to the left (less than or equal to the starting tick)
+    /// @return next The next initialized or uninitialized tick up to 256 ticks away from the current tick
+    /// @return initialized Whether the next tick is initialized, as the function only searches within up to 256 ticks
+    fn next_initialized_tick_in_word(
+        word: u256, tick: i32, tick_spacing: i32, lte: bool
+    ) -> (i32, bool) {
+        let compressed = compress(tick, tick_spacing);
+
+        if lte {
+            let bit_pos = calculate_bit(compressed);
+            // all the 1s at or to the right of the current bitPos
+            let mask: u256 = 1_u256.shl(bit_pos.into()) - 1 + 1_u256.shl(bit_pos.into());
+            let masked: u256 = word & mask;
+
+            // if there are no initialized ticks to the right of or at the current tick, return rightmost in the word
+            let initialized = masked != 0;
+            // overflow/underflow is possible, but prevented externally by limiting both tickSpacing and tick
+            let next = if initialized {
+                // (compressed - int24(bit_pos - BitMath.most_significant_bit(masked))) * tick_spacing
+                (compressed - (bit_pos - BitMath::most_significant_bit(masked)).into())
+                    * tick_spacing
+            } else {
+                // (compressed - int24(bit_pos)) * tick_spacing
+                (compressed - bit_pos.into()) * tick_spacing
+            };
+            (next, initialized)
+        } else {
+            let bit_pos = calculate_bit(compressed + IntegerTrait::<i32>::new(1, false));
+            // all the 1s at or to the left of the bitPos
+            let mask: u256 = ~(1_u256.shl(bit_pos.into()) - 1);
+            let masked: u256 = word & mask;
+
+            // if there are no initialized ticks to the left of the current tick, return leftmost in the word
+            let initialized = masked != 0;
+            // overflow/underflow is possible, but prevented externally by limiting both tickSpacing and tick
+            let next = if initialized {
+                // (compressed + 1 + int24(BitMath::least_significant_bit(masked) - bit_pos)) * tick_spacing
+                (compressed
+                    + IntegerTrait::<i32>::new(1, false)
+                    + (BitMath::least_significant_bit(masked) - bit_pos).into())
+                    * tick_spacing
+            } else {
+                // (compressed + 1 + int24(type(uint8).max - bit_pos)) * tick_spacing
+                let max_u8: u8 = BoundedInt::max();
+                (compressed + IntegerTrait::<i32>::new(1, false) + (max_u8 - bit_pos).into())
+                    * tick_spacing
+            };
+            (next, initialized)
+        }
+    }
+
+    /// Returns `tick / tick_spacing` rounded towards negative infinity, like Solidity's division
+    /// followed by the decrement of negative ticks that aren't spaced
+    fn compress(tick: i32, tick_spacing: i32) -> i32 {
+        // i32 division rounds to the nearest integer, truncate it like Solidity does instead
+        let compressed: i32 = i32_div_no_round(tick, tick_spacing);
+        if tick.sign && tick.mag % tick_spacing.mag != 0 {
+            compressed - IntegerTrait::<i32>::new(1, false) // round towards negative infinity
+        } else {
+            compressed
+        }
+    }
+
+    /// Calculates the word value based on a tick input, i.e. `tick / 256` rounded towards
+    /// negative infinity, so that the word `w` holds the ticks `w * 256` to `w * 256 + 255`.
+    /// - For ticks greater than or equal to 0, it divides the tick by 256.
+    /// - For negative multiples of 256, it divides the tick by 256.
     /// - For other negative ticks, it divides the tick by 256 and subtracts 1.
     ///
     /// Parameters:
@@ -140,20 +183,15 @@ mod TickBitmap {
     ///
     /// Returns: An i16 value representing the calculated word.
     fn calculate_word(tick: i32) -> i16 {
-        let zero = IntegerTrait::<i32>::new(0, false);
-        let one_negative = IntegerTrait::<i32>::new(1, true);
-        let upper_bound = IntegerTrait::<i32>::new(255, false);
-        let divisor = IntegerTrait::<i32>::new(256, false);
-        let negative_lower_bound = IntegerTrait::<i32>::new(256, true);
-
-        let result = if tick >= zero && tick <= upper_bound {
-            zero
-        } else if tick > upper_bound {
-            tick / divisor
-        } else if tick >= negative_lower_bound {
-            one_negative
+        let divisor: u32 = 256;
+
+        // i32 division rounds to the nearest integer, so divide the magnitudes instead
+        let result = if !tick.sign {
+            IntegerTrait::<i32>::new(tick.mag / divisor, false)
+        } else if tick.mag % divisor == 0 {
+            IntegerTrait::<i32>::new(tick.mag / divisor, true)
         } else {
-            tick / divisor + one_negative
+            IntegerTrait::<i32>::new(tick.mag / divisor + 1, true)
         };
         result.try_into().expect('calculate_word')
     }
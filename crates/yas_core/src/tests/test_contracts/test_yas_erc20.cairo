@@ -0,0 +1,189 @@
+mod YASERC20Tests {
+    use starknet::{ContractAddress, get_tx_info};
+
+    use yas_core::contracts::yas_erc20::{
+        ERC20::PERMIT_TYPE_HASH, IERC20Dispatcher, IERC20DispatcherTrait
+    };
+    use yas_core::tests::utils::constants::PoolConstants::{OWNER, WALLET};
+    use yas_core::tests::utils::deployers::Deployers::{
+        deploy_erc20_with_owner, deploy_mock_account
+    };
+    use yas_core::utils::typed_data::TypedData::{
+        StarknetDomain, compute_hash_on_elements, hash_u256, hash_message
+    };
+
+    // Mints the initial supply to WALLET, the token is owned by OWNER
+    fn deploy_erc20(decimals: u8, initial_supply: u256) -> IERC20Dispatcher {
+        deploy_erc20_with_owner('YAS0', '$YAS0', decimals, initial_supply, WALLET(), OWNER())
+    }
+
+    // Returns the signature a MockAccount accepts for the permit, i.e. its message hash
+    fn sign_permit(
+        token: IERC20Dispatcher,
+        owner: ContractAddress,
+        spender: ContractAddress,
+        value: u256,
+        deadline: u256
+    ) -> Array<felt252> {
+        let domain = StarknetDomain {
+            name: token.name(), version: '1', chain_id: get_tx_info().unbox().chain_id
+        };
+        let struct_hash = compute_hash_on_elements(
+            array![
+                PERMIT_TYPE_HASH,
+                spender.into(),
+                hash_u256(value),
+                token.nonces(owner),
+                hash_u256(deadline),
+                token.contract_address.into()
+            ]
+                .span()
+        );
+        array![hash_message(domain, owner, struct_hash)]
+    }
+
+    mod Constructor {
+        use super::{deploy_erc20, OWNER, WALLET};
+
+        use yas_core::contracts::yas_erc20::IERC20DispatcherTrait;
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_sets_decimals_supply_and_owner() {
+            let token = deploy_erc20(6, 1000);
+
+            assert(token.decimals() == 6, 'wrong decimals');
+            assert(token.totalSupply() == 1000, 'wrong total supply');
+            assert(token.balanceOf(WALLET()) == 1000, 'wrong balance');
+            assert(token.owner() == OWNER(), 'wrong owner');
+        }
+    }
+
+    mod MintAndBurn {
+        use super::{deploy_erc20, OWNER, WALLET};
+        use starknet::testing::set_contract_address;
+
+        use yas_core::contracts::yas_erc20::IERC20DispatcherTrait;
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_owner_mints() {
+            let token = deploy_erc20(18, 1000);
+
+            set_contract_address(OWNER());
+            token.mint(WALLET(), 500);
+
+            assert(token.totalSupply() == 1500, 'wrong total supply');
+            assert(token.balanceOf(WALLET()) == 1500, 'wrong balance');
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_owner_burns() {
+            let token = deploy_erc20(18, 1000);
+
+            set_contract_address(OWNER());
+            token.burn(WALLET(), 400);
+
+            assert(token.totalSupply() == 600, 'wrong total supply');
+            assert(token.balanceOf(WALLET()) == 600, 'wrong balance');
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('ERC20: caller is not the owner', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_caller_is_not_owner_on_mint() {
+            let token = deploy_erc20(18, 1000);
+
+            set_contract_address(WALLET());
+            token.mint(WALLET(), 500);
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('ERC20: caller is not the owner', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_caller_is_not_owner_on_burn() {
+            let token = deploy_erc20(18, 1000);
+
+            set_contract_address(WALLET());
+            token.burn(WALLET(), 500);
+        }
+    }
+
+    mod Permit {
+        use super::{deploy_erc20, deploy_mock_account, sign_permit, WALLET};
+        use starknet::testing::set_block_timestamp;
+
+        use yas_core::contracts::yas_erc20::IERC20DispatcherTrait;
+
+        #[test]
+        #[available_gas(200000000)]
+        fn test_sets_the_allowance() {
+            let token = deploy_erc20(18, 1000);
+            let owner = deploy_mock_account();
+            let signature = sign_permit(token, owner, WALLET(), 100, 1);
+
+            token.permit(owner, WALLET(), 100, 1, signature);
+
+            assert(token.allowance(owner, WALLET()) == 100, 'wrong allowance');
+            assert(token.nonces(owner) == 1, 'wrong nonce');
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        #[should_panic(expected: ('ERC20: invalid signature', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_with_an_invalid_signature() {
+            let token = deploy_erc20(18, 1000);
+            let owner = deploy_mock_account();
+
+            token.permit(owner, WALLET(), 100, 1, array!['not a signature']);
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        #[should_panic(expected: ('ERC20: invalid signature', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_with_a_signature_for_another_value() {
+            let token = deploy_erc20(18, 1000);
+            let owner = deploy_mock_account();
+            let signature = sign_permit(token, owner, WALLET(), 100, 1);
+
+            token.permit(owner, WALLET(), 101, 1, signature);
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        #[should_panic(expected: ('ERC20: invalid signature', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_with_a_signature_for_another_token_of_the_same_name() {
+            let token = deploy_erc20(18, 1000);
+            let other_token = deploy_erc20(18, 1000);
+            let owner = deploy_mock_account();
+            let signature = sign_permit(token, owner, WALLET(), 100, 1);
+
+            other_token.permit(owner, WALLET(), 100, 1, signature);
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        #[should_panic(expected: ('ERC20: invalid signature', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_the_signature_is_reused() {
+            let token = deploy_erc20(18, 1000);
+            let owner = deploy_mock_account();
+            let signature = sign_permit(token, owner, WALLET(), 100, 1);
+            token.permit(owner, WALLET(), 100, 1, signature.clone());
+
+            token.permit(owner, WALLET(), 100, 1, signature);
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        #[should_panic(expected: ('ERC20: permit expired', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_deadline_has_passed() {
+            let token = deploy_erc20(18, 1000);
+            let owner = deploy_mock_account();
+            let signature = sign_permit(token, owner, WALLET(), 100, 9);
+
+            set_block_timestamp(10);
+            token.permit(owner, WALLET(), 100, 9, signature);
+        }
+    }
+}
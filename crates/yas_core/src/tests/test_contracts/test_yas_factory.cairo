@@ -23,12 +23,13 @@ mod YASFactoryTests {
     }
 
     // The events emitted by Starknet are queued. In our default constructor, 
-    // we have 4 events (1 OwnerChanged and 3 FeeAmountEnabled). To make this simple, 
-    // it is necessary to 'clean up' these events
+    // we have 8 events (1 OwnerChanged, 1 FeeAmountManagerChanged, 1 ProtocolFeeManagerChanged,
+    // 1 GuardianChanged and 4 FeeAmountEnabled). To make this simple, it is necessary to 'clean up'
+    // these events
     fn clean_events(address: ContractAddress) {
         let mut i = 0;
         loop {
-            if i == 5 {
+            if i == 8 {
                 break;
             }
             pop_log::<OwnerChanged>(address);
@@ -42,8 +43,10 @@ mod YASFactoryTests {
             OTHER, OWNER, ZERO, POOL_CLASS_HASH, FeeAmount, fee_amount, tick_spacing
         };
         use yas_core::contracts::yas_factory::{
-            YASFactory, YASFactory::OwnerChanged, YASFactory::FeeAmountEnabled, IYASFactory,
-            IYASFactoryDispatcher, IYASFactoryDispatcherTrait
+            YASFactory, YASFactory::OwnerChanged, YASFactory::FeeAmountManagerChanged,
+            YASFactory::ProtocolFeeManagerChanged, YASFactory::GuardianChanged,
+            YASFactory::FeeAmountEnabled, IYASFactory, IYASFactoryDispatcher,
+            IYASFactoryDispatcherTrait
         };
         use starknet::{
             contract_address_const, class_hash_const, testing::{set_contract_address, pop_log}
@@ -64,6 +67,16 @@ mod YASFactoryTests {
             assert(yas_factory.owner() == OWNER(), 'Owner doesnt match')
         }
 
+        #[test]
+        #[available_gas(20000000)]
+        fn test_deployer_should_hold_all_roles() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            assert(yas_factory.fee_amount_manager() == OWNER(), 'wrong fee amount manager');
+            assert(yas_factory.protocol_fee_manager() == OWNER(), 'wrong protocol fee manager');
+            assert(yas_factory.guardian() == OWNER(), 'wrong guardian');
+            assert(yas_factory.pending_owner() == ZERO(), 'pending owner should be ZERO');
+        }
+
         #[test]
         #[available_gas(20000000)]
         fn test_initial_enabled_fee_amounts() {
@@ -107,6 +120,18 @@ mod YASFactoryTests {
             assert(event.old_owner == ZERO(), 'event old owner should be ZERO');
             assert(event.new_owner == OWNER(), 'event new owner should be OWNER');
 
+            let event = pop_log::<FeeAmountManagerChanged>(yas_factory.contract_address).unwrap();
+            assert(event.old_manager == ZERO(), 'wrong old fee amount manager');
+            assert(event.new_manager == OWNER(), 'wrong new fee amount manager');
+
+            let event = pop_log::<ProtocolFeeManagerChanged>(yas_factory.contract_address).unwrap();
+            assert(event.old_manager == ZERO(), 'wrong old protocol fee manager');
+            assert(event.new_manager == OWNER(), 'wrong new protocol fee manager');
+
+            let event = pop_log::<GuardianChanged>(yas_factory.contract_address).unwrap();
+            assert(event.old_guardian == ZERO(), 'wrong old guardian');
+            assert(event.new_guardian == OWNER(), 'wrong new guardian');
+
             let event = pop_log::<FeeAmountEnabled>(yas_factory.contract_address).unwrap();
             assert(event.fee == fee_amount(FeeAmount::CUSTOM), 'wrong custom fee event');
             assert(
@@ -147,8 +172,11 @@ mod YASFactoryTests {
             YASFactory, YASFactory::PoolCreated, IYASFactory, IYASFactoryDispatcher,
             IYASFactoryDispatcherTrait
         };
+        use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
         use starknet::testing::{pop_log, set_contract_address};
         use starknet::call_contract_syscall;
+        use poseidon::poseidon_hash_span;
+        use yas_core::utils::typed_data::TypedData::compute_hash_on_elements;
 
         use yas_core::numbers::signed_integer::i32::i32;
 
@@ -298,56 +326,730 @@ mod YASFactoryTests {
             // try create again in other order, this should fail
             yas_factory.create_pool(TOKEN_B(), TOKEN_A(), fee_amount(FeeAmount::LOW));
         }
+
+        #[test]
+        #[available_gas(200000000)]
+        fn test_success_for_all_fee_tiers_of_the_same_pair() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+
+            let custom = fee_amount(FeeAmount::CUSTOM);
+            let low = fee_amount(FeeAmount::LOW);
+            let medium = fee_amount(FeeAmount::MEDIUM);
+            let high = fee_amount(FeeAmount::HIGH);
+
+            let custom_pool = yas_factory.create_pool(TOKEN_A(), TOKEN_B(), custom);
+            let low_pool = yas_factory.create_pool(TOKEN_A(), TOKEN_B(), low);
+            let medium_pool = yas_factory.create_pool(TOKEN_B(), TOKEN_A(), medium);
+            let high_pool = yas_factory.create_pool(TOKEN_B(), TOKEN_A(), high);
+
+            assert(custom_pool != low_pool, 'custom and low pools collide');
+            assert(custom_pool != medium_pool, 'custom and medium pools collide');
+            assert(custom_pool != high_pool, 'custom and high pools collide');
+            assert(low_pool != medium_pool, 'low and medium pools collide');
+            assert(low_pool != high_pool, 'low and high pools collide');
+            assert(medium_pool != high_pool, 'medium and high pools collide');
+
+            assert(
+                yas_factory.pool(TOKEN_B(), TOKEN_A(), custom) == custom_pool, 'wrong custom pool'
+            );
+            assert(yas_factory.pool(TOKEN_B(), TOKEN_A(), low) == low_pool, 'wrong low pool');
+            assert(
+                yas_factory.pool(TOKEN_A(), TOKEN_B(), medium) == medium_pool, 'wrong medium pool'
+            );
+            assert(yas_factory.pool(TOKEN_A(), TOKEN_B(), high) == high_pool, 'wrong high pool');
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        fn test_pools_keep_their_fee_tier() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+
+            let low_pool = IYASPoolDispatcher {
+                contract_address: yas_factory
+                    .create_pool(TOKEN_A(), TOKEN_B(), fee_amount(FeeAmount::LOW))
+            };
+            let high_pool = IYASPoolDispatcher {
+                contract_address: yas_factory
+                    .create_pool(TOKEN_A(), TOKEN_B(), fee_amount(FeeAmount::HIGH))
+            };
+
+            assert(low_pool.get_fee() == fee_amount(FeeAmount::LOW), 'wrong low pool fee');
+            assert(
+                low_pool.get_tick_spacing().mag == tick_spacing(FeeAmount::LOW),
+                'wrong low pool tick_spacing'
+            );
+            assert(high_pool.get_fee() == fee_amount(FeeAmount::HIGH), 'wrong high pool fee');
+            assert(
+                high_pool.get_tick_spacing().mag == tick_spacing(FeeAmount::HIGH),
+                'wrong high pool tick_spacing'
+            );
+        }
+
+        // Same vector as the known-answer tests of deprecated_scripts/src/pool_address.rs
+        #[test]
+        #[available_gas(200000000)]
+        fn test_deploys_the_pool_at_the_offline_address() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+
+            let pool: felt252 = yas_factory.create_pool(TOKEN_B(), TOKEN_A(), 3000).into();
+
+            let token_0: felt252 = TOKEN_A().into();
+            let token_1: felt252 = TOKEN_B().into();
+            let salt = poseidon_hash_span(array![token_0, token_1, 3000, 60, 0].span());
+            assert(
+                salt == 0x6096017e1fc867b9b7fb94ad38660c217ecda43b66deceae8a098f5e57adf77,
+                'wrong salt'
+            );
+            let factory: felt252 = yas_factory.contract_address.into();
+            let calldata_hash = compute_hash_on_elements(
+                array![factory, token_0, token_1, 3000, 60, 0].span()
+            );
+            let address = compute_hash_on_elements(
+                array![
+                    'STARKNET_CONTRACT_ADDRESS',
+                    factory,
+                    salt,
+                    POOL_CLASS_HASH().into(),
+                    calldata_hash
+                ]
+                    .span()
+            );
+            assert(pool == address, 'wrong pool address');
+        }
     }
 
-    mod SetOwner {
+    mod Ownership {
         use super::{clean_events, deploy};
-        use yas_core::tests::utils::constants::FactoryConstants::{OTHER, OWNER, POOL_CLASS_HASH};
+        use yas_core::tests::utils::constants::FactoryConstants::{
+            OTHER, OWNER, ZERO, POOL_CLASS_HASH
+        };
         use yas_core::contracts::yas_factory::{
-            YASFactory, YASFactory::OwnerChanged, IYASFactory, IYASFactoryDispatcher,
-            IYASFactoryDispatcherTrait
+            YASFactory, YASFactory::OwnerChanged, YASFactory::OwnershipTransferStarted, IYASFactory,
+            IYASFactoryDispatcher, IYASFactoryDispatcherTrait
         };
         use starknet::testing::{pop_log, set_contract_address};
-        use yas_core::numbers::signed_integer::i32::i32;
 
         #[test]
         #[available_gas(20000000)]
         #[should_panic(expected: ('only owner can do this action!', 'ENTRYPOINT_FAILED'))]
-        fn test_fails_if_caller_is_not_owner() {
+        fn test_transfer_fails_if_caller_is_not_owner() {
             let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
             set_contract_address(OTHER());
-            yas_factory.set_owner(OTHER());
+            yas_factory.transfer_ownership(OTHER());
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('new owner cannot be zero', 'ENTRYPOINT_FAILED'))]
+        fn test_transfer_fails_if_new_owner_is_zero() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.transfer_ownership(ZERO());
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_transfer_does_not_change_the_owner() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+
+            yas_factory.transfer_ownership(OTHER());
+            assert(yas_factory.owner() == OWNER(), 'owner should still be OWNER');
+            assert(yas_factory.pending_owner() == OTHER(), 'pending owner should be OTHER');
         }
 
         #[test]
         #[available_gas(20000000)]
-        fn test_success_when_caller_is_owner() {
+        #[should_panic(expected: ('only pending owner can accept', 'ENTRYPOINT_FAILED'))]
+        fn test_accept_fails_if_caller_is_not_pending_owner() {
             set_contract_address(OWNER());
             let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.transfer_ownership(OTHER());
 
-            // Set and read new owner
-            yas_factory.set_owner(OTHER());
+            yas_factory.accept_ownership();
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_accept_when_caller_is_pending_owner() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.transfer_ownership(OTHER());
+
+            set_contract_address(OTHER());
+            yas_factory.accept_ownership();
             assert(yas_factory.owner() == OTHER(), 'new owner should be OTHER');
+            assert(yas_factory.pending_owner() == ZERO(), 'pending owner should be ZERO');
         }
 
         #[test]
         #[available_gas(20000000)]
-        fn test_emits_events() {
+        fn test_renounce_ownership() {
             set_contract_address(OWNER());
             let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.transfer_ownership(OTHER());
 
-            // Clean up the 4 events emitted by the deploy
+            yas_factory.renounce_ownership();
+            assert(yas_factory.owner() == ZERO(), 'owner should be ZERO');
+            assert(yas_factory.pending_owner() == ZERO(), 'pending owner should be ZERO');
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only owner can do this action!', 'ENTRYPOINT_FAILED'))]
+        fn test_renounce_fails_if_caller_is_not_owner() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+            yas_factory.renounce_ownership();
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_emits_events() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
             clean_events(yas_factory.contract_address);
 
-            yas_factory.set_owner(OTHER());
+            yas_factory.transfer_ownership(OTHER());
+
+            let event = pop_log::<OwnershipTransferStarted>(yas_factory.contract_address).unwrap();
+            assert(event.previous_owner == OWNER(), 'wrong event previous owner');
+            assert(event.new_owner == OTHER(), 'event new owner should be OTHER');
+
+            set_contract_address(OTHER());
+            yas_factory.accept_ownership();
 
-            // Verify OwnerChanged event emitted
             let event = pop_log::<OwnerChanged>(yas_factory.contract_address).unwrap();
             assert(event.old_owner == OWNER(), 'event old owner should be OWNER');
             assert(event.new_owner == OTHER(), 'event new owner should be OTHER');
         }
     }
 
+    mod Roles {
+        use super::{clean_events, deploy};
+        use yas_core::tests::utils::constants::FactoryConstants::{
+            OTHER, OWNER, ZERO, POOL_CLASS_HASH
+        };
+        use yas_core::contracts::yas_factory::{
+            YASFactory, YASFactory::FeeAmountManagerChanged, YASFactory::ProtocolFeeManagerChanged,
+            IYASFactory, IYASFactoryDispatcher, IYASFactoryDispatcherTrait
+        };
+        use starknet::testing::{pop_log, set_contract_address};
+        use yas_core::numbers::signed_integer::{integer_trait::IntegerTrait, i32::i32};
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only owner can do this action!', 'ENTRYPOINT_FAILED'))]
+        fn test_set_fee_amount_manager_fails_if_caller_is_not_owner() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+            yas_factory.set_fee_amount_manager(OTHER());
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('new manager cannot be zero', 'ENTRYPOINT_FAILED'))]
+        fn test_set_fee_amount_manager_fails_if_manager_is_zero() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.set_fee_amount_manager(ZERO());
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only owner can do this action!', 'ENTRYPOINT_FAILED'))]
+        fn test_set_protocol_fee_manager_fails_if_caller_is_not_owner() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+            yas_factory.set_protocol_fee_manager(OTHER());
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_fee_amount_manager_can_enable_fee_amounts() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.set_fee_amount_manager(OTHER());
+
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(250, IntegerTrait::<i32>::new(15, false));
+            assert(
+                yas_factory.fee_amount_tick_spacing(250) == IntegerTrait::<i32>::new(15, false),
+                'fee amount should be enabled'
+            );
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only fee amount manager', 'ENTRYPOINT_FAILED'))]
+        fn test_owner_can_not_enable_fee_amounts_after_handing_over_the_role() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.set_fee_amount_manager(OTHER());
+
+            yas_factory.enable_fee_amount(250, IntegerTrait::<i32>::new(15, false));
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_roles_survive_an_ownership_transfer() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.set_protocol_fee_manager(OTHER());
+            yas_factory.transfer_ownership(OTHER());
+
+            set_contract_address(OTHER());
+            yas_factory.accept_ownership();
+            assert(yas_factory.fee_amount_manager() == OWNER(), 'wrong fee amount manager');
+            assert(yas_factory.protocol_fee_manager() == OTHER(), 'wrong protocol fee manager');
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_emits_events() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            clean_events(yas_factory.contract_address);
+
+            yas_factory.set_fee_amount_manager(OTHER());
+            let event = pop_log::<FeeAmountManagerChanged>(yas_factory.contract_address).unwrap();
+            assert(event.old_manager == OWNER(), 'event old mgr should be OWNER');
+            assert(event.new_manager == OTHER(), 'event new mgr should be OTHER');
+
+            yas_factory.set_protocol_fee_manager(OTHER());
+            let event = pop_log::<ProtocolFeeManagerChanged>(yas_factory.contract_address)
+                .unwrap();
+            assert(event.old_manager == OWNER(), 'event old mgr should be OWNER');
+            assert(event.new_manager == OTHER(), 'event new mgr should be OTHER');
+        }
+    }
+
+    mod Pause {
+        use super::{clean_events, deploy};
+        use yas_core::tests::utils::constants::FactoryConstants::{
+            OTHER, OWNER, ZERO, POOL_CLASS_HASH
+        };
+        use yas_core::contracts::yas_factory::{
+            YASFactory, YASFactory::GuardianChanged, YASFactory::Paused, YASFactory::Unpaused,
+            IYASFactory, IYASFactoryDispatcher, IYASFactoryDispatcherTrait
+        };
+        use starknet::testing::{pop_log, set_contract_address};
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only owner can do this action!', 'ENTRYPOINT_FAILED'))]
+        fn test_set_guardian_fails_if_caller_is_not_owner() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+            yas_factory.set_guardian(OTHER());
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('new guardian cannot be zero', 'ENTRYPOINT_FAILED'))]
+        fn test_set_guardian_fails_if_guardian_is_zero() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.set_guardian(ZERO());
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only guardian', 'ENTRYPOINT_FAILED'))]
+        fn test_pause_fails_if_caller_is_not_guardian() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.set_guardian(OTHER());
+
+            yas_factory.pause();
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('already paused', 'ENTRYPOINT_FAILED'))]
+        fn test_pause_fails_if_already_paused() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.pause();
+            yas_factory.pause();
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('not paused', 'ENTRYPOINT_FAILED'))]
+        fn test_unpause_fails_if_not_paused() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.unpause();
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_guardian_pauses_and_unpauses() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.set_guardian(OTHER());
+
+            set_contract_address(OTHER());
+            yas_factory.pause();
+            assert(yas_factory.is_paused(), 'factory should be paused');
+
+            yas_factory.unpause();
+            assert(!yas_factory.is_paused(), 'factory should not be paused');
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_emits_events() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            clean_events(yas_factory.contract_address);
+
+            yas_factory.set_guardian(OTHER());
+            let event = pop_log::<GuardianChanged>(yas_factory.contract_address).unwrap();
+            assert(event.old_guardian == OWNER(), 'wrong old guardian');
+            assert(event.new_guardian == OTHER(), 'wrong new guardian');
+
+            set_contract_address(OTHER());
+            yas_factory.pause();
+            let event = pop_log::<Paused>(yas_factory.contract_address).unwrap();
+            assert(event.account == OTHER(), 'wrong Paused account');
+
+            yas_factory.unpause();
+            let event = pop_log::<Unpaused>(yas_factory.contract_address).unwrap();
+            assert(event.account == OTHER(), 'wrong Unpaused account');
+        }
+    }
+
+    mod PermissionlessFeeAmounts {
+        use super::{clean_events, deploy};
+        use yas_core::tests::utils::constants::FactoryConstants::{
+            OTHER, OWNER, POOL_CLASS_HASH, TOKEN_A, TOKEN_B
+        };
+        use yas_core::contracts::yas_factory::{
+            YASFactory, YASFactory::FeeAmountEnabled, YASFactory::PermissionlessFeeAmountsSet,
+            YASFactory::PermissionlessMinLiquiditySet, YASFactory::TickSpacingAllowed, IYASFactory,
+            IYASFactoryDispatcher, IYASFactoryDispatcherTrait
+        };
+        use starknet::testing::{pop_log, set_contract_address};
+        use yas_core::numbers::signed_integer::{integer_trait::IntegerTrait, i32::i32};
+
+        fn setup() -> IYASFactoryDispatcher {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.set_permissionless_fee_amounts(true);
+            yas_factory.set_tick_spacing_allowed(IntegerTrait::<i32>::new(1, false), true);
+            yas_factory
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only owner can do this action!', 'ENTRYPOINT_FAILED'))]
+        fn test_set_permissionless_fails_if_caller_is_not_owner() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+            yas_factory.set_permissionless_fee_amounts(true);
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only owner can do this action!', 'ENTRYPOINT_FAILED'))]
+        fn test_set_tick_spacing_allowed_fails_if_caller_is_not_owner() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+            yas_factory.set_tick_spacing_allowed(IntegerTrait::<i32>::new(1, false), true);
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('wrong tick_spacing (0<ts<16384)', 'ENTRYPOINT_FAILED'))]
+        fn test_set_tick_spacing_allowed_fails_if_tick_spacing_is_invalid() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.set_tick_spacing_allowed(IntegerTrait::<i32>::new(16384, false), true);
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only fee amount manager', 'ENTRYPOINT_FAILED'))]
+        fn test_enable_fails_if_permissionless_mode_is_off() {
+            let yas_factory = setup();
+            yas_factory.set_permissionless_fee_amounts(false);
+
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(100, IntegerTrait::<i32>::new(1, false));
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('tick spacing not allowed', 'ENTRYPOINT_FAILED'))]
+        fn test_enable_fails_if_tick_spacing_is_not_allowed() {
+            let yas_factory = setup();
+
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(3, false));
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('tick spacing not allowed', 'ENTRYPOINT_FAILED'))]
+        fn test_enable_fails_if_tick_spacing_was_disallowed() {
+            let yas_factory = setup();
+            yas_factory.set_tick_spacing_allowed(IntegerTrait::<i32>::new(1, false), false);
+
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(1, false));
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('fee amount already initialized', 'ENTRYPOINT_FAILED'))]
+        fn test_enable_fails_if_fee_amount_is_already_enabled() {
+            let yas_factory = setup();
+
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(100, IntegerTrait::<i32>::new(1, false));
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('fee cannot be gt 1000000', 'ENTRYPOINT_FAILED'))]
+        fn test_enable_fails_if_fee_is_too_large() {
+            let yas_factory = setup();
+
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(1000000, IntegerTrait::<i32>::new(1, false));
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        fn test_anyone_enables_a_fee_amount_with_an_allowed_tick_spacing() {
+            let yas_factory = setup();
+            clean_events(yas_factory.contract_address);
+            // skip the PermissionlessFeeAmountsSet and TickSpacingAllowed events
+            pop_log::<PermissionlessFeeAmountsSet>(yas_factory.contract_address);
+            pop_log::<TickSpacingAllowed>(yas_factory.contract_address);
+
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(1, false));
+            assert(
+                yas_factory.fee_amount_tick_spacing(150) == IntegerTrait::<i32>::new(1, false),
+                'fee amount should be enabled'
+            );
+
+            let event = pop_log::<FeeAmountEnabled>(yas_factory.contract_address).unwrap();
+            assert(event.fee == 150, 'event fee should be 150');
+            assert(
+                event.tick_spacing == IntegerTrait::<i32>::new(1, false),
+                'tick_spacing event should be 1'
+            );
+
+            let pool = yas_factory.create_pool(TOKEN_A(), TOKEN_B(), 150);
+            assert(yas_factory.pool(TOKEN_A(), TOKEN_B(), 150) == pool, 'wrong pool');
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_fee_amount_manager_overrides_a_squatted_fee_amount() {
+            let yas_factory = setup();
+
+            // squat the fee amount with the only allowed tick spacing
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(1, false));
+
+            set_contract_address(OWNER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(3, false));
+            assert(
+                yas_factory.fee_amount_tick_spacing(150) == IntegerTrait::<i32>::new(3, false),
+                'tick spacing not overridden'
+            );
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('fee amount already initialized', 'ENTRYPOINT_FAILED'))]
+        fn test_enable_fails_if_fee_amount_was_enabled_in_permissionless_mode() {
+            let yas_factory = setup();
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(1, false));
+
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(1, false));
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('fee amount already initialized', 'ENTRYPOINT_FAILED'))]
+        fn test_override_fails_if_fee_amount_was_already_overridden() {
+            let yas_factory = setup();
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(1, false));
+            set_contract_address(OWNER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(3, false));
+
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(5, false));
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        #[should_panic(expected: ('fee amount already has pools', 'ENTRYPOINT_FAILED'))]
+        fn test_override_fails_if_a_pool_was_created_with_the_fee_amount() {
+            let yas_factory = setup();
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(1, false));
+            yas_factory.create_pool(TOKEN_A(), TOKEN_B(), 150);
+
+            set_contract_address(OWNER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(3, false));
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only owner can do this action!', 'ENTRYPOINT_FAILED'))]
+        fn test_set_min_liquidity_fails_if_caller_is_not_owner() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+            yas_factory.set_permissionless_min_liquidity(1000);
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_min_liquidity_only_applies_to_permissionless_fee_amounts() {
+            let yas_factory = setup();
+            yas_factory.set_permissionless_min_liquidity(1000);
+            assert(yas_factory.permissionless_min_liquidity() == 1000, 'wrong min liquidity');
+            assert(yas_factory.fee_amount_min_liquidity(3000) == 0, 'manager fee has no minimum');
+
+            set_contract_address(OTHER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(1, false));
+            assert(yas_factory.fee_amount_min_liquidity(150) == 1000, 'fee should have a minimum');
+
+            // the override hands the fee amount over to the fee amount manager
+            set_contract_address(OWNER());
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(3, false));
+            assert(yas_factory.fee_amount_min_liquidity(150) == 0, 'override has no minimum');
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_emits_events() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            clean_events(yas_factory.contract_address);
+
+            yas_factory.set_permissionless_fee_amounts(true);
+            let event = pop_log::<PermissionlessFeeAmountsSet>(yas_factory.contract_address)
+                .unwrap();
+            assert(event.enabled, 'event enabled should be true');
+
+            yas_factory.set_tick_spacing_allowed(IntegerTrait::<i32>::new(1, false), true);
+            let event = pop_log::<TickSpacingAllowed>(yas_factory.contract_address).unwrap();
+            assert(
+                event.tick_spacing == IntegerTrait::<i32>::new(1, false), 'wrong event tick_spacing'
+            );
+            assert(event.allowed, 'event allowed should be true');
+
+            yas_factory.set_permissionless_min_liquidity(1000);
+            let event = pop_log::<PermissionlessMinLiquiditySet>(yas_factory.contract_address)
+                .unwrap();
+            assert(event.min_liquidity == 1000, 'wrong event min_liquidity');
+        }
+    }
+
+    mod SetPoolClassHash {
+        use super::{clean_events, deploy};
+        use yas_core::tests::utils::constants::FactoryConstants::{
+            OTHER, OWNER, POOL_CLASS_HASH, TOKEN_A, TOKEN_B, FeeAmount, fee_amount
+        };
+        use yas_core::contracts::yas_factory::{
+            YASFactory, YASFactory::PoolClassHashChanged, IYASFactory, IYASFactoryDispatcher,
+            IYASFactoryDispatcherTrait
+        };
+        use yas_core::tests::utils::mock_yas_pool_v2::{
+            MockYASPoolV2, IMockYASPoolV2Dispatcher, IMockYASPoolV2DispatcherTrait
+        };
+        use starknet::{ClassHash, class_hash_const};
+        use starknet::testing::{pop_log, set_contract_address};
+
+        fn V2_CLASS_HASH() -> ClassHash {
+            MockYASPoolV2::TEST_CLASS_HASH.try_into().unwrap()
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('only owner can do this action!', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_caller_is_not_owner() {
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            set_contract_address(OTHER());
+            yas_factory.set_pool_class_hash(V2_CLASS_HASH());
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        #[should_panic(expected: ('pool class hash can not be zero', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_class_hash_is_zero() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            yas_factory.set_pool_class_hash(class_hash_const::<0>());
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        fn test_new_pools_are_deployed_from_the_new_class() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            let old_pool = yas_factory
+                .create_pool(TOKEN_A(), TOKEN_B(), fee_amount(FeeAmount::LOW));
+
+            yas_factory.set_pool_class_hash(V2_CLASS_HASH());
+            assert(yas_factory.pool_class_hash() == V2_CLASS_HASH(), 'wrong pool class hash');
+
+            let new_pool = IMockYASPoolV2Dispatcher {
+                contract_address: yas_factory
+                    .create_pool(TOKEN_A(), TOKEN_B(), fee_amount(FeeAmount::MEDIUM))
+            };
+            assert(new_pool.version() == 2, 'pool deployed from old class');
+            assert(new_pool.factory() == yas_factory.contract_address, 'wrong pool factory');
+            assert(
+                yas_factory.pool(TOKEN_A(), TOKEN_B(), fee_amount(FeeAmount::LOW)) == old_pool,
+                'old pool should be kept'
+            );
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_keeps_the_previous_class_hashes() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            assert(
+                yas_factory.pool_class_hashes() == array![POOL_CLASS_HASH()], 'wrong initial hashes'
+            );
+
+            yas_factory.set_pool_class_hash(V2_CLASS_HASH());
+
+            assert(
+                yas_factory.pool_class_hashes() == array![POOL_CLASS_HASH(), V2_CLASS_HASH()],
+                'wrong pool class hashes'
+            );
+        }
+
+        #[test]
+        #[available_gas(20000000)]
+        fn test_emits_event() {
+            set_contract_address(OWNER());
+            let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
+            clean_events(yas_factory.contract_address);
+
+            yas_factory.set_pool_class_hash(V2_CLASS_HASH());
+
+            let event = pop_log::<PoolClassHashChanged>(yas_factory.contract_address).unwrap();
+            assert(event.old_class_hash == POOL_CLASS_HASH(), 'wrong old class hash');
+            assert(event.new_class_hash == V2_CLASS_HASH(), 'wrong new class hash');
+        }
+    }
+
     mod SetEnableFeeAmount {
         use super::{clean_events, deploy};
         use yas_core::tests::utils::constants::FactoryConstants::{
@@ -362,8 +1064,8 @@ mod YASFactoryTests {
 
         #[test]
         #[available_gas(20000000)]
-        #[should_panic(expected: ('only owner can do this action!', 'ENTRYPOINT_FAILED'))]
-        fn test_fails_if_caller_is_not_owner() {
+        #[should_panic(expected: ('only fee amount manager', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_caller_is_not_fee_amount_manager() {
             let yas_factory = deploy(OWNER(), POOL_CLASS_HASH());
             set_contract_address(OTHER());
             yas_factory.enable_fee_amount(100, IntegerTrait::<i32>::new(2, false));
@@ -1,6 +1,7 @@
 mod YASPoolTests {
-    use starknet::{contract_address_const, ContractAddress};
+    use starknet::{contract_address_const, get_contract_address, ContractAddress};
     use starknet::syscalls::deploy_syscall;
+    use starknet::testing::set_contract_address;
     use integer::BoundedInt;
 
     use yas_core::contracts::yas_pool::{
@@ -31,10 +32,14 @@ mod YASPoolTests {
         calldata.append(fee.into());
         Serde::serialize(@tick_spacing, ref calldata);
 
+        // only the factory is allowed to deploy pools
+        let caller = get_contract_address();
+        set_contract_address(factory);
         let (address, _) = deploy_syscall(
             YASPool::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), true
         )
             .expect('DEPLOY_FAILED');
+        set_contract_address(caller);
 
         return IYASPoolDispatcher { contract_address: address };
     }
@@ -42,13 +47,17 @@ mod YASPoolTests {
     mod Constructor {
         use super::deploy;
 
-        use starknet::{contract_address_const, ContractAddress};
+        use starknet::{contract_address_const, ContractAddress, SyscallResultTrait};
+        use starknet::syscalls::deploy_syscall;
+        use starknet::testing::set_contract_address;
 
         use yas_core::contracts::yas_pool::{
             YASPool, IYASPool, IYASPoolDispatcher, IYASPoolDispatcherTrait
         };
         use yas_core::numbers::signed_integer::{i32::i32, integer_trait::IntegerTrait};
-        use yas_core::tests::utils::constants::PoolConstants::{FACTORY_ADDRESS, TOKEN_A, TOKEN_B};
+        use yas_core::tests::utils::constants::PoolConstants::{
+            FACTORY_ADDRESS, TOKEN_A, TOKEN_B, OTHER
+        };
 
         #[test]
         #[available_gas(2000000000000)]
@@ -57,6 +66,20 @@ mod YASPoolTests {
             let tick_spacing = IntegerTrait::<i32>::new(1, false);
             let yas_pool = deploy(FACTORY_ADDRESS(), TOKEN_A(), TOKEN_B(), fee, tick_spacing);
         }
+
+        #[test]
+        #[available_gas(2000000000000)]
+        #[should_panic(expected: ('only factory can deploy', 'CONSTRUCTOR_FAILED'))]
+        fn test_fails_if_caller_is_not_the_factory() {
+            let mut calldata = array![
+                FACTORY_ADDRESS().into(), TOKEN_A().into(), TOKEN_B().into(), 5
+            ];
+            Serde::serialize(@IntegerTrait::<i32>::new(1, false), ref calldata);
+
+            set_contract_address(OTHER());
+            deploy_syscall(YASPool::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), true)
+                .unwrap_syscall();
+        }
     }
 
     mod Initialize {
@@ -1713,6 +1736,110 @@ mod YASPoolTests {
         }
     }
 
+    mod SwapAcrossNegativeWords {
+        use super::{setup_pool_for_swap_test, get_min_tick_and_max_tick};
+
+        use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+        use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+        use yas_core::libraries::tick_math::TickMath::get_sqrt_ratio_at_tick;
+        use yas_core::numbers::signed_integer::{i32::i32, i256::i256, integer_trait::IntegerTrait};
+        use yas_core::tests::utils::constants::FactoryConstants::{fee_amount, FeeAmount};
+        use yas_core::tests::utils::constants::PoolConstants::WALLET;
+        use yas_core::tests::utils::swap_cases::SwapTestHelper::Position;
+
+        const LIQUIDITY: u128 = 1000000000000000000;
+
+        // Creates a pool with a tick spacing of 60 at `tick`, with a full range position and a
+        // position in [tick_lower, tick_upper], both of LIQUIDITY
+        fn setup(
+            tick: i32, tick_lower: i32, tick_upper: i32
+        ) -> (IYASPoolDispatcher, IYASRouterDispatcher) {
+            let (min_tick, max_tick) = get_min_tick_and_max_tick();
+            let positions = array![
+                Position { tick_lower: min_tick, tick_upper: max_tick, liquidity: LIQUIDITY },
+                Position { tick_lower, tick_upper, liquidity: LIQUIDITY }
+            ];
+            let (yas_pool, yas_router, _, _) = setup_pool_for_swap_test(
+                get_sqrt_ratio_at_tick(tick), fee_amount(FeeAmount::MEDIUM), @positions
+            );
+            (yas_pool, yas_router)
+        }
+
+        // Swaps until the price of `tick` is reached, with more than enough of the input token
+        fn swap_to_tick(
+            yas_router: IYASRouterDispatcher, yas_pool: IYASPoolDispatcher, tick: i32
+        ) {
+            let zero_for_one = tick < yas_pool.get_slot_0().tick;
+            yas_router
+                .swap(
+                    yas_pool.contract_address,
+                    WALLET(),
+                    zero_for_one,
+                    IntegerTrait::<i256>::new(100000000000000000000, false),
+                    get_sqrt_ratio_at_tick(tick)
+                );
+            assert(
+                yas_pool.get_slot_0().sqrt_price_X96.mag == get_sqrt_ratio_at_tick(tick).mag,
+                'price limit not reached'
+            );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_crosses_the_spaced_tick_below_a_negative_tick() {
+            let (yas_pool, yas_router) = setup(
+                IntegerTrait::<i32>::new(0, false),
+                IntegerTrait::<i32>::new(60, true),
+                IntegerTrait::<i32>::new(60, false)
+            );
+
+            // -40 compresses to -1, i.e. -60, which must be crossed on the way to -90
+            swap_to_tick(yas_router, yas_pool, IntegerTrait::<i32>::new(40, true));
+            assert(yas_pool.get_liquidity() == 2 * LIQUIDITY, 'wrong liquidity at -40');
+
+            swap_to_tick(yas_router, yas_pool, IntegerTrait::<i32>::new(90, true));
+            assert(yas_pool.get_liquidity() == LIQUIDITY, 'wrong liquidity at -90');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_does_not_cross_the_spaced_tick_below_a_negative_tick_upwards() {
+            let (yas_pool, yas_router) = setup(
+                IntegerTrait::<i32>::new(0, false),
+                IntegerTrait::<i32>::new(60, true),
+                IntegerTrait::<i32>::new(60, false)
+            );
+
+            swap_to_tick(yas_router, yas_pool, IntegerTrait::<i32>::new(40, true));
+            swap_to_tick(yas_router, yas_pool, IntegerTrait::<i32>::new(30, false));
+
+            assert(yas_pool.get_liquidity() == 2 * LIQUIDITY, 'wrong liquidity at 30');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_crosses_ticks_of_a_negative_word_both_ways() {
+            // compressed, -21000 and -24840 are -350 and -414, both in the word -2
+            let (yas_pool, yas_router) = setup(
+                IntegerTrait::<i32>::new(18000, true),
+                IntegerTrait::<i32>::new(24840, true),
+                IntegerTrait::<i32>::new(21000, true)
+            );
+
+            swap_to_tick(yas_router, yas_pool, IntegerTrait::<i32>::new(22800, true));
+            assert(yas_pool.get_liquidity() == 2 * LIQUIDITY, 'wrong liquidity at -22800');
+
+            swap_to_tick(yas_router, yas_pool, IntegerTrait::<i32>::new(25200, true));
+            assert(yas_pool.get_liquidity() == LIQUIDITY, 'wrong liquidity at -25200');
+
+            swap_to_tick(yas_router, yas_pool, IntegerTrait::<i32>::new(22800, true));
+            assert(yas_pool.get_liquidity() == 2 * LIQUIDITY, 'wrong liquidity back at -22800');
+
+            swap_to_tick(yas_router, yas_pool, IntegerTrait::<i32>::new(18000, true));
+            assert(yas_pool.get_liquidity() == LIQUIDITY, 'wrong liquidity back at -18000');
+        }
+    }
+
     mod Burn {
         use super::{setup, get_min_tick_and_max_tick};
 
@@ -1883,6 +2010,758 @@ mod YASPoolTests {
         }
     }
 
+    mod Collect {
+        use super::setup;
+
+        use integer::BoundedInt;
+        use starknet::testing::set_contract_address;
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+        use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+        use yas_core::libraries::position::PositionKey;
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_collects_all_tokens_owed_after_burn() {
+            let (yas_pool, token_0, token_1, yas_router, min_tick, max_tick) = setup();
+            yas_router.mint(yas_pool.contract_address, OTHER(), min_tick, max_tick, 1000000);
+
+            set_contract_address(OTHER());
+            let (amount_0, amount_1) = yas_pool.burn(min_tick, max_tick, 1000000);
+
+            let (amount_0_collected, amount_1_collected) = yas_pool
+                .collect(OTHER(), min_tick, max_tick, BoundedInt::max(), BoundedInt::max());
+
+            assert(amount_0_collected.into() == amount_0, 'wrong amount_0 collected');
+            assert(amount_1_collected.into() == amount_1, 'wrong amount_1 collected');
+            assert(token_0.balanceOf(OTHER()) == amount_0, 'wrong token_0 balance');
+            assert(token_1.balanceOf(OTHER()) == amount_1, 'wrong token_1 balance');
+
+            let info_position = yas_pool
+                .get_position(
+                    PositionKey { owner: OTHER(), tick_lower: min_tick, tick_upper: max_tick }
+                );
+            assert(info_position.tokens_owed_0 == 0, 'wrong tokens_owed_0');
+            assert(info_position.tokens_owed_1 == 0, 'wrong tokens_owed_1');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_collects_only_the_requested_amounts() {
+            let (yas_pool, token_0, token_1, yas_router, min_tick, max_tick) = setup();
+            yas_router.mint(yas_pool.contract_address, OTHER(), min_tick, max_tick, 1000000);
+
+            set_contract_address(OTHER());
+            let (amount_0, amount_1) = yas_pool.burn(min_tick, max_tick, 1000000);
+
+            let (amount_0_collected, amount_1_collected) = yas_pool
+                .collect(WALLET(), min_tick, max_tick, 1, 0);
+
+            assert(amount_0_collected == 1, 'wrong amount_0 collected');
+            assert(amount_1_collected == 0, 'wrong amount_1 collected');
+
+            let info_position = yas_pool
+                .get_position(
+                    PositionKey { owner: OTHER(), tick_lower: min_tick, tick_upper: max_tick }
+                );
+            assert(info_position.tokens_owed_0.into() == amount_0 - 1, 'wrong tokens_owed_0');
+            assert(info_position.tokens_owed_1.into() == amount_1, 'wrong tokens_owed_1');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_collects_nothing_for_other_owners() {
+            let (yas_pool, token_0, token_1, yas_router, min_tick, max_tick) = setup();
+            yas_router.mint(yas_pool.contract_address, OTHER(), min_tick, max_tick, 1000000);
+
+            set_contract_address(OTHER());
+            yas_pool.burn(min_tick, max_tick, 1000000);
+
+            // tokens are owed to OTHER(), not to WALLET()
+            set_contract_address(WALLET());
+            let (amount_0_collected, amount_1_collected) = yas_pool
+                .collect(WALLET(), min_tick, max_tick, BoundedInt::max(), BoundedInt::max());
+
+            assert(amount_0_collected == 0, 'wrong amount_0 collected');
+            assert(amount_1_collected == 0, 'wrong amount_1 collected');
+        }
+    }
+
+    mod Getters {
+        use super::setup;
+
+        use starknet::testing::set_contract_address;
+
+        use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
+        use yas_core::contracts::yas_pool::IYASPoolDispatcherTrait;
+        use yas_core::contracts::yas_router::IYASRouterDispatcherTrait;
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::FP64x96PartialEq;
+        use yas_core::numbers::signed_integer::{i32::i32, integer_trait::IntegerTrait};
+        use yas_core::tests::utils::constants::FactoryConstants::{FeeAmount, fee_amount};
+        use yas_core::tests::utils::constants::PoolConstants::{OWNER, WALLET};
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_returns_the_pool_immutables() {
+            let (yas_pool, token_0, token_1, yas_router, _, _) = setup();
+
+            assert(yas_pool.get_factory() == yas_router.get_factory(), 'wrong factory');
+            assert(yas_pool.get_fee() == fee_amount(FeeAmount::MEDIUM), 'wrong fee');
+            assert(yas_pool.token_0() == token_0.contract_address, 'wrong token_0');
+            assert(yas_pool.token_1() == token_1.contract_address, 'wrong token_1');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_returns_the_in_range_liquidity() {
+            let (yas_pool, _, _, yas_router, _, _) = setup();
+            assert(yas_pool.get_liquidity() == 3161, 'wrong liquidity');
+
+            // out of range positions do not add active liquidity
+            yas_router
+                .mint(
+                    yas_pool.contract_address,
+                    WALLET(),
+                    IntegerTrait::<i32>::new(60, false),
+                    IntegerTrait::<i32>::new(120, false),
+                    1000
+                );
+            assert(yas_pool.get_liquidity() == 3161, 'wrong out of range liquidity');
+
+            // the pool is initialized at a 1:10 price, around tick -23028
+            yas_router
+                .mint(
+                    yas_pool.contract_address,
+                    WALLET(),
+                    IntegerTrait::<i32>::new(24000, true),
+                    IntegerTrait::<i32>::new(22020, true),
+                    1000
+                );
+            assert(yas_pool.get_liquidity() == 4161, 'wrong in range liquidity');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_returns_no_protocol_fees() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            let (protocol_fees_token_0, protocol_fees_token_1) = yas_pool.get_protocol_fees();
+            assert(protocol_fees_token_0 == 0, 'wrong protocol fees token_0');
+            assert(protocol_fees_token_1 == 0, 'wrong protocol fees token_1');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_returns_the_pool_state() {
+            let (yas_pool, token_0, token_1, yas_router, _, _) = setup();
+
+            let state = yas_pool.get_pool_state();
+            assert(state.factory == yas_router.get_factory(), 'wrong factory');
+            assert(state.token_0 == token_0.contract_address, 'wrong token_0');
+            assert(state.token_1 == token_1.contract_address, 'wrong token_1');
+            assert(state.fee == yas_pool.get_fee(), 'wrong fee');
+            assert(state.tick_spacing == yas_pool.get_tick_spacing(), 'wrong tick_spacing');
+            assert(
+                state.max_liquidity_per_tick == yas_pool.get_max_liquidity_per_tick(),
+                'wrong max_liquidity_per_tick'
+            );
+            assert(
+                state.slot_0.sqrt_price_X96 == yas_pool.get_slot_0().sqrt_price_X96,
+                'wrong sqrt_price_X96'
+            );
+            assert(state.slot_0.tick == yas_pool.get_slot_0().tick, 'wrong tick');
+            assert(state.liquidity == 3161, 'wrong liquidity');
+            let (fee_growth_0, fee_growth_1) = yas_pool.get_fee_growth_globals();
+            assert(state.fee_growth_global_0_X128 == fee_growth_0, 'wrong fee growth 0');
+            assert(state.fee_growth_global_1_X128 == fee_growth_1, 'wrong fee growth 1');
+            assert(state.protocol_fees_token_0 == 0, 'wrong protocol fees token_0');
+            assert(state.protocol_fees_token_1 == 0, 'wrong protocol fees token_1');
+            assert(!state.paused, 'pool should not be paused');
+
+            set_contract_address(OWNER());
+            IYASFactoryDispatcher { contract_address: state.factory }.pause();
+            assert(yas_pool.get_pool_state().paused, 'pool should be paused');
+        }
+    }
+
+    mod SecondsPerLiquidityInside {
+        use super::setup;
+
+        use starknet::testing::set_block_timestamp;
+
+        use yas_core::contracts::yas_pool::{YASPool, IYASPoolDispatcherTrait};
+        use yas_core::contracts::yas_router::IYASRouterDispatcherTrait;
+        use yas_core::libraries::tick_math::TickMath::get_sqrt_ratio_at_tick;
+        use yas_core::numbers::signed_integer::{i32::i32, integer_trait::IntegerTrait};
+        use yas_core::tests::utils::constants::PoolConstants::WALLET;
+        use yas_core::utils::math_utils::Constants::Q128;
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('seconds not tracked',))]
+        fn test_fails_if_pool_predates_the_accumulator() {
+            // the storage of a pool upgraded from a class that didn't track seconds per liquidity
+            let state = YASPool::contract_state_for_testing();
+
+            YASPool::YASPoolImpl::get_seconds_per_liquidity_inside(
+                @state, IntegerTrait::<i32>::new(60, true), IntegerTrait::<i32>::new(60, false)
+            );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('TLI', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_ticks_are_not_initialized() {
+            let (yas_pool, _, _, _, _, max_tick) = setup();
+            let tick_lower = IntegerTrait::<i32>::new(60, false);
+            yas_pool.get_seconds_per_liquidity_inside(tick_lower, max_tick);
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_accumulates_seconds_per_in_range_liquidity() {
+            let (yas_pool, _, _, yas_router, min_tick, max_tick) = setup();
+            assert(
+                yas_pool.get_seconds_per_liquidity_inside(min_tick, max_tick) == 0,
+                'wrong initial seconds'
+            );
+
+            set_block_timestamp(100);
+            let after_100_seconds = 100 * Q128 / 3161;
+            assert(
+                yas_pool.get_seconds_per_liquidity_inside(min_tick, max_tick) == after_100_seconds,
+                'wrong seconds after 100s'
+            );
+
+            // doubling the liquidity halves the seconds accumulated from now on
+            yas_router.mint(yas_pool.contract_address, WALLET(), min_tick, max_tick, 3161);
+            set_block_timestamp(200);
+            let after_200_seconds = after_100_seconds + 100 * Q128 / 6322;
+            assert(
+                yas_pool.get_seconds_per_liquidity_inside(min_tick, max_tick) == after_200_seconds,
+                'wrong seconds after 200s'
+            );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_does_not_accumulate_out_of_range() {
+            let (yas_pool, _, _, yas_router, _, _) = setup();
+            let tick_lower = IntegerTrait::<i32>::new(60, false);
+            let tick_upper = IntegerTrait::<i32>::new(120, false);
+            yas_router.mint(yas_pool.contract_address, WALLET(), tick_lower, tick_upper, 1000);
+
+            set_block_timestamp(100);
+            assert(
+                yas_pool.get_seconds_per_liquidity_inside(tick_lower, tick_upper) == 0,
+                'wrong out of range seconds'
+            );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_stops_accumulating_when_the_price_leaves_the_range() {
+            let (yas_pool, _, _, yas_router, _, _) = setup();
+            // the pool is initialized at a 1:10 price, around tick -23028
+            let tick_lower = IntegerTrait::<i32>::new(24000, true);
+            let tick_upper = IntegerTrait::<i32>::new(22020, true);
+            yas_router.mint(yas_pool.contract_address, WALLET(), tick_lower, tick_upper, 1000);
+
+            set_block_timestamp(100);
+            // move the price below the range, crossing its lower tick
+            let sqrt_price_limit_X96 = get_sqrt_ratio_at_tick(
+                IntegerTrait::<i32>::new(24060, true)
+            );
+            yas_router
+                .swap(
+                    yas_pool.contract_address,
+                    WALLET(),
+                    true,
+                    1000000000000000000_u256.into(),
+                    sqrt_price_limit_X96
+                );
+
+            // only the first 100 seconds, spent in range, count
+            set_block_timestamp(200);
+            let seconds = yas_pool.get_seconds_per_liquidity_inside(tick_lower, tick_upper);
+            assert(seconds == 100 * Q128 / 4161, 'wrong seconds in range');
+        }
+    }
+
+    mod Pause {
+        use super::setup;
+
+        use integer::BoundedInt;
+        use starknet::testing::{pop_log, set_contract_address};
+
+        use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
+        use yas_core::contracts::yas_pool::{
+            YASPool::Paused, YASPool::Unpaused, IYASPoolDispatcher, IYASPoolDispatcherTrait
+        };
+        use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+        use yas_core::libraries::tick_math::TickMath::MIN_SQRT_RATIO;
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::{
+            FP64x96Impl, FixedTrait
+        };
+        use yas_core::tests::utils::constants::PoolConstants::{OWNER, WALLET, OTHER};
+
+        fn swap_exact_0_for_1(yas_router: IYASRouterDispatcher, yas_pool: IYASPoolDispatcher) {
+            let amount: u256 = 1000;
+            let sqrt_price_limit_X96 = FixedTrait::new(MIN_SQRT_RATIO + 1, false);
+            yas_router
+                .swap(
+                    yas_pool.contract_address, WALLET(), true, amount.into(), sqrt_price_limit_X96
+                );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('only factory guardian', 'ENTRYPOINT_FAILED'))]
+        fn test_pause_fails_if_caller_is_not_guardian() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_contract_address(OTHER());
+            yas_pool.pause();
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('paused', 'ENTRYPOINT_FAILED', 'ENTRYPOINT_FAILED'))]
+        fn test_swap_fails_if_pool_is_paused() {
+            let (yas_pool, _, _, yas_router, _, _) = setup();
+
+            set_contract_address(OWNER());
+            yas_pool.pause();
+
+            set_contract_address(WALLET());
+            swap_exact_0_for_1(yas_router, yas_pool);
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('paused', 'ENTRYPOINT_FAILED', 'ENTRYPOINT_FAILED'))]
+        fn test_mint_fails_if_pool_is_paused() {
+            let (yas_pool, _, _, yas_router, min_tick, max_tick) = setup();
+
+            set_contract_address(OWNER());
+            yas_pool.pause();
+
+            set_contract_address(WALLET());
+            yas_router.mint(yas_pool.contract_address, WALLET(), min_tick, max_tick, 1000);
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('paused', 'ENTRYPOINT_FAILED', 'ENTRYPOINT_FAILED'))]
+        fn test_swap_fails_if_factory_is_paused() {
+            let (yas_pool, _, _, yas_router, _, _) = setup();
+
+            set_contract_address(OWNER());
+            IYASFactoryDispatcher { contract_address: yas_router.get_factory() }.pause();
+            assert(yas_pool.is_paused(), 'pool should be paused');
+
+            set_contract_address(WALLET());
+            swap_exact_0_for_1(yas_router, yas_pool);
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_burn_and_collect_work_while_paused() {
+            let (yas_pool, _, _, yas_router, min_tick, max_tick) = setup();
+
+            set_contract_address(OWNER());
+            yas_pool.pause();
+            IYASFactoryDispatcher { contract_address: yas_router.get_factory() }.pause();
+
+            set_contract_address(WALLET());
+            let (amount_0, amount_1) = yas_pool.burn(min_tick, max_tick, 3161);
+            let (amount_0_collected, amount_1_collected) = yas_pool
+                .collect(WALLET(), min_tick, max_tick, BoundedInt::max(), BoundedInt::max());
+
+            assert(amount_0_collected.into() == amount_0, 'wrong amount_0 collected');
+            assert(amount_1_collected.into() == amount_1, 'wrong amount_1 collected');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_swap_works_after_unpause() {
+            let (yas_pool, _, _, yas_router, _, _) = setup();
+
+            set_contract_address(OWNER());
+            yas_pool.pause();
+            yas_pool.unpause();
+            assert(!yas_pool.is_paused(), 'pool should not be paused');
+
+            set_contract_address(WALLET());
+            swap_exact_0_for_1(yas_router, yas_pool);
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_emits_events() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_contract_address(OWNER());
+            yas_pool.pause();
+            yas_pool.unpause();
+
+            // skip the Initialize and Mint events emitted by setup()
+            pop_log::<Paused>(yas_pool.contract_address);
+            pop_log::<Paused>(yas_pool.contract_address);
+
+            let event = pop_log::<Paused>(yas_pool.contract_address).unwrap();
+            assert(event.account == OWNER(), 'wrong Paused account');
+            let event = pop_log::<Unpaused>(yas_pool.contract_address).unwrap();
+            assert(event.account == OWNER(), 'wrong Unpaused account');
+        }
+    }
+
+    mod MinLiquidity {
+        use integer::BoundedInt;
+        use starknet::testing::set_contract_address;
+
+        use yas_core::contracts::yas_erc20::IERC20DispatcherTrait;
+        use yas_core::contracts::yas_factory::IYASFactoryDispatcherTrait;
+        use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+        use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+        use yas_core::numbers::signed_integer::{i32::i32, integer_trait::IntegerTrait};
+        use yas_core::tests::utils::constants::FactoryConstants::POOL_CLASS_HASH;
+        use yas_core::tests::utils::constants::PoolConstants::{
+            OTHER, OWNER, WALLET, encode_price_sqrt_1_1
+        };
+        use yas_core::tests::utils::deployers::Deployers::{
+            deploy_factory, deploy_yas_router, deploy_erc20
+        };
+
+        const MIN_LIQUIDITY: u128 = 1000000;
+
+        // creates a pool with a fee amount enabled in permissionless mode, or by the fee amount
+        // manager, with the minimum liquidity of permissionless fee amounts set
+        fn setup(permissionless: bool) -> (IYASPoolDispatcher, IYASRouterDispatcher) {
+            let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH());
+            let yas_router = deploy_yas_router(yas_factory.contract_address);
+            let token_0 = deploy_erc20('YAS0', '$YAS0', BoundedInt::max(), WALLET());
+            let token_1 = deploy_erc20('YAS1', '$YAS1', BoundedInt::max(), WALLET());
+
+            set_contract_address(OWNER());
+            yas_factory.set_permissionless_fee_amounts(true);
+            yas_factory.set_tick_spacing_allowed(IntegerTrait::<i32>::new(1, false), true);
+            yas_factory.set_permissionless_min_liquidity(MIN_LIQUIDITY);
+
+            if permissionless {
+                set_contract_address(OTHER());
+            }
+            yas_factory.enable_fee_amount(150, IntegerTrait::<i32>::new(1, false));
+            let yas_pool = IYASPoolDispatcher {
+                contract_address: yas_factory
+                    .create_pool(token_0.contract_address, token_1.contract_address, 150)
+            };
+            yas_pool.initialize(encode_price_sqrt_1_1());
+
+            set_contract_address(WALLET());
+            token_0.approve(yas_router.contract_address, BoundedInt::max());
+            token_1.approve(yas_router.contract_address, BoundedInt::max());
+
+            (yas_pool, yas_router)
+        }
+
+        fn mint(yas_router: IYASRouterDispatcher, yas_pool: IYASPoolDispatcher, amount: u128) {
+            yas_router
+                .mint(
+                    yas_pool.contract_address,
+                    WALLET(),
+                    IntegerTrait::<i32>::new(60, true),
+                    IntegerTrait::<i32>::new(60, false),
+                    amount
+                );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(
+            expected: ('liquidity below minimum', 'ENTRYPOINT_FAILED', 'ENTRYPOINT_FAILED')
+        )]
+        fn test_mint_fails_below_the_minimum_of_a_permissionless_fee_amount() {
+            let (yas_pool, yas_router) = setup(true);
+            mint(yas_router, yas_pool, MIN_LIQUIDITY - 1);
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_mint_works_at_the_minimum_of_a_permissionless_fee_amount() {
+            let (yas_pool, yas_router) = setup(true);
+            mint(yas_router, yas_pool, MIN_LIQUIDITY);
+            assert(yas_pool.get_liquidity() == MIN_LIQUIDITY, 'wrong liquidity');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_mint_has_no_minimum_if_the_fee_amount_manager_enabled_the_fee_amount() {
+            let (yas_pool, yas_router) = setup(false);
+            mint(yas_router, yas_pool, 1);
+            assert(yas_pool.get_liquidity() == 1, 'wrong liquidity');
+        }
+    }
+
+    mod ProtocolFees {
+        use super::setup;
+
+        use integer::BoundedInt;
+        use starknet::testing::{pop_log, set_contract_address};
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
+        use yas_core::contracts::yas_pool::{
+            YASPool::SetFeeProtocol, YASPool::CollectProtocol, IYASPoolDispatcher,
+            IYASPoolDispatcherTrait
+        };
+        use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+        use yas_core::libraries::tick_math::TickMath::MIN_SQRT_RATIO;
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::{
+            FP64x96Impl, FixedTrait
+        };
+        use yas_core::tests::utils::constants::PoolConstants::{OWNER, WALLET, OTHER};
+
+        fn swap_exact_0_for_1(yas_router: IYASRouterDispatcher, yas_pool: IYASPoolDispatcher) {
+            let amount: u256 = 1000000;
+            let sqrt_price_limit_X96 = FixedTrait::new(MIN_SQRT_RATIO + 1, false);
+            set_contract_address(WALLET());
+            yas_router
+                .swap(
+                    yas_pool.contract_address, WALLET(), true, amount.into(), sqrt_price_limit_X96
+                );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('only protocol fee manager', 'ENTRYPOINT_FAILED'))]
+        fn test_set_fee_protocol_fails_if_caller_is_not_protocol_fee_manager() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_contract_address(OTHER());
+            yas_pool.set_fee_protocol(4, 4);
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('invalid fee protocol', 'ENTRYPOINT_FAILED'))]
+        fn test_set_fee_protocol_fails_if_fee_protocol_is_invalid() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_contract_address(OWNER());
+            yas_pool.set_fee_protocol(4, 11);
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_set_fee_protocol_by_the_current_protocol_fee_manager() {
+            let (yas_pool, _, _, yas_router, _, _) = setup();
+            set_contract_address(OWNER());
+            IYASFactoryDispatcher { contract_address: yas_router.get_factory() }
+                .set_protocol_fee_manager(OTHER());
+
+            set_contract_address(OTHER());
+            yas_pool.set_fee_protocol(4, 10);
+
+            assert(yas_pool.get_slot_0().fee_protocol == 4 + 10 * 16, 'wrong fee protocol');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('only protocol fee manager', 'ENTRYPOINT_FAILED'))]
+        fn test_collect_protocol_fails_if_caller_is_not_protocol_fee_manager() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_contract_address(OTHER());
+            yas_pool.collect_protocol(OTHER(), BoundedInt::max(), BoundedInt::max());
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_collects_the_protocol_fees_of_swaps() {
+            let (yas_pool, token_0, _, yas_router, _, _) = setup();
+            set_contract_address(OWNER());
+            yas_pool.set_fee_protocol(4, 4);
+
+            swap_exact_0_for_1(yas_router, yas_pool);
+            let (protocol_fees_0, protocol_fees_1) = yas_pool.get_protocol_fees();
+            assert(protocol_fees_0 > 0, 'protocol fees not accrued');
+            assert(protocol_fees_1 == 0, 'wrong token_1 protocol fees');
+
+            set_contract_address(OWNER());
+            let (amount_0, amount_1) = yas_pool
+                .collect_protocol(OTHER(), BoundedInt::max(), BoundedInt::max());
+
+            assert(amount_0 == protocol_fees_0 && amount_1 == 0, 'wrong amounts collected');
+            assert(token_0.balanceOf(OTHER()) == amount_0.into(), 'wrong recipient balance');
+            let (protocol_fees_0, _) = yas_pool.get_protocol_fees();
+            assert(protocol_fees_0 == 0, 'protocol fees not cleared');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_does_not_accrue_protocol_fees_when_off() {
+            let (yas_pool, _, _, yas_router, _, _) = setup();
+
+            swap_exact_0_for_1(yas_router, yas_pool);
+
+            let (protocol_fees_0, protocol_fees_1) = yas_pool.get_protocol_fees();
+            assert(protocol_fees_0 == 0 && protocol_fees_1 == 0, 'protocol fees accrued');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_emits_events() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_contract_address(OWNER());
+            yas_pool.set_fee_protocol(5, 6);
+            yas_pool.collect_protocol(OTHER(), 0, 0);
+
+            // skip the Initialize and Mint events emitted by setup()
+            pop_log::<SetFeeProtocol>(yas_pool.contract_address);
+            pop_log::<SetFeeProtocol>(yas_pool.contract_address);
+
+            let event = pop_log::<SetFeeProtocol>(yas_pool.contract_address).unwrap();
+            assert(
+                event.fee_protocol_0_old == 0 && event.fee_protocol_1_old == 0,
+                'wrong old fee protocol'
+            );
+            assert(
+                event.fee_protocol_0_new == 5 && event.fee_protocol_1_new == 6,
+                'wrong new fee protocol'
+            );
+            let event = pop_log::<CollectProtocol>(yas_pool.contract_address).unwrap();
+            assert(event.sender == OWNER() && event.recipient == OTHER(), 'wrong CollectProtocol');
+        }
+    }
+
+    mod Upgrade {
+        use super::setup;
+
+        use starknet::ClassHash;
+        use starknet::testing::{set_block_timestamp, set_contract_address};
+
+        use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+        use yas_core::tests::utils::constants::PoolConstants::{OWNER, OTHER};
+        use yas_core::tests::utils::mock_yas_pool_v2::{
+            MockYASPoolV2, IMockYASPoolV2Dispatcher, IMockYASPoolV2DispatcherTrait
+        };
+        use yas_core::utils::utils::Slot0PartialEq;
+
+        const UPGRADE_DELAY: u64 = 172800;
+
+        fn V2_CLASS_HASH() -> ClassHash {
+            MockYASPoolV2::TEST_CLASS_HASH.try_into().unwrap()
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('only factory owner', 'ENTRYPOINT_FAILED'))]
+        fn test_schedule_fails_if_caller_is_not_factory_owner() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_contract_address(OTHER());
+            yas_pool.schedule_upgrade(V2_CLASS_HASH());
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_schedule_sets_the_pending_upgrade() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_block_timestamp(100);
+            set_contract_address(OWNER());
+            yas_pool.schedule_upgrade(V2_CLASS_HASH());
+
+            let (class_hash, ready_at) = yas_pool.get_pending_upgrade();
+            assert(class_hash == V2_CLASS_HASH(), 'wrong pending class hash');
+            assert(ready_at == 100 + UPGRADE_DELAY, 'wrong ready_at');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('upgrade not ready', 'ENTRYPOINT_FAILED'))]
+        fn test_upgrade_fails_before_the_delay() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_block_timestamp(100);
+            set_contract_address(OWNER());
+            yas_pool.schedule_upgrade(V2_CLASS_HASH());
+
+            set_block_timestamp(100 + UPGRADE_DELAY - 1);
+            yas_pool.upgrade(V2_CLASS_HASH());
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('upgrade not scheduled', 'ENTRYPOINT_FAILED'))]
+        fn test_upgrade_fails_if_class_hash_was_not_scheduled() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_contract_address(OWNER());
+            yas_pool.upgrade(V2_CLASS_HASH());
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('upgrade not scheduled', 'ENTRYPOINT_FAILED'))]
+        fn test_upgrade_fails_after_cancel() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_block_timestamp(100);
+            set_contract_address(OWNER());
+            yas_pool.schedule_upgrade(V2_CLASS_HASH());
+            yas_pool.cancel_upgrade();
+
+            set_block_timestamp(100 + UPGRADE_DELAY);
+            yas_pool.upgrade(V2_CLASS_HASH());
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('only factory owner', 'ENTRYPOINT_FAILED'))]
+        fn test_upgrade_fails_if_caller_is_not_factory_owner() {
+            let (yas_pool, _, _, _, _, _) = setup();
+
+            set_block_timestamp(100);
+            set_contract_address(OWNER());
+            yas_pool.schedule_upgrade(V2_CLASS_HASH());
+
+            set_block_timestamp(100 + UPGRADE_DELAY);
+            set_contract_address(OTHER());
+            yas_pool.upgrade(V2_CLASS_HASH());
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_upgrade_keeps_the_pool_state() {
+            let (yas_pool, token_0, token_1, _, _, _) = setup();
+            let slot_0_bf = yas_pool.get_slot_0();
+
+            set_block_timestamp(100);
+            set_contract_address(OWNER());
+            yas_pool.schedule_upgrade(V2_CLASS_HASH());
+
+            set_block_timestamp(100 + UPGRADE_DELAY);
+            yas_pool.upgrade(V2_CLASS_HASH());
+
+            let yas_pool_v2 = IMockYASPoolV2Dispatcher {
+                contract_address: yas_pool.contract_address
+            };
+            assert(yas_pool_v2.version() == 2, 'pool class was not replaced');
+            assert(yas_pool_v2.token_0() == token_0.contract_address, 'wrong token_0');
+            assert(yas_pool_v2.token_1() == token_1.contract_address, 'wrong token_1');
+            assert(yas_pool_v2.get_fee() == 3000, 'wrong fee');
+            assert(yas_pool_v2.get_tick_spacing().mag == 60, 'wrong tick_spacing');
+            assert(yas_pool_v2.get_liquidity() == 3161, 'wrong liquidity');
+
+            assert(yas_pool_v2.get_slot_0() == slot_0_bf, 'wrong slot_0');
+        }
+    }
+
     // YASPool mint() aux functions
     use starknet::{ClassHash, SyscallResultTrait};
     use starknet::testing::{set_contract_address, set_caller_address};
@@ -1911,14 +2790,17 @@ mod YASPoolTests {
     use yas_core::libraries::tick_math::TickMath::{MAX_SQRT_RATIO, MIN_SQRT_RATIO};
     use yas_core::utils::math_utils::pow;
 
+    use yas_core::tests::utils::deployers::Deployers::{
+        deploy_factory, deploy_yas_router, deploy_erc20, get_min_tick_and_max_tick
+    };
     use yas_core::tests::utils::swap_cases::SwapTestHelper;
 
 
     fn setup() -> (
         IYASPoolDispatcher, IERC20Dispatcher, IERC20Dispatcher, IYASRouterDispatcher, i32, i32
     ) {
-        let yas_router: IYASRouterDispatcher = deploy_yas_router(); // 0x1
-        let yas_factory: IYASFactoryDispatcher = deploy_factory(OWNER(), POOL_CLASS_HASH()); // 0x2
+        let yas_factory: IYASFactoryDispatcher = deploy_factory(OWNER(), POOL_CLASS_HASH()); // 0x1
+        let yas_router = deploy_yas_router(yas_factory.contract_address); // 0x2
 
         // Deploy ERC20 tokens with factory address
         let token_0 = deploy_erc20('YAS0', '$YAS0', BoundedInt::max(), OWNER()); // 0x3
@@ -1952,8 +2834,8 @@ mod YASPoolTests {
     fn setup_with(
         initial_price: FixedType, usdc_amount: u256, eth_amount: u256, mint_amount: u128
     ) -> (IYASPoolDispatcher, IYASRouterDispatcher, IERC20Dispatcher, IERC20Dispatcher) {
-        let yas_router = deploy_yas_router(); // 0x1
-        let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH()); // 0x2
+        let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH()); // 0x1
+        let yas_router = deploy_yas_router(yas_factory.contract_address); // 0x2
 
         // Deploy ERC20 tokens with factory address
         // in testnet TOKEN0 is USDC and TOKEN1 is ETH
@@ -2078,8 +2960,8 @@ mod YASPoolTests {
     fn setup_pool_for_swap_test(
         initial_price: FixedType, fee_amount: u32, mint_positions: @Array<SwapTestHelper::Position>
     ) -> (IYASPoolDispatcher, IYASRouterDispatcher, IERC20Dispatcher, IERC20Dispatcher) {
-        let yas_router = deploy_yas_router(); // 0x1
-        let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH()); // 0x2
+        let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH()); // 0x1
+        let yas_router = deploy_yas_router(yas_factory.contract_address); // 0x2
 
         // Deploy ERC20 tokens with factory address
         // in testnet TOKEN0 is USDC and TOKEN1 is ETH
@@ -2132,51 +3014,6 @@ mod YASPoolTests {
         };
     }
 
-    fn deploy_erc20(
-        name: felt252, symbol: felt252, initial_supply: u256, recipent: ContractAddress
-    ) -> IERC20Dispatcher {
-        let mut calldata = array![name, symbol];
-        Serde::serialize(@initial_supply, ref calldata);
-        calldata.append(recipent.into());
-
-        let (address, _) = deploy_syscall(
-            ERC20::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), true
-        )
-            .unwrap_syscall();
-
-        return IERC20Dispatcher { contract_address: address };
-    }
-
-    fn deploy_yas_router() -> IYASRouterDispatcher {
-        let (address, _) = deploy_syscall(
-            YASRouter::TEST_CLASS_HASH.try_into().unwrap(), 0, array![].span(), true
-        )
-            .unwrap_syscall();
-
-        return IYASRouterDispatcher { contract_address: address };
-    }
-
-    fn deploy_factory(
-        deployer: ContractAddress, pool_class_hash: ClassHash
-    ) -> IYASFactoryDispatcher {
-        let (address, _) = deploy_syscall(
-            YASFactory::TEST_CLASS_HASH.try_into().unwrap(),
-            0,
-            array![deployer.into(), pool_class_hash.into()].span(),
-            true
-        )
-            .unwrap_syscall();
-
-        return IYASFactoryDispatcher { contract_address: address };
-    }
-
-    fn get_min_tick_and_max_tick() -> (i32, i32) {
-        let tick_spacing = IntegerTrait::<i32>::new(tick_spacing(FeeAmount::MEDIUM), false);
-        let min_tick = i32_div_no_round(MIN_TICK(), tick_spacing) * tick_spacing;
-        let max_tick = i32_div_no_round(MAX_TICK(), tick_spacing) * tick_spacing;
-        (min_tick, max_tick)
-    }
-
     fn get_min_tick_and_max_tick_with_fee(fee_amount: u32) -> (i32, i32) {
         let tick_spacing = IntegerTrait::<i32>::new(fee_amount, false);
         let min_tick = i32_div_no_round(MIN_TICK(), tick_spacing) * tick_spacing;
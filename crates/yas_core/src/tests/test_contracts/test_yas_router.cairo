@@ -0,0 +1,1036 @@
+mod YASRouterTests {
+    use starknet::testing::set_contract_address;
+    use integer::BoundedInt;
+
+    use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+    use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
+    use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+    use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+    use yas_core::tests::utils::constants::FactoryConstants::{
+        POOL_CLASS_HASH, FeeAmount, fee_amount
+    };
+    use yas_core::tests::utils::constants::PoolConstants::{OWNER, WALLET, encode_price_sqrt_1_1};
+    use yas_core::tests::utils::deployers::Deployers::{
+        deploy_factory, deploy_yas_router, deploy_erc20, deploy_mock_account,
+        get_min_tick_and_max_tick
+    };
+
+    fn setup() -> (IYASRouterDispatcher, IERC20Dispatcher, IERC20Dispatcher, IERC20Dispatcher) {
+        let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH()); // 0x1
+        let yas_router = deploy_yas_router(yas_factory.contract_address); // 0x2
+
+        let token_0 = deploy_erc20('YAS0', '$YAS0', BoundedInt::max(), OWNER()); // 0x3
+        let token_1 = deploy_erc20('YAS1', '$YAS1', BoundedInt::max(), OWNER()); // 0x4
+        let token_2 = deploy_erc20('YAS2', '$YAS2', BoundedInt::max(), OWNER()); // 0x5
+
+        set_contract_address(OWNER());
+        token_0.transfer(WALLET(), BoundedInt::max());
+        token_1.transfer(WALLET(), BoundedInt::max());
+        token_2.transfer(WALLET(), BoundedInt::max());
+
+        // Give permissions to expend WALLET() tokens
+        set_contract_address(WALLET());
+        token_0.approve(yas_router.contract_address, BoundedInt::max());
+        token_1.approve(yas_router.contract_address, BoundedInt::max());
+        token_2.approve(yas_router.contract_address, BoundedInt::max());
+
+        create_pool_with_liquidity(yas_factory, yas_router, token_0, token_1); // 0x6
+        create_pool_with_liquidity(yas_factory, yas_router, token_1, token_2); // 0x7
+
+        (yas_router, token_0, token_1, token_2)
+    }
+
+    fn create_pool_with_liquidity(
+        yas_factory: IYASFactoryDispatcher,
+        yas_router: IYASRouterDispatcher,
+        token_a: IERC20Dispatcher,
+        token_b: IERC20Dispatcher
+    ) -> IYASPoolDispatcher {
+        let yas_pool_address = yas_factory
+            .create_pool(
+                token_a.contract_address, token_b.contract_address, fee_amount(FeeAmount::MEDIUM)
+            );
+        let yas_pool = IYASPoolDispatcher { contract_address: yas_pool_address };
+        yas_pool.initialize(encode_price_sqrt_1_1());
+
+        let (min_tick, max_tick) = get_min_tick_and_max_tick();
+        yas_router.mint(yas_pool_address, WALLET(), min_tick, max_tick, 1000000000000000000);
+        yas_pool
+    }
+
+    fn encode_path(tokens: Span<IERC20Dispatcher>) -> Array<felt252> {
+        let mut path = array![];
+        let mut i = 0;
+        loop {
+            if i == tokens.len() {
+                break;
+            }
+            if i != 0 {
+                path.append(fee_amount(FeeAmount::MEDIUM).into());
+            }
+            path.append((*tokens[i]).contract_address.into());
+            i += 1;
+        };
+        path
+    }
+
+    mod ExactInput {
+        use super::{setup, encode_path};
+
+        use starknet::testing::{set_contract_address, set_block_timestamp};
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_single_pool() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            let wallet_token_0_balance_bf = token_0.balanceOf(WALLET());
+
+            let amount_out = yas_router
+                .exact_input(encode_path(array![token_0, token_1].span()), OTHER(), 1000, 1, 1);
+
+            assert(amount_out > 0, 'amount_out should be gt 0');
+            assert(
+                wallet_token_0_balance_bf - token_0.balanceOf(WALLET()) == 1000,
+                'wrong token_0 amount in'
+            );
+            assert(token_1.balanceOf(OTHER()) == amount_out, 'wrong token_1 amount out');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_multiple_pools_0_to_1_to_2() {
+            let (yas_router, token_0, token_1, token_2) = setup();
+
+            let wallet_token_0_balance_bf = token_0.balanceOf(WALLET());
+
+            let amount_out = yas_router
+                .exact_input(
+                    encode_path(array![token_0, token_1, token_2].span()), OTHER(), 1000, 1, 1
+                );
+
+            assert(amount_out > 0, 'amount_out should be gt 0');
+            assert(
+                wallet_token_0_balance_bf - token_0.balanceOf(WALLET()) == 1000,
+                'wrong token_0 amount in'
+            );
+            assert(token_1.balanceOf(OTHER()) == 0, 'token_1 should not be received');
+            assert(token_2.balanceOf(OTHER()) == amount_out, 'wrong token_2 amount out');
+            // intermediate tokens are not left in the router
+            assert(token_1.balanceOf(yas_router.contract_address) == 0, 'router holds token_1');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_multiple_pools_2_to_1_to_0() {
+            let (yas_router, token_0, token_1, token_2) = setup();
+
+            let wallet_token_2_balance_bf = token_2.balanceOf(WALLET());
+
+            let amount_out = yas_router
+                .exact_input(
+                    encode_path(array![token_2, token_1, token_0].span()), OTHER(), 1000, 1, 1
+                );
+
+            assert(amount_out > 0, 'amount_out should be gt 0');
+            assert(
+                wallet_token_2_balance_bf - token_2.balanceOf(WALLET()) == 1000,
+                'wrong token_2 amount in'
+            );
+            assert(token_0.balanceOf(OTHER()) == amount_out, 'wrong token_0 amount out');
+            assert(token_1.balanceOf(yas_router.contract_address) == 0, 'router holds token_1');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Too little received', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_amount_out_is_less_than_minimum() {
+            let (yas_router, token_0, token_1, token_2) = setup();
+
+            // a fee is paid on every hop, so the amount out is always lower than the amount in
+            yas_router
+                .exact_input(
+                    encode_path(array![token_0, token_1, token_2].span()), OTHER(), 1000, 1000, 1
+                );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Transaction too old', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_deadline_has_passed() {
+            let (yas_router, token_0, token_1, token_2) = setup();
+
+            set_block_timestamp(10);
+            yas_router
+                .exact_input(
+                    encode_path(array![token_0, token_1, token_2].span()), OTHER(), 1000, 1, 9
+                );
+        }
+    }
+
+    mod ExactOutput {
+        use super::{setup, encode_path};
+
+        use starknet::testing::{set_contract_address, set_block_timestamp};
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_single_pool() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            let wallet_token_0_balance_bf = token_0.balanceOf(WALLET());
+
+            // exact output paths are encoded in reverse order
+            let amount_in = yas_router
+                .exact_output(
+                    encode_path(array![token_1, token_0].span()), OTHER(), 1000, 3000, 1
+                );
+
+            assert(amount_in > 1000, 'amount_in should be gt 1000');
+            assert(
+                wallet_token_0_balance_bf - token_0.balanceOf(WALLET()) == amount_in,
+                'wrong token_0 amount in'
+            );
+            assert(token_1.balanceOf(OTHER()) == 1000, 'wrong token_1 amount out');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_multiple_pools_0_to_1_to_2() {
+            let (yas_router, token_0, token_1, token_2) = setup();
+
+            let wallet_token_0_balance_bf = token_0.balanceOf(WALLET());
+
+            let amount_in = yas_router
+                .exact_output(
+                    encode_path(array![token_2, token_1, token_0].span()), OTHER(), 1000, 3000, 1
+                );
+
+            assert(amount_in > 1000, 'amount_in should be gt 1000');
+            assert(
+                wallet_token_0_balance_bf - token_0.balanceOf(WALLET()) == amount_in,
+                'wrong token_0 amount in'
+            );
+            assert(token_1.balanceOf(OTHER()) == 0, 'token_1 should not be received');
+            assert(token_2.balanceOf(OTHER()) == 1000, 'wrong token_2 amount out');
+            assert(token_1.balanceOf(yas_router.contract_address) == 0, 'router holds token_1');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_multiple_pools_2_to_1_to_0() {
+            let (yas_router, token_0, token_1, token_2) = setup();
+
+            let wallet_token_2_balance_bf = token_2.balanceOf(WALLET());
+
+            let amount_in = yas_router
+                .exact_output(
+                    encode_path(array![token_0, token_1, token_2].span()), OTHER(), 1000, 3000, 1
+                );
+
+            assert(amount_in > 1000, 'amount_in should be gt 1000');
+            assert(
+                wallet_token_2_balance_bf - token_2.balanceOf(WALLET()) == amount_in,
+                'wrong token_2 amount in'
+            );
+            assert(token_0.balanceOf(OTHER()) == 1000, 'wrong token_0 amount out');
+            assert(token_1.balanceOf(yas_router.contract_address) == 0, 'router holds token_1');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Too much requested', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_amount_in_is_greater_than_maximum() {
+            let (yas_router, token_0, token_1, token_2) = setup();
+
+            // a fee is paid on every hop, so the amount in is always greater than the amount out
+            yas_router
+                .exact_output(
+                    encode_path(array![token_2, token_1, token_0].span()), OTHER(), 1000, 1000, 1
+                );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Transaction too old', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_deadline_has_passed() {
+            let (yas_router, token_0, token_1, token_2) = setup();
+
+            set_block_timestamp(10);
+            yas_router
+                .exact_output(
+                    encode_path(array![token_2, token_1, token_0].span()), OTHER(), 1000, 3000, 9
+                );
+        }
+    }
+
+    mod ExactInputSingle {
+        use super::setup;
+
+        use starknet::testing::{set_block_timestamp, set_contract_address};
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
+        use yas_core::contracts::yas_router::{
+            IYASRouterDispatcher, IYASRouterDispatcherTrait, ExactInputSingleParams
+        };
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::FP64x96Zeroable;
+        use yas_core::tests::utils::constants::FactoryConstants::{FeeAmount, fee_amount};
+        use yas_core::tests::utils::constants::PoolConstants::{OWNER, WALLET, OTHER};
+
+        fn params(
+            token_in: IERC20Dispatcher,
+            token_out: IERC20Dispatcher,
+            amount_in: u256,
+            amount_out_minimum: u256,
+            deadline: u256
+        ) -> ExactInputSingleParams {
+            ExactInputSingleParams {
+                token_in: token_in.contract_address,
+                token_out: token_out.contract_address,
+                fee: fee_amount(FeeAmount::MEDIUM),
+                recipient: OTHER(),
+                deadline,
+                amount_in,
+                amount_out_minimum,
+                sqrt_price_limit_X96: Zeroable::zero()
+            }
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_0_to_1() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            let wallet_token_0_balance_bf = token_0.balanceOf(WALLET());
+
+            let amount_out = yas_router.exact_input_single(params(token_0, token_1, 1000, 990, 1));
+
+            assert(amount_out >= 990, 'amount_out should be gte 990');
+            assert(
+                wallet_token_0_balance_bf - token_0.balanceOf(WALLET()) == 1000,
+                'wrong token_0 amount in'
+            );
+            assert(token_1.balanceOf(OTHER()) == amount_out, 'wrong token_1 amount out');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_1_to_0() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            let wallet_token_1_balance_bf = token_1.balanceOf(WALLET());
+
+            let amount_out = yas_router.exact_input_single(params(token_1, token_0, 1000, 990, 1));
+
+            assert(amount_out >= 990, 'amount_out should be gte 990');
+            assert(
+                wallet_token_1_balance_bf - token_1.balanceOf(WALLET()) == 1000,
+                'wrong token_1 amount in'
+            );
+            assert(token_0.balanceOf(OTHER()) == amount_out, 'wrong token_0 amount out');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Too little received', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_amount_out_is_less_than_minimum() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            yas_router.exact_input_single(params(token_0, token_1, 1000, 1000, 1));
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Transaction too old', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_deadline_has_passed() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            set_block_timestamp(10);
+            yas_router.exact_input_single(params(token_0, token_1, 1000, 1, 9));
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('paused', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_factory_is_paused() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            set_contract_address(OWNER());
+            IYASFactoryDispatcher { contract_address: yas_router.get_factory() }.pause();
+
+            set_contract_address(WALLET());
+            yas_router.exact_input_single(params(token_0, token_1, 1000, 1, 1));
+        }
+    }
+
+    mod ExactOutputSingle {
+        use super::setup;
+
+        use starknet::testing::set_block_timestamp;
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::contracts::yas_router::{
+            IYASRouterDispatcher, IYASRouterDispatcherTrait, ExactOutputSingleParams
+        };
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::FP64x96Zeroable;
+        use yas_core::tests::utils::constants::FactoryConstants::{FeeAmount, fee_amount};
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        fn params(
+            token_in: IERC20Dispatcher,
+            token_out: IERC20Dispatcher,
+            amount_out: u256,
+            amount_in_maximum: u256,
+            deadline: u256
+        ) -> ExactOutputSingleParams {
+            ExactOutputSingleParams {
+                token_in: token_in.contract_address,
+                token_out: token_out.contract_address,
+                fee: fee_amount(FeeAmount::MEDIUM),
+                recipient: OTHER(),
+                deadline,
+                amount_out,
+                amount_in_maximum,
+                sqrt_price_limit_X96: Zeroable::zero()
+            }
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_0_to_1() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            let wallet_token_0_balance_bf = token_0.balanceOf(WALLET());
+
+            let amount_in = yas_router.exact_output_single(params(token_0, token_1, 1000, 1010, 1));
+
+            assert(amount_in <= 1010, 'amount_in should be lte 1010');
+            assert(
+                wallet_token_0_balance_bf - token_0.balanceOf(WALLET()) == amount_in,
+                'wrong token_0 amount in'
+            );
+            assert(token_1.balanceOf(OTHER()) == 1000, 'wrong token_1 amount out');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_1_to_0() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            let wallet_token_1_balance_bf = token_1.balanceOf(WALLET());
+
+            let amount_in = yas_router.exact_output_single(params(token_1, token_0, 1000, 1010, 1));
+
+            assert(amount_in <= 1010, 'amount_in should be lte 1010');
+            assert(
+                wallet_token_1_balance_bf - token_1.balanceOf(WALLET()) == amount_in,
+                'wrong token_1 amount in'
+            );
+            assert(token_0.balanceOf(OTHER()) == 1000, 'wrong token_0 amount out');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Too much requested', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_amount_in_is_greater_than_maximum() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            yas_router.exact_output_single(params(token_0, token_1, 1000, 1000, 1));
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Transaction too old', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_deadline_has_passed() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            set_block_timestamp(10);
+            yas_router.exact_output_single(params(token_0, token_1, 1000, 1010, 9));
+        }
+    }
+
+    mod SwapForExact {
+        use super::{deploy_factory, deploy_yas_router, deploy_erc20, create_pool_with_liquidity};
+
+        use starknet::testing::set_contract_address;
+        use integer::BoundedInt;
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+        use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+        use yas_core::libraries::tick_math::TickMath::{MIN_SQRT_RATIO, MAX_SQRT_RATIO};
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::{
+            FP64x96Impl, FP64x96Zeroable, FixedTrait
+        };
+        use yas_core::numbers::signed_integer::{i256::i256, integer_trait::IntegerTrait};
+        use yas_core::tests::utils::constants::FactoryConstants::POOL_CLASS_HASH;
+        use yas_core::tests::utils::constants::PoolConstants::{OWNER, WALLET, OTHER};
+        use yas_core::tests::test_contracts::test_yas_pool::YASPoolTests::{
+            setup_pool_for_swap_test, round_for_price_comparison, calculate_execution_price,
+            Swap::assert_swap_result_equals
+        };
+        use yas_core::tests::utils::pool_1::SWAP_EXPECTED_RESULTS_POOL_1;
+        use yas_core::tests::utils::swap_cases::SwapTestHelper::{
+            PoolTestCase, SwapExpectedResults, POOL_CASES, SWAP_CASES
+        };
+
+        fn setup() -> (
+            IYASRouterDispatcher, IYASPoolDispatcher, IERC20Dispatcher, IERC20Dispatcher
+        ) {
+            let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH()); // 0x1
+            let yas_router = deploy_yas_router(yas_factory.contract_address); // 0x2
+
+            let token_0 = deploy_erc20('YAS0', '$YAS0', BoundedInt::max(), OWNER()); // 0x3
+            let token_1 = deploy_erc20('YAS1', '$YAS1', BoundedInt::max(), OWNER()); // 0x4
+
+            set_contract_address(OWNER());
+            token_0.transfer(WALLET(), BoundedInt::max());
+            token_1.transfer(WALLET(), BoundedInt::max());
+
+            set_contract_address(WALLET());
+            token_0.approve(yas_router.contract_address, BoundedInt::max());
+            token_1.approve(yas_router.contract_address, BoundedInt::max());
+
+            let yas_pool = create_pool_with_liquidity(yas_factory, yas_router, token_0, token_1);
+
+            (yas_router, yas_pool, token_0, token_1)
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_swap_exact_1_for_0() {
+            let (yas_router, yas_pool, token_0, token_1) = setup();
+            let wallet_token_1_balance_bf = token_1.balanceOf(WALLET());
+
+            let (amount_0, amount_1) = yas_router
+                .swap_exact_1_for_0(
+                    yas_pool.contract_address,
+                    1000,
+                    OTHER(),
+                    FP64x96Impl::new(MAX_SQRT_RATIO - 1, false)
+                );
+
+            assert(amount_1.mag == 1000 && !amount_1.sign, 'wrong amount_1');
+            assert(amount_0.sign, 'amount_0 should be negative');
+            assert(
+                wallet_token_1_balance_bf - token_1.balanceOf(WALLET()) == 1000,
+                'wrong token_1 amount in'
+            );
+            assert(token_0.balanceOf(OTHER()) == amount_0.mag, 'wrong token_0 amount out');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_pool_1_exact_output_cases() {
+            // the exact output swaps of SWAP_CASES: large amounts, large amounts with a price
+            // limit, and small amounts, each in both directions
+            test_exact_output_cases(POOL_CASES()[1], array![2, 3, 6, 7, 10, 11]);
+        }
+
+        fn test_exact_output_cases(pool_case: @PoolTestCase, case_indexes: Array<u32>) {
+            let swap_cases = SWAP_CASES();
+            let expected_cases = SWAP_EXPECTED_RESULTS_POOL_1();
+            let mut i = 0;
+            loop {
+                if i == case_indexes.len() {
+                    break;
+                }
+                let swap_case = swap_cases[*case_indexes[i]];
+                let expected = expected_cases[*case_indexes[i]];
+                assert(*swap_case.exact_out, 'not an exact output case');
+
+                let (yas_pool, yas_router, token_0, token_1) = setup_pool_for_swap_test(
+                    initial_price: *pool_case.starting_price,
+                    fee_amount: *pool_case.fee_amount,
+                    mint_positions: pool_case.mint_positions
+                );
+
+                let user_token_0_balance_bf = token_0.balanceOf(WALLET());
+                let user_token_1_balance_bf = token_1.balanceOf(WALLET());
+                let pool_balance_0_bf = token_0.balanceOf(yas_pool.contract_address);
+                let pool_balance_1_bf = token_1.balanceOf(yas_pool.contract_address);
+                let (fee_growth_global_0_X128_bf, fee_growth_global_1_X128_bf) = yas_pool
+                    .get_fee_growth_globals();
+                let slot0_bf = yas_pool.get_slot_0();
+
+                let zero_for_one = *swap_case.zero_for_one;
+                let amount_out = (*swap_case.amount_specified).mag;
+                let (amount_0, amount_1) = if zero_for_one {
+                    let sqrt_price_limit = if (*swap_case.sqrt_price_limit).is_zero() {
+                        FixedTrait::new(MIN_SQRT_RATIO + 1, false)
+                    } else {
+                        *swap_case.sqrt_price_limit
+                    };
+                    yas_router
+                        .swap_0_for_exact_1(
+                            yas_pool.contract_address,
+                            amount_out,
+                            BoundedInt::max(),
+                            WALLET(),
+                            sqrt_price_limit
+                        )
+                } else {
+                    let sqrt_price_limit = if (*swap_case.sqrt_price_limit).is_zero() {
+                        FixedTrait::new(MAX_SQRT_RATIO - 1, false)
+                    } else {
+                        *swap_case.sqrt_price_limit
+                    };
+                    yas_router
+                        .swap_1_for_exact_0(
+                            yas_pool.contract_address,
+                            amount_out,
+                            BoundedInt::max(),
+                            WALLET(),
+                            sqrt_price_limit
+                        )
+                };
+                assert(amount_0 == *expected.amount_0_delta, 'wrong returned amount_0');
+                assert(amount_1 == *expected.amount_1_delta, 'wrong returned amount_1');
+
+                let (token_0_swapped_amount, token_1_swapped_amount) = if zero_for_one {
+                    (
+                        user_token_0_balance_bf - token_0.balanceOf(WALLET()),
+                        token_1.balanceOf(WALLET()) - user_token_1_balance_bf
+                    )
+                } else {
+                    (
+                        token_0.balanceOf(WALLET()) - user_token_0_balance_bf,
+                        user_token_1_balance_bf - token_1.balanceOf(WALLET())
+                    )
+                };
+                let (fee_growth_global_0_X128_af, fee_growth_global_1_X128_af) = yas_pool
+                    .get_fee_growth_globals();
+                let slot0_af = yas_pool.get_slot_0();
+
+                let actual = SwapExpectedResults {
+                    amount_0_before: pool_balance_0_bf,
+                    amount_0_delta: IntegerTrait::<i256>::new(
+                        token_0.balanceOf(yas_pool.contract_address), false
+                    )
+                        - IntegerTrait::<i256>::new(pool_balance_0_bf, false),
+                    amount_1_before: pool_balance_1_bf,
+                    amount_1_delta: IntegerTrait::<i256>::new(
+                        token_1.balanceOf(yas_pool.contract_address), false
+                    )
+                        - IntegerTrait::<i256>::new(pool_balance_1_bf, false),
+                    execution_price: calculate_execution_price(
+                        token_0_swapped_amount, token_1_swapped_amount
+                    ),
+                    fee_growth_global_0_X128_delta: fee_growth_global_0_X128_af
+                        - fee_growth_global_0_X128_bf,
+                    fee_growth_global_1_X128_delta: fee_growth_global_1_X128_af
+                        - fee_growth_global_1_X128_bf,
+                    pool_price_after: round_for_price_comparison(slot0_af.sqrt_price_X96.mag),
+                    pool_price_before: round_for_price_comparison(slot0_bf.sqrt_price_X96.mag),
+                    tick_after: slot0_af.tick,
+                    tick_before: slot0_bf.tick,
+                };
+                assert_swap_result_equals(actual, expected);
+                i += 1;
+            };
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Too much requested', 'ENTRYPOINT_FAILED'))]
+        fn test_swap_0_for_exact_1_fails_if_amount_in_is_greater_than_maximum() {
+            let (yas_router, yas_pool, _, _) = setup();
+
+            yas_router
+                .swap_0_for_exact_1(
+                    yas_pool.contract_address,
+                    1000,
+                    1000,
+                    OTHER(),
+                    FixedTrait::new(MIN_SQRT_RATIO + 1, false)
+                );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Too much requested', 'ENTRYPOINT_FAILED'))]
+        fn test_swap_1_for_exact_0_fails_if_amount_in_is_greater_than_maximum() {
+            let (yas_router, yas_pool, _, _) = setup();
+
+            yas_router
+                .swap_1_for_exact_0(
+                    yas_pool.contract_address,
+                    1000,
+                    1000,
+                    OTHER(),
+                    FP64x96Impl::new(MAX_SQRT_RATIO - 1, false)
+                );
+        }
+    }
+
+    mod SelfPermit {
+        use super::{setup, deploy_mock_account};
+        use starknet::testing::set_contract_address;
+
+        use yas_core::contracts::yas_erc20::IERC20DispatcherTrait;
+        use yas_core::contracts::yas_router::{
+            YASRouter::Selectors, IYASRouterDispatcherTrait, ExactInputSingleParams
+        };
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::FP64x96Zeroable;
+        use yas_core::tests::test_contracts::test_yas_erc20::YASERC20Tests::sign_permit;
+        use yas_core::tests::utils::constants::FactoryConstants::{FeeAmount, fee_amount};
+        use yas_core::tests::utils::constants::PoolConstants::OTHER;
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_swaps_without_a_prior_approval() {
+            let (yas_router, token_0, token_1, _) = setup();
+            let account = deploy_mock_account();
+            token_0.transfer(account, 1000);
+
+            let mut permit_call = array![Selectors::SELF_PERMIT];
+            Serde::serialize(@token_0.contract_address, ref permit_call);
+            Serde::serialize(@1000_u256, ref permit_call);
+            Serde::serialize(@1_u256, ref permit_call);
+            Serde::serialize(
+                @sign_permit(token_0, account, yas_router.contract_address, 1000, 1),
+                ref permit_call
+            );
+            let mut swap_call = array![Selectors::EXACT_INPUT_SINGLE];
+            Serde::serialize(
+                @ExactInputSingleParams {
+                    token_in: token_0.contract_address,
+                    token_out: token_1.contract_address,
+                    fee: fee_amount(FeeAmount::MEDIUM),
+                    recipient: OTHER(),
+                    deadline: 1,
+                    amount_in: 1000,
+                    amount_out_minimum: 0,
+                    sqrt_price_limit_X96: Zeroable::zero()
+                },
+                ref swap_call
+            );
+
+            set_contract_address(account);
+            let results = yas_router.multicall(array![permit_call.span(), swap_call.span()]);
+
+            let mut swap_result = *results[1];
+            let amount_out = Serde::<u256>::deserialize(ref swap_result).unwrap();
+            assert(token_0.balanceOf(account) == 0, 'wrong token_0 balance');
+            assert(token_1.balanceOf(OTHER()) == amount_out, 'wrong token_1 amount out');
+            assert(
+                token_0.allowance(account, yas_router.contract_address) == 0, 'wrong allowance'
+            );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(
+            expected: ('ERC20: invalid signature', 'ENTRYPOINT_FAILED', 'ENTRYPOINT_FAILED')
+        )]
+        fn test_fails_with_an_invalid_signature() {
+            let (yas_router, token_0, _, _) = setup();
+
+            yas_router.self_permit(token_0.contract_address, 1000, 1, array!['not a signature']);
+        }
+    }
+
+    mod SweepToken {
+        use super::setup;
+
+        use yas_core::contracts::yas_erc20::IERC20DispatcherTrait;
+        use yas_core::contracts::yas_router::{
+            YASRouter::Selectors, IYASRouterDispatcherTrait, ExactInputSingleParams
+        };
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::FP64x96Zeroable;
+        use yas_core::tests::utils::constants::FactoryConstants::{FeeAmount, fee_amount};
+        use yas_core::tests::utils::constants::PoolConstants::OTHER;
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_sweeps_the_router_balance() {
+            let (yas_router, token_0, _, _) = setup();
+            token_0.transfer(yas_router.contract_address, 100);
+
+            yas_router.sweep_token(token_0.contract_address, 100, OTHER());
+
+            assert(token_0.balanceOf(OTHER()) == 100, 'wrong recipient balance');
+            assert(token_0.balanceOf(yas_router.contract_address) == 0, 'wrong router balance');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_sweeps_nothing_if_the_router_holds_nothing() {
+            let (yas_router, token_0, _, _) = setup();
+
+            yas_router.sweep_token(token_0.contract_address, 0, OTHER());
+
+            assert(token_0.balanceOf(OTHER()) == 0, 'wrong recipient balance');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Insufficient token', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_balance_is_less_than_minimum() {
+            let (yas_router, token_0, _, _) = setup();
+            token_0.transfer(yas_router.contract_address, 100);
+
+            yas_router.sweep_token(token_0.contract_address, 101, OTHER());
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_refunds_a_swap_output_sent_to_the_router() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            let mut swap_call = array![Selectors::EXACT_INPUT_SINGLE];
+            Serde::serialize(
+                @ExactInputSingleParams {
+                    token_in: token_0.contract_address,
+                    token_out: token_1.contract_address,
+                    fee: fee_amount(FeeAmount::MEDIUM),
+                    recipient: yas_router.contract_address,
+                    deadline: 1,
+                    amount_in: 1000,
+                    amount_out_minimum: 0,
+                    sqrt_price_limit_X96: Zeroable::zero()
+                },
+                ref swap_call
+            );
+            let mut sweep_call = array![Selectors::SWEEP_TOKEN];
+            Serde::serialize(@token_1.contract_address, ref sweep_call);
+            Serde::serialize(@1_u256, ref sweep_call);
+            Serde::serialize(@OTHER(), ref sweep_call);
+
+            let results = yas_router.multicall(array![swap_call.span(), sweep_call.span()]);
+
+            let mut swap_result = *results[0];
+            let amount_out = Serde::<u256>::deserialize(ref swap_result).unwrap();
+            assert(amount_out > 0, 'amount_out should be gt 0');
+            assert(token_1.balanceOf(OTHER()) == amount_out, 'wrong token_1 refund');
+            assert(token_1.balanceOf(yas_router.contract_address) == 0, 'wrong router balance');
+        }
+    }
+
+    mod Multicall {
+        use super::{setup, encode_path};
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::contracts::yas_router::{
+            YASRouter::Selectors, IYASRouterDispatcher, IYASRouterDispatcherTrait,
+            ExactInputSingleParams
+        };
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::FP64x96Zeroable;
+        use yas_core::tests::utils::constants::FactoryConstants::{FeeAmount, fee_amount};
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        fn exact_input_single_call(
+            token_in: IERC20Dispatcher, token_out: IERC20Dispatcher, amount_in: u256
+        ) -> Array<felt252> {
+            let mut call = array![Selectors::EXACT_INPUT_SINGLE];
+            Serde::serialize(
+                @ExactInputSingleParams {
+                    token_in: token_in.contract_address,
+                    token_out: token_out.contract_address,
+                    fee: fee_amount(FeeAmount::MEDIUM),
+                    recipient: OTHER(),
+                    deadline: 1,
+                    amount_in,
+                    amount_out_minimum: 0,
+                    sqrt_price_limit_X96: Zeroable::zero()
+                },
+                ref call
+            );
+            call
+        }
+
+        fn exact_input_call(path: Array<felt252>, amount_in: u256) -> Span<felt252> {
+            let mut call = array![Selectors::EXACT_INPUT];
+            Serde::serialize(@path, ref call);
+            Serde::serialize(@OTHER(), ref call);
+            Serde::serialize(@amount_in, ref call);
+            Serde::serialize(@0_u256, ref call);
+            Serde::serialize(@1_u256, ref call);
+            call.span()
+        }
+
+        fn deserialize_u256(result: Span<felt252>) -> u256 {
+            let mut result = result;
+            Serde::<u256>::deserialize(ref result).unwrap()
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_chains_several_swaps() {
+            let (yas_router, token_0, token_1, token_2) = setup();
+
+            let wallet_token_0_balance_bf = token_0.balanceOf(WALLET());
+            let wallet_token_2_balance_bf = token_2.balanceOf(WALLET());
+
+            let results = yas_router
+                .multicall(
+                    array![
+                        exact_input_single_call(token_0, token_1, 1000).span(),
+                        exact_input_call(encode_path(array![token_2, token_1].span()), 500)
+                    ]
+                );
+
+            assert(results.len() == 2, 'wrong results len');
+            let amount_out_0 = deserialize_u256(*results[0]);
+            let amount_out_1 = deserialize_u256(*results[1]);
+            assert(amount_out_0 > 0 && amount_out_1 > 0, 'amounts out should be gt 0');
+            assert(
+                token_1.balanceOf(OTHER()) == amount_out_0 + amount_out_1,
+                'wrong token_1 amount out'
+            );
+            // every call is paid by the caller of multicall
+            assert(
+                wallet_token_0_balance_bf - token_0.balanceOf(WALLET()) == 1000,
+                'wrong token_0 amount in'
+            );
+            assert(
+                wallet_token_2_balance_bf - token_2.balanceOf(WALLET()) == 500,
+                'wrong token_2 amount in'
+            );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('Too little received', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_any_call_fails() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            let mut failing_call = array![Selectors::EXACT_INPUT];
+            Serde::serialize(@encode_path(array![token_0, token_1].span()), ref failing_call);
+            Serde::serialize(@OTHER(), ref failing_call);
+            Serde::serialize(@1000_u256, ref failing_call);
+            Serde::serialize(@1000_u256, ref failing_call);
+            Serde::serialize(@1_u256, ref failing_call);
+
+            yas_router
+                .multicall(
+                    array![
+                        exact_input_single_call(token_0, token_1, 1000).span(), failing_call.span()
+                    ]
+                );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('unsupported selector', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_selector_is_not_supported() {
+            let (yas_router, _, _, _) = setup();
+
+            yas_router.multicall(array![array!['get_factory'].span()]);
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('invalid calldata', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_calldata_is_too_long() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            let mut call = exact_input_single_call(token_0, token_1, 1000);
+            call.append(0);
+
+            yas_router.multicall(array![call.span()]);
+        }
+    }
+
+    mod Callbacks {
+        use super::{setup, get_min_tick_and_max_tick};
+
+        use starknet::{ContractAddress, get_contract_address};
+        use starknet::syscalls::deploy_syscall;
+        use starknet::testing::set_contract_address;
+
+        use yas_core::contracts::yas_erc20::IERC20Dispatcher;
+        use yas_core::contracts::yas_pool::{YASPool, IYASPoolDispatcher, IYASPoolDispatcherTrait};
+        use yas_core::contracts::yas_router::{
+            IYASRouterDispatcher, IYASRouterDispatcherTrait, SwapCallbackData
+        };
+        use yas_core::numbers::signed_integer::{
+            i32::i32, i256::i256, integer_trait::IntegerTrait
+        };
+        use yas_core::tests::utils::constants::FactoryConstants::{
+            FeeAmount, fee_amount, tick_spacing
+        };
+        use yas_core::tests::utils::constants::PoolConstants::{
+            WALLET, OTHER, encode_price_sqrt_1_1
+        };
+
+        // deploys a pool with the same key as a genuine one, but not through the factory
+        fn deploy_fake_pool(
+            factory: ContractAddress, token_0: IERC20Dispatcher, token_1: IERC20Dispatcher
+        ) -> IYASPoolDispatcher {
+            let mut calldata = array![
+                factory.into(),
+                token_0.contract_address.into(),
+                token_1.contract_address.into(),
+                fee_amount(FeeAmount::MEDIUM).into()
+            ];
+            let tick_spacing = IntegerTrait::<i32>::new(tick_spacing(FeeAmount::MEDIUM), false);
+            Serde::serialize(@tick_spacing, ref calldata);
+
+            // impersonate the factory, it never registers this pool
+            let caller = get_contract_address();
+            set_contract_address(factory);
+            let (address, _) = deploy_syscall(
+                YASPool::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), true
+            )
+                .expect('DEPLOY_FAILED');
+            set_contract_address(caller);
+
+            let yas_pool = IYASPoolDispatcher { contract_address: address };
+            yas_pool.initialize(encode_price_sqrt_1_1());
+            yas_pool
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('invalid caller', 'ENTRYPOINT_FAILED'))]
+        fn test_swap_callback_fails_if_caller_is_not_a_pool() {
+            let (yas_router, token_0, token_1, _) = setup();
+
+            let mut data = array![];
+            Serde::serialize(
+                @SwapCallbackData {
+                    path: array![
+                        token_0.contract_address.into(),
+                        fee_amount(FeeAmount::MEDIUM).into(),
+                        token_1.contract_address.into()
+                    ]
+                        .span(),
+                    payer: WALLET()
+                },
+                ref data
+            );
+
+            let amount_0_delta = IntegerTrait::<i256>::new(1000, false);
+            let amount_1_delta = IntegerTrait::<i256>::new(990, true);
+
+            set_contract_address(OTHER());
+            yas_router.yas_swap_callback(amount_0_delta, amount_1_delta, data);
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(
+            expected: (
+                'invalid caller', 'ENTRYPOINT_FAILED', 'ENTRYPOINT_FAILED', 'ENTRYPOINT_FAILED'
+            )
+        )]
+        fn test_mint_callback_fails_if_pool_was_not_deployed_by_the_factory() {
+            let (yas_router, token_0, token_1, _) = setup();
+            let fake_pool = deploy_fake_pool(yas_router.get_factory(), token_0, token_1);
+
+            let (min_tick, max_tick) = get_min_tick_and_max_tick();
+            set_contract_address(WALLET());
+            yas_router.mint(fake_pool.contract_address, WALLET(), min_tick, max_tick, 1000);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use starknet::{ContractAddress, contract_address_const};
+
+use yas_core::libraries::path::Path;
+
+fn TOKEN_A() -> ContractAddress {
+    contract_address_const::<'TOKEN_A'>()
+}
+
+fn TOKEN_B() -> ContractAddress {
+    contract_address_const::<'TOKEN_B'>()
+}
+
+fn TOKEN_C() -> ContractAddress {
+    contract_address_const::<'TOKEN_C'>()
+}
+
+fn single_pool_path() -> Span<felt252> {
+    let path: Array<felt252> = array![TOKEN_A().into(), 3000, TOKEN_B().into()];
+    path.span()
+}
+
+fn multiple_pools_path() -> Span<felt252> {
+    let path: Array<felt252> = array![
+        TOKEN_A().into(), 3000, TOKEN_B().into(), 500, TOKEN_C().into()
+    ];
+    path.span()
+}
+
+#[test]
+#[available_gas(2000000)]
+fn test_has_multiple_pools() {
+    assert(!Path::has_multiple_pools(single_pool_path()), 'single pool path');
+    assert(Path::has_multiple_pools(multiple_pools_path()), 'multiple pools path');
+}
+
+#[test]
+#[available_gas(2000000)]
+fn test_num_pools() {
+    assert(Path::num_pools(single_pool_path()) == 1, 'wrong num pools single');
+    assert(Path::num_pools(multiple_pools_path()) == 2, 'wrong num pools multiple');
+}
+
+#[test]
+#[available_gas(2000000)]
+fn test_decode_first_pool() {
+    let (token_a, token_b, fee) = Path::decode_first_pool(multiple_pools_path());
+    assert(token_a == TOKEN_A(), 'wrong token_a');
+    assert(token_b == TOKEN_B(), 'wrong token_b');
+    assert(fee == 3000, 'wrong fee');
+}
+
+#[test]
+#[available_gas(2000000)]
+fn test_get_first_pool() {
+    let first_pool = Path::get_first_pool(multiple_pools_path());
+    assert(first_pool.len() == 3, 'wrong first pool len');
+    let (token_a, token_b, fee) = Path::decode_first_pool(first_pool);
+    assert(token_a == TOKEN_A(), 'wrong token_a');
+    assert(token_b == TOKEN_B(), 'wrong token_b');
+    assert(fee == 3000, 'wrong fee');
+}
+
+#[test]
+#[available_gas(2000000)]
+fn test_skip_token() {
+    let path = Path::skip_token(multiple_pools_path());
+    assert(!Path::has_multiple_pools(path), 'should be single pool');
+
+    let (token_a, token_b, fee) = Path::decode_first_pool(path);
+    assert(token_a == TOKEN_B(), 'wrong token_a');
+    assert(token_b == TOKEN_C(), 'wrong token_b');
+    assert(fee == 500, 'wrong fee');
+}
+
+#[test]
+#[available_gas(2000000)]
+#[should_panic(expected: ('invalid path',))]
+fn test_decode_first_pool_fails_if_path_is_too_short() {
+    let path: Array<felt252> = array![TOKEN_A().into(), 3000];
+    Path::decode_first_pool(path.span());
+}
+
+#[test]
+#[available_gas(2000000)]
+#[should_panic(expected: ('invalid path',))]
+fn test_decode_first_pool_fails_if_path_ends_with_fee() {
+    let path: Array<felt252> = array![TOKEN_A().into(), 3000, TOKEN_B().into(), 500];
+    Path::decode_first_pool(path.span());
+}
@@ -260,4 +260,77 @@ mod TestSwapMath {
             assert(sqrtQ == sqrtP_target, 'incorrect sqrtQ');
         }
     }
+
+    mod ComputeSwapStepToTick {
+        use yas_core::libraries::swap_math::SwapMath;
+        use yas_core::libraries::tick_math::TickMath::{
+            get_sqrt_ratio_at_tick, MIN_SQRT_RATIO, MIN_TICK
+        };
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::{
+            FP64x96Impl, FP64x96PartialEq
+        };
+        use yas_core::numbers::signed_integer::{i32::i32, i256::i256, integer_trait::IntegerTrait};
+        use yas_core::tests::test_libraries::test_swap_math::TestSwapMath::expand_to_18_decimals;
+
+        #[test]
+        #[available_gas(200000000)]
+        fn test_stops_at_the_price_limit() {
+            let sqrt_price_limit = get_sqrt_ratio_at_tick(IntegerTrait::<i32>::new(30, false));
+
+            let step = SwapMath::compute_swap_step_to_tick(
+                get_sqrt_ratio_at_tick(IntegerTrait::<i32>::new(0, false)),
+                IntegerTrait::<i32>::new(0, false),
+                IntegerTrait::<i32>::new(60, false),
+                sqrt_price_limit,
+                expand_to_18_decimals(1).try_into().unwrap(),
+                IntegerTrait::<i256>::new(expand_to_18_decimals(1), false),
+                3000,
+                false
+            );
+
+            assert(!step.reached_tick_next, 'should not reach tick_next');
+            assert(step.sqrt_price_X96 == sqrt_price_limit, 'price should be the limit');
+            assert(step.tick == IntegerTrait::<i32>::new(30, false), 'tick should be 30');
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        fn test_moves_below_the_tick_reached_downwards() {
+            let step = SwapMath::compute_swap_step_to_tick(
+                get_sqrt_ratio_at_tick(IntegerTrait::<i32>::new(0, false)),
+                IntegerTrait::<i32>::new(0, false),
+                IntegerTrait::<i32>::new(60, true),
+                FP64x96Impl::new(MIN_SQRT_RATIO + 1, false),
+                expand_to_18_decimals(1).try_into().unwrap(),
+                IntegerTrait::<i256>::new(expand_to_18_decimals(1), false),
+                3000,
+                true
+            );
+
+            assert(step.reached_tick_next, 'should reach tick_next');
+            assert(
+                step.sqrt_price_X96 == get_sqrt_ratio_at_tick(IntegerTrait::<i32>::new(60, true)),
+                'price should be at tick -60'
+            );
+            assert(step.tick == IntegerTrait::<i32>::new(61, true), 'tick should be -61');
+        }
+
+        #[test]
+        #[available_gas(200000000)]
+        fn test_clamps_tick_next_to_the_min_tick() {
+            let step = SwapMath::compute_swap_step_to_tick(
+                get_sqrt_ratio_at_tick(IntegerTrait::<i32>::new(0, false)),
+                IntegerTrait::<i32>::new(0, false),
+                MIN_TICK() - IntegerTrait::<i32>::new(100, false),
+                FP64x96Impl::new(MIN_SQRT_RATIO + 1, false),
+                expand_to_18_decimals(1).try_into().unwrap(),
+                IntegerTrait::<i256>::new(1000, false),
+                3000,
+                true
+            );
+
+            assert(step.tick_next == MIN_TICK(), 'tick_next should be MIN_TICK');
+            assert(!step.reached_tick_next, 'should not reach tick_next');
+        }
+    }
 }
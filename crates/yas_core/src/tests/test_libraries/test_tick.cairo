@@ -367,6 +367,81 @@ mod TickTests {
         }
     }
 
+    mod GetSecondsPerLiquidityInside {
+        use super::STATE;
+
+        use yas_core::libraries::tick::Info;
+        use yas_core::libraries::tick::Tick::{TickImpl, InternalImpl};
+        use yas_core::numbers::signed_integer::{
+            i32::i32, i64::i64, i128::i128, integer_trait::IntegerTrait
+        };
+
+        fn tick_info(seconds_per_liquidity_outside_X128: u256) -> Info {
+            Info {
+                fee_growth_outside_0X128: 0,
+                fee_growth_outside_1X128: 0,
+                liquidity_gross: 1,
+                liquidity_net: IntegerTrait::<i128>::new(0, false),
+                seconds_per_liquidity_outside_X128,
+                tick_cumulative_outside: IntegerTrait::<i64>::new(0, false),
+                seconds_outside: 0,
+                initialized: true
+            }
+        }
+
+        #[test]
+        #[available_gas(30000000)]
+        fn test_returns_all_for_two_uninitialized_ticks_if_tick_is_inside() {
+            let state = STATE();
+
+            let seconds_per_liquidity_inside_X128 = TickImpl::get_seconds_per_liquidity_inside(
+                @state,
+                IntegerTrait::<i32>::new(2, true),
+                IntegerTrait::<i32>::new(2, false),
+                IntegerTrait::<i32>::new(0, false),
+                15
+            );
+
+            assert(seconds_per_liquidity_inside_X128 == 15, 'sec_per_liqui should be 15');
+        }
+
+        #[test]
+        #[available_gas(30000000)]
+        fn test_subtracts_upper_and_lower_tick_if_inside() {
+            let mut state = STATE();
+            InternalImpl::set_tick(ref state, IntegerTrait::<i32>::new(2, true), tick_info(2));
+            InternalImpl::set_tick(ref state, IntegerTrait::<i32>::new(2, false), tick_info(3));
+
+            let seconds_per_liquidity_inside_X128 = TickImpl::get_seconds_per_liquidity_inside(
+                @state,
+                IntegerTrait::<i32>::new(2, true),
+                IntegerTrait::<i32>::new(2, false),
+                IntegerTrait::<i32>::new(0, false),
+                15
+            );
+
+            assert(seconds_per_liquidity_inside_X128 == 10, 'sec_per_liqui should be 10');
+        }
+
+        #[test]
+        #[available_gas(30000000)]
+        fn test_returns_the_difference_of_outside_values_if_tick_is_below() {
+            let mut state = STATE();
+            InternalImpl::set_tick(ref state, IntegerTrait::<i32>::new(2, true), tick_info(7));
+            InternalImpl::set_tick(ref state, IntegerTrait::<i32>::new(2, false), tick_info(3));
+
+            let seconds_per_liquidity_inside_X128 = TickImpl::get_seconds_per_liquidity_inside(
+                @state,
+                IntegerTrait::<i32>::new(2, true),
+                IntegerTrait::<i32>::new(2, false),
+                IntegerTrait::<i32>::new(4, true),
+                15
+            );
+
+            assert(seconds_per_liquidity_inside_X128 == 4, 'sec_per_liqui should be 4');
+        }
+    }
+
     mod TickSpacingToMaxLiquidityPerTick {
         use super::STATE;
         use integer::BoundedInt;
@@ -550,6 +625,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 3
             );
@@ -569,6 +645,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 3
             );
@@ -581,6 +658,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 3
             );
@@ -601,6 +679,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 3
             );
@@ -613,6 +692,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 3
             );
@@ -633,6 +713,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 3
             );
@@ -645,6 +726,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 3
             );
@@ -666,6 +748,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 3
             );
@@ -678,6 +761,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 true,
                 3
             );
@@ -690,6 +774,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 3
             );
@@ -708,6 +793,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 10
             );
@@ -720,6 +806,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 true,
                 10
             );
@@ -732,6 +819,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 true,
                 10
             );
@@ -744,6 +832,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 10
             );
@@ -772,6 +861,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 max_u128
             );
@@ -784,6 +874,7 @@ mod TickTests {
                 0,
                 0,
                 0,
+                0,
                 false,
                 max_u128
             );
@@ -803,6 +894,7 @@ mod TickTests {
                 IntegerTrait::<i128>::new(1, false),
                 1,
                 2,
+                3,
                 5,
                 false,
                 max_liquidity
@@ -811,6 +903,9 @@ mod TickTests {
             let result = InternalImpl::get_tick(@state, tick_id);
             assert(result.fee_growth_outside_0X128 == 1, 'fee_growth_0X128 should be 1');
             assert(result.fee_growth_outside_1X128 == 2, 'fee_growth_1X128 should be 2');
+            assert(
+                result.seconds_per_liquidity_outside_X128 == 3, 'sec_per_liqui_X128 should be 3'
+            );
             assert(result.seconds_outside == 5, 'seconds_outside should be 5');
             assert(result.initialized == true, 'initialized should be true');
         }
@@ -828,6 +923,7 @@ mod TickTests {
                 IntegerTrait::<i128>::new(1, false),
                 1,
                 2,
+                3,
                 5,
                 false,
                 max_liquidity
@@ -839,6 +935,7 @@ mod TickTests {
                 IntegerTrait::<i128>::new(1, false),
                 6,
                 7,
+                8,
                 10,
                 false,
                 max_liquidity
@@ -848,6 +945,9 @@ mod TickTests {
             let result = InternalImpl::get_tick(@state, tick_id);
             assert(result.fee_growth_outside_0X128 == 1, 'fee_growth_0X128 should be 1');
             assert(result.fee_growth_outside_1X128 == 2, 'fee_growth_1X128 should be 2');
+            assert(
+                result.seconds_per_liquidity_outside_X128 == 3, 'sec_per_liqui_X128 should be 3'
+            );
             assert(result.seconds_outside == 5, 'seconds_outside should be 5');
             assert(result.initialized == true, 'initialized should be true');
         }
@@ -865,6 +965,7 @@ mod TickTests {
                 IntegerTrait::<i128>::new(1, false),
                 1,
                 2,
+                3,
                 5,
                 false,
                 max_liquidity
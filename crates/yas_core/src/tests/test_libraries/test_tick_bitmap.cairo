@@ -421,6 +421,26 @@ mod TickBitmapTests {
             assert(next == IntegerTrait::<i32>::new(511, false), 'next should be 511');
             assert(initialized == false, 'initialized should be false');
         }
+
+        #[test]
+        #[available_gas(300000000)]
+        fn test_skips_the_spaced_tick_below_a_negative_tick() {
+            let mut state = STATE();
+
+            // -40 compresses to -1, i.e. the tick -60, which is not to the right of -40
+            TickBitmapImpl::flip_tick(
+                ref state, IntegerTrait::<i32>::new(60, true), IntegerTrait::<i32>::new(60, false)
+            );
+            let (next, initialized) = TickBitmapImpl::next_initialized_tick_within_one_word(
+                @state,
+                IntegerTrait::<i32>::new(40, true),
+                IntegerTrait::<i32>::new(60, false),
+                false
+            );
+
+            assert(next == IntegerTrait::<i32>::new(15300, false), 'next should be 15300');
+            assert(initialized == false, 'initialized should be false');
+        }
     }
 
     // lte = true
@@ -585,6 +605,81 @@ mod TickBitmapTests {
             assert(next == IntegerTrait::<i32>::new(329, false), 'next should be 329');
             assert(initialized == true, 'initialized should be true');
         }
+
+        #[test]
+        #[available_gas(300000000)]
+        fn test_finds_negative_tick_in_the_same_word() {
+            let mut state = STATE();
+
+            // -414 and -300 both live in word -2
+            TickBitmapImpl::flip_tick(
+                ref state, IntegerTrait::<i32>::new(414, true), IntegerTrait::<i32>::new(1, false)
+            );
+            let (next, initialized) = TickBitmapImpl::next_initialized_tick_within_one_word(
+                @state,
+                IntegerTrait::<i32>::new(300, true),
+                IntegerTrait::<i32>::new(1, false),
+                true
+            );
+
+            assert(next == IntegerTrait::<i32>::new(414, true), 'next should be -414');
+            assert(initialized == true, 'initialized should be true');
+        }
+
+        #[test]
+        #[available_gas(300000000)]
+        fn test_finds_the_spaced_tick_below_a_negative_tick() {
+            let mut state = STATE();
+
+            TickBitmapImpl::flip_tick(
+                ref state, IntegerTrait::<i32>::new(60, true), IntegerTrait::<i32>::new(60, false)
+            );
+            let (next, initialized) = TickBitmapImpl::next_initialized_tick_within_one_word(
+                @state,
+                IntegerTrait::<i32>::new(40, true),
+                IntegerTrait::<i32>::new(60, false),
+                true
+            );
+
+            assert(next == IntegerTrait::<i32>::new(60, true), 'next should be -60');
+            assert(initialized == true, 'initialized should be true');
+        }
+    }
+
+    // the searches used by the contracts that read the bitmap of a pool through its getters
+    mod NextInitializedTickInWord {
+        use yas_core::libraries::tick_bitmap::TickBitmap::{
+            word_to_search, next_initialized_tick_in_word
+        };
+        use yas_core::numbers::signed_integer::{i16::i16, i32::i32, integer_trait::IntegerTrait};
+        use yas_core::utils::math_utils::BitShift::BitShiftTrait;
+
+        #[test]
+        #[available_gas(30000000)]
+        fn test_searches_the_word_of_the_spaced_tick_below_a_negative_tick() {
+            let tick = IntegerTrait::<i32>::new(40, true);
+            let tick_spacing = IntegerTrait::<i32>::new(60, false);
+
+            let word_pos = word_to_search(tick, tick_spacing, true);
+            assert(word_pos == IntegerTrait::<i16>::new(1, true), 'lte word should be -1');
+            let word_pos = word_to_search(tick, tick_spacing, false);
+            assert(word_pos == IntegerTrait::<i16>::new(0, false), 'gt word should be 0');
+        }
+
+        #[test]
+        #[available_gas(30000000)]
+        fn test_finds_the_spaced_tick_below_a_negative_tick() {
+            // -60 is the last bit of the word -1 at spacing 60
+            let (next, initialized) = next_initialized_tick_in_word(
+                1_u256.shl(255),
+                IntegerTrait::<i32>::new(40, true),
+                IntegerTrait::<i32>::new(60, false),
+                true
+            );
+
+            assert(next == IntegerTrait::<i32>::new(60, true), 'next should be -60');
+            assert(initialized == true, 'initialized should be true');
+        }
     }
 
     mod Position {
@@ -660,5 +755,29 @@ mod TickBitmapTests {
             assert(word == IntegerTrait::<i16>::new(2, true), 'word should be 2');
             assert(bit == 255, 'bit should be 255');
         }
+
+        #[test]
+        #[available_gas(30000000)]
+        fn test_negative_position_at_minus_414() {
+            let (word, bit) = TickBitmap::position(IntegerTrait::<i32>::new(414, true));
+            assert(word == IntegerTrait::<i16>::new(2, true), 'word should be 2');
+            assert(bit == 98, 'bit should be 98');
+        }
+
+        #[test]
+        #[available_gas(30000000)]
+        fn test_negative_position_at_minus_512_boundary() {
+            let (word, bit) = TickBitmap::position(IntegerTrait::<i32>::new(512, true));
+            assert(word == IntegerTrait::<i16>::new(2, true), 'word should be 2');
+            assert(bit == 0, 'bit should be 0');
+        }
+
+        #[test]
+        #[available_gas(30000000)]
+        fn test_negative_position_at_minus_700() {
+            let (word, bit) = TickBitmap::position(IntegerTrait::<i32>::new(700, true));
+            assert(word == IntegerTrait::<i16>::new(3, true), 'word should be 3');
+            assert(bit == 68, 'bit should be 68');
+        }
     }
 }
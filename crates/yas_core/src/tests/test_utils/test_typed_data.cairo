@@ -0,0 +1,71 @@
+mod TypedDataTests {
+    use pedersen::pedersen;
+    use starknet::{ContractAddress, contract_address_const};
+
+    use yas_core::tests::utils::deployers::Deployers::deploy_mock_account;
+    use yas_core::utils::typed_data::TypedData::{
+        StarknetDomain, STARKNET_DOMAIN_TYPE_HASH, U256_TYPE_HASH, compute_hash_on_elements,
+        hash_domain, hash_u256, hash_message, is_valid_signature
+    };
+
+    fn domain() -> StarknetDomain {
+        StarknetDomain { name: 'YAS', version: '1', chain_id: 'SN_GOERLI' }
+    }
+
+    #[test]
+    #[available_gas(2000000)]
+    fn test_compute_hash_on_no_elements() {
+        assert(compute_hash_on_elements(array![].span()) == pedersen(0, 0), 'wrong empty hash');
+    }
+
+    #[test]
+    #[available_gas(2000000)]
+    fn test_compute_hash_on_elements_appends_the_length() {
+        let expected = pedersen(pedersen(pedersen(0, 'a'), 'b'), 2);
+        assert(compute_hash_on_elements(array!['a', 'b'].span()) == expected, 'wrong hash');
+    }
+
+    #[test]
+    #[available_gas(2000000)]
+    fn test_hash_domain() {
+        let expected = compute_hash_on_elements(
+            array![STARKNET_DOMAIN_TYPE_HASH, 'YAS', '1', 'SN_GOERLI'].span()
+        );
+        assert(hash_domain(domain()) == expected, 'wrong domain hash');
+    }
+
+    #[test]
+    #[available_gas(2000000)]
+    fn test_hash_u256() {
+        let value = u256 { low: 1, high: 2 };
+        let expected = compute_hash_on_elements(array![U256_TYPE_HASH, 1, 2].span());
+        assert(hash_u256(value) == expected, 'wrong u256 hash');
+    }
+
+    #[test]
+    #[available_gas(2000000)]
+    fn test_hash_message_depends_on_the_account() {
+        let hash_a = hash_message(domain(), contract_address_const::<1>(), 'struct');
+        let hash_b = hash_message(domain(), contract_address_const::<2>(), 'struct');
+        assert(hash_a != hash_b, 'hashes should differ');
+    }
+
+    #[test]
+    #[available_gas(2000000)]
+    fn test_hash_message_depends_on_the_chain() {
+        let mut other_domain = domain();
+        other_domain.chain_id = 'SN_MAIN';
+        let account = contract_address_const::<1>();
+        let hash_a = hash_message(domain(), account, 'struct');
+        let hash_b = hash_message(other_domain, account, 'struct');
+        assert(hash_a != hash_b, 'hashes should differ');
+    }
+
+    #[test]
+    #[available_gas(20000000)]
+    fn test_is_valid_signature() {
+        let account = deploy_mock_account();
+        assert(is_valid_signature(account, 'hash', array!['hash']), 'should be valid');
+        assert(!is_valid_signature(account, 'hash', array!['other']), 'should be invalid');
+    }
+}
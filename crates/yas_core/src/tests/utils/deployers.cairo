@@ -0,0 +1,81 @@
+/// Deploys the core contracts used across the test suites of every crate in the workspace.
+mod Deployers {
+    use starknet::{ClassHash, ContractAddress, SyscallResultTrait};
+    use starknet::syscalls::deploy_syscall;
+
+    use yas_core::contracts::yas_erc20::{ERC20, IERC20Dispatcher};
+    use yas_core::contracts::yas_factory::{YASFactory, IYASFactoryDispatcher};
+    use yas_core::contracts::yas_router::{YASRouter, IYASRouterDispatcher};
+    use yas_core::libraries::tick_math::{TickMath::MIN_TICK, TickMath::MAX_TICK};
+    use yas_core::numbers::signed_integer::{
+        i32::i32, i32::i32_div_no_round, integer_trait::IntegerTrait
+    };
+    use yas_core::tests::utils::constants::FactoryConstants::{FeeAmount, tick_spacing};
+    use yas_core::tests::utils::mock_account::MockAccount;
+
+    fn deploy_factory(
+        deployer: ContractAddress, pool_class_hash: ClassHash
+    ) -> IYASFactoryDispatcher {
+        let (address, _) = deploy_syscall(
+            YASFactory::TEST_CLASS_HASH.try_into().unwrap(),
+            0,
+            array![deployer.into(), pool_class_hash.into()].span(),
+            true
+        )
+            .unwrap_syscall();
+
+        return IYASFactoryDispatcher { contract_address: address };
+    }
+
+    fn deploy_yas_router(factory: ContractAddress) -> IYASRouterDispatcher {
+        let (address, _) = deploy_syscall(
+            YASRouter::TEST_CLASS_HASH.try_into().unwrap(), 0, array![factory.into()].span(), true
+        )
+            .unwrap_syscall();
+
+        return IYASRouterDispatcher { contract_address: address };
+    }
+
+    // Deploys a token with 18 decimals whose recipient of the initial supply also owns it
+    fn deploy_erc20(
+        name: felt252, symbol: felt252, initial_supply: u256, recipent: ContractAddress
+    ) -> IERC20Dispatcher {
+        deploy_erc20_with_owner(name, symbol, 18, initial_supply, recipent, recipent)
+    }
+
+    fn deploy_erc20_with_owner(
+        name: felt252,
+        symbol: felt252,
+        decimals: u8,
+        initial_supply: u256,
+        recipent: ContractAddress,
+        owner: ContractAddress
+    ) -> IERC20Dispatcher {
+        let mut calldata = array![name, symbol, decimals.into()];
+        Serde::serialize(@initial_supply, ref calldata);
+        calldata.append(recipent.into());
+        calldata.append(owner.into());
+
+        let (address, _) = deploy_syscall(
+            ERC20::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), true
+        )
+            .unwrap_syscall();
+
+        return IERC20Dispatcher { contract_address: address };
+    }
+
+    fn deploy_mock_account() -> ContractAddress {
+        let (address, _) = deploy_syscall(
+            MockAccount::TEST_CLASS_HASH.try_into().unwrap(), 0, array![].span(), true
+        )
+            .unwrap_syscall();
+        address
+    }
+
+    fn get_min_tick_and_max_tick() -> (i32, i32) {
+        let tick_spacing = IntegerTrait::<i32>::new(tick_spacing(FeeAmount::MEDIUM), false);
+        let min_tick = i32_div_no_round(MIN_TICK(), tick_spacing) * tick_spacing;
+        let max_tick = i32_div_no_round(MAX_TICK(), tick_spacing) * tick_spacing;
+        (min_tick, max_tick)
+    }
+}
@@ -0,0 +1,23 @@
+/// Minimal account used to test signature-based entry points without a private key.
+/// A signature is valid when it is the signed hash itself, i.e. `array![hash]`.
+#[starknet::contract]
+mod MockAccount {
+    use yas_core::interfaces::interface_account::IAccount;
+    use yas_core::utils::typed_data::TypedData::VALIDATED;
+
+    #[storage]
+    struct Storage {}
+
+    #[external(v0)]
+    impl MockAccountImpl of IAccount<ContractState> {
+        fn is_valid_signature(
+            self: @ContractState, hash: felt252, signature: Array<felt252>
+        ) -> felt252 {
+            if signature.len() == 1 && *signature[0] == hash {
+                VALIDATED
+            } else {
+                0
+            }
+        }
+    }
+}
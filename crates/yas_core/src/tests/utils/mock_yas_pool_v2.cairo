@@ -0,0 +1,90 @@
+use starknet::ContractAddress;
+
+use yas_core::contracts::yas_pool::Slot0;
+use yas_core::numbers::signed_integer::i32::i32;
+
+#[starknet::interface]
+trait IMockYASPoolV2<TContractState> {
+    fn version(self: @TContractState) -> felt252;
+    fn factory(self: @TContractState) -> ContractAddress;
+    fn token_0(self: @TContractState) -> ContractAddress;
+    fn token_1(self: @TContractState) -> ContractAddress;
+    fn get_fee(self: @TContractState) -> u32;
+    fn get_slot_0(self: @TContractState) -> Slot0;
+    fn get_liquidity(self: @TContractState) -> u128;
+    fn get_tick_spacing(self: @TContractState) -> i32;
+}
+
+/// Minimal pool class used to check that an upgraded pool keeps reading its state.
+/// Storage variables must keep the name and type they have in YASPool.
+#[starknet::contract]
+mod MockYASPoolV2 {
+    use super::IMockYASPoolV2;
+
+    use starknet::ContractAddress;
+
+    use yas_core::contracts::yas_pool::Slot0;
+    use yas_core::numbers::signed_integer::i32::i32;
+
+    #[storage]
+    struct Storage {
+        factory: ContractAddress,
+        token_0: ContractAddress,
+        token_1: ContractAddress,
+        fee: u32,
+        slot_0: Slot0,
+        liquidity: u128,
+        tick_spacing: i32
+    }
+
+    #[constructor]
+    fn constructor(
+        ref self: ContractState,
+        factory: ContractAddress,
+        token_0: ContractAddress,
+        token_1: ContractAddress,
+        fee: u32,
+        tick_spacing: i32,
+    ) {
+        self.factory.write(factory);
+        self.token_0.write(token_0);
+        self.token_1.write(token_1);
+        self.fee.write(fee);
+        self.tick_spacing.write(tick_spacing);
+    }
+
+    #[external(v0)]
+    impl MockYASPoolV2Impl of IMockYASPoolV2<ContractState> {
+        fn version(self: @ContractState) -> felt252 {
+            2
+        }
+
+        fn factory(self: @ContractState) -> ContractAddress {
+            self.factory.read()
+        }
+
+        fn token_0(self: @ContractState) -> ContractAddress {
+            self.token_0.read()
+        }
+
+        fn token_1(self: @ContractState) -> ContractAddress {
+            self.token_1.read()
+        }
+
+        fn get_fee(self: @ContractState) -> u32 {
+            self.fee.read()
+        }
+
+        fn get_slot_0(self: @ContractState) -> Slot0 {
+            self.slot_0.read()
+        }
+
+        fn get_liquidity(self: @ContractState) -> u128 {
+            self.liquidity.read()
+        }
+
+        fn get_tick_spacing(self: @ContractState) -> i32 {
+            self.tick_spacing.read()
+        }
+    }
+}
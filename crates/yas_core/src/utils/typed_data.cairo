@@ -0,0 +1,82 @@
+/// Hashing of SNIP-12 (revision 0) typed data, the Starknet equivalent of EIP-712.
+/// Off-chain signers compute the same hashes, see `deprecated_scripts/src/permit.rs`.
+mod TypedData {
+    use pedersen::pedersen;
+    use starknet::{ContractAddress, contract_address_to_felt252};
+
+    use yas_core::interfaces::interface_account::{IAccountDispatcher, IAccountDispatcherTrait};
+
+    const STARKNET_MESSAGE: felt252 = 'StarkNet Message';
+    /// sn_keccak('StarkNetDomain(name:felt,version:felt,chainId:felt)')
+    const STARKNET_DOMAIN_TYPE_HASH: felt252 =
+        0x1bfc207425a47a5dfa1a50a4f5241203f50624ca5fdf5e18755765416b8e288;
+    /// sn_keccak('u256(low:felt,high:felt)')
+    const U256_TYPE_HASH: felt252 =
+        0x2ee86241508f9ca7043fb572033e45c445012a8dbb2b929391d37fc44fbfceb;
+    /// Value returned by `is_valid_signature` when the signature is valid.
+    const VALIDATED: felt252 = 'VALID';
+
+    #[derive(Copy, Drop)]
+    struct StarknetDomain {
+        name: felt252,
+        version: felt252,
+        chain_id: felt252
+    }
+
+    /// Hashes `elements` as a Pedersen hash chain, followed by the number of elements.
+    fn compute_hash_on_elements(elements: Span<felt252>) -> felt252 {
+        let len = elements.len();
+        let mut elements = elements;
+        let mut hash = 0;
+        loop {
+            match elements.pop_front() {
+                Option::Some(element) => {
+                    hash = pedersen(hash, *element);
+                },
+                Option::None => {
+                    break;
+                }
+            };
+        };
+        pedersen(hash, len.into())
+    }
+
+    fn hash_domain(domain: StarknetDomain) -> felt252 {
+        compute_hash_on_elements(
+            array![STARKNET_DOMAIN_TYPE_HASH, domain.name, domain.version, domain.chain_id].span()
+        )
+    }
+
+    fn hash_u256(value: u256) -> felt252 {
+        compute_hash_on_elements(array![U256_TYPE_HASH, value.low.into(), value.high.into()].span())
+    }
+
+    /// Computes the hash `account` signs to authorize the message whose struct hash is `struct_hash`.
+    /// params:
+    ///     - domain: The domain separating the messages of an application from any other.
+    ///     - account: The account signing the message.
+    ///     - struct_hash: The hash of the message, `compute_hash_on_elements([type_hash, ...fields])`.
+    /// return:
+    ///     - The message hash.
+    fn hash_message(
+        domain: StarknetDomain, account: ContractAddress, struct_hash: felt252
+    ) -> felt252 {
+        compute_hash_on_elements(
+            array![
+                STARKNET_MESSAGE,
+                hash_domain(domain),
+                contract_address_to_felt252(account),
+                struct_hash
+            ]
+                .span()
+        )
+    }
+
+    /// Asks `account` whether `signature` is a valid signature of `hash`.
+    fn is_valid_signature(
+        account: ContractAddress, hash: felt252, signature: Array<felt252>
+    ) -> bool {
+        IAccountDispatcher { contract_address: account }
+            .is_valid_signature(hash, signature) == VALIDATED
+    }
+}
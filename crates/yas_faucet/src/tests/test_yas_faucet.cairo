@@ -3,9 +3,10 @@ mod YASFaucet {
     use starknet::syscalls::deploy_syscall;
     use starknet::testing::{set_contract_address, set_caller_address, set_block_timestamp};
 
-    use yas_core::contracts::yas_erc20::{ERC20, IERC20Dispatcher, IERC20DispatcherTrait};
+    use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
     use yas_faucet::yas_faucet::{YASFaucet, IYASFaucetDispatcher, IYASFaucetDispatcherTrait};
     use yas_core::tests::utils::constants::PoolConstants::{OTHER, OWNER, WALLET};
+    use yas_core::tests::utils::deployers::Deployers::deploy_erc20;
 
     fn setup() -> (IYASFaucetDispatcher, IERC20Dispatcher) {
         let yas_token = deploy_erc20('YAS', '$YAS', 4000000000000000000, OWNER());
@@ -15,6 +16,16 @@ mod YASFaucet {
         (yas_faucet, yas_token)
     }
 
+    // Registers a second token, dripping 500 every hour
+    fn setup_two_tokens() -> (IYASFaucetDispatcher, IERC20Dispatcher, IERC20Dispatcher) {
+        let (yas_faucet, yas_token) = setup();
+        let other_token = deploy_erc20('YAS1', '$YAS1', 4000000000000000000, OWNER());
+        set_contract_address(OWNER());
+        other_token.transfer(yas_faucet.contract_address, 4000000000000000000);
+        yas_faucet.add_token(other_token.contract_address, 500, 3600);
+        (yas_faucet, yas_token, other_token)
+    }
+
     fn deploy_yas_faucet(
         owner: ContractAddress,
         token_address: ContractAddress,
@@ -35,21 +46,6 @@ mod YASFaucet {
         return IYASFaucetDispatcher { contract_address: address };
     }
 
-    fn deploy_erc20(
-        name: felt252, symbol: felt252, initial_supply: u256, recipent: ContractAddress
-    ) -> IERC20Dispatcher {
-        let mut calldata = array![name, symbol];
-        Serde::serialize(@initial_supply, ref calldata);
-        calldata.append(recipent.into());
-
-        let (address, _) = deploy_syscall(
-            ERC20::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), true
-        )
-            .unwrap_syscall();
-
-        return IERC20Dispatcher { contract_address: address };
-    }
-
     #[test]
     #[available_gas(200000000)]
     fn test_happy_path() {
@@ -156,4 +152,220 @@ mod YASFaucet {
         assert(yas_erc_20.balanceOf(OTHER()) == 4000000000000000000, 'wrong balance');
         assert(yas_erc_20.balanceOf(yas_faucet.contract_address) == 0, 'wrong balance');
     }
+
+    #[test]
+    #[available_gas(200000000)]
+    fn test_constructor_registers_the_default_token() {
+        let (yas_faucet, yas_erc_20) = setup();
+
+        let tokens = yas_faucet.get_tokens();
+        assert(tokens.len() == 1, 'wrong tokens len');
+        assert(*tokens[0] == yas_erc_20.contract_address, 'wrong token');
+        let config = yas_faucet.get_token_config(yas_erc_20.contract_address);
+        assert(config.withdrawal_amount == 1000, 'wrong withdrawal_amount');
+        assert(config.wait_time == 86400, 'wrong wait_time');
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    fn test_add_token() {
+        let (yas_faucet, yas_erc_20, other_token) = setup_two_tokens();
+
+        let tokens = yas_faucet.get_tokens();
+        assert(tokens.len() == 2, 'wrong tokens len');
+        assert(*tokens[0] == yas_erc_20.contract_address, 'wrong token 0');
+        assert(*tokens[1] == other_token.contract_address, 'wrong token 1');
+        let config = yas_faucet.get_token_config(other_token.contract_address);
+        assert(config.withdrawal_amount == 500, 'wrong withdrawal_amount');
+        assert(config.wait_time == 3600, 'wrong wait_time');
+        assert(
+            yas_faucet.get_token_amount_faucet(other_token.contract_address) == 4000000000000000000,
+            'wrong amount_faucet'
+        );
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    #[should_panic(expected: ('Token already registered', 'ENTRYPOINT_FAILED'))]
+    fn test_add_token_twice_panic() {
+        let (yas_faucet, _, other_token) = setup_two_tokens();
+
+        yas_faucet.add_token(other_token.contract_address, 500, 3600);
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    #[should_panic(expected: ('Caller is not the owner', 'ENTRYPOINT_FAILED'))]
+    fn test_add_token_not_owner_panic() {
+        let (yas_faucet, _) = setup();
+
+        set_contract_address(WALLET());
+        yas_faucet.add_token(contract_address_const::<0x1234>(), 500, 3600);
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    fn test_faucet_mint_token() {
+        let (yas_faucet, yas_erc_20, other_token) = setup_two_tokens();
+
+        set_contract_address(WALLET());
+        yas_faucet.faucet_mint_token(other_token.contract_address);
+
+        assert(other_token.balanceOf(WALLET()) == 500, 'wrong balance');
+        assert(yas_erc_20.balanceOf(WALLET()) == 0, 'wrong default balance');
+        assert(
+            yas_faucet.get_user_token_unlock_time(WALLET(), other_token.contract_address) == 3600,
+            'wrong unlock time'
+        );
+        assert(yas_faucet.get_user_unlock_time(WALLET()) == 0, 'wrong default unlock time');
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    #[should_panic(expected: ('Token not registered', 'ENTRYPOINT_FAILED'))]
+    fn test_faucet_mint_token_not_registered_panic() {
+        let (yas_faucet, _) = setup();
+
+        set_contract_address(WALLET());
+        yas_faucet.faucet_mint_token(contract_address_const::<0x1234>());
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    fn test_faucet_mint_all() {
+        let (yas_faucet, yas_erc_20, other_token) = setup_two_tokens();
+
+        set_contract_address(WALLET());
+        yas_faucet.faucet_mint_all();
+
+        assert(yas_erc_20.balanceOf(WALLET()) == 1000, 'wrong balance');
+        assert(other_token.balanceOf(WALLET()) == 500, 'wrong other balance');
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    fn test_faucet_mint_all_skips_locked_tokens() {
+        let (yas_faucet, yas_erc_20, other_token) = setup_two_tokens();
+
+        set_contract_address(WALLET());
+        yas_faucet.faucet_mint_all();
+
+        // only the wait time of the other token has passed
+        set_block_timestamp(3600 + 1);
+        yas_faucet.faucet_mint_all();
+
+        assert(yas_erc_20.balanceOf(WALLET()) == 1000, 'wrong balance');
+        assert(other_token.balanceOf(WALLET()) == 1000, 'wrong other balance');
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    #[should_panic(expected: ('Nothing to withdraw', 'ENTRYPOINT_FAILED'))]
+    fn test_faucet_mint_all_nothing_to_withdraw_panic() {
+        let (yas_faucet, _, _) = setup_two_tokens();
+
+        set_contract_address(WALLET());
+        yas_faucet.faucet_mint_all();
+        yas_faucet.faucet_mint_all();
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    fn test_set_token_config() {
+        let (yas_faucet, _, other_token) = setup_two_tokens();
+
+        yas_faucet.set_token_config(other_token.contract_address, 42, 60);
+
+        let config = yas_faucet.get_token_config(other_token.contract_address);
+        assert(config.withdrawal_amount == 42, 'wrong withdrawal_amount');
+        assert(config.wait_time == 60, 'wrong wait_time');
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    fn test_remove_token() {
+        let (yas_faucet, yas_erc_20, other_token) = setup_two_tokens();
+
+        yas_faucet.remove_token(other_token.contract_address);
+
+        let tokens = yas_faucet.get_tokens();
+        assert(tokens.len() == 1, 'wrong tokens len');
+        assert(*tokens[0] == yas_erc_20.contract_address, 'wrong token');
+
+        // the faucet keeps the balance of a removed token
+        yas_faucet.withdraw_all_token_balance(other_token.contract_address, OTHER());
+        assert(other_token.balanceOf(OTHER()) == 4000000000000000000, 'wrong balance');
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    #[should_panic(expected: ('Token not registered', 'ENTRYPOINT_FAILED'))]
+    fn test_faucet_mint_removed_token_panic() {
+        let (yas_faucet, _, other_token) = setup_two_tokens();
+        yas_faucet.remove_token(other_token.contract_address);
+
+        set_contract_address(WALLET());
+        yas_faucet.faucet_mint_token(other_token.contract_address);
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    #[should_panic(expected: ('Cannot remove default token', 'ENTRYPOINT_FAILED'))]
+    fn test_remove_default_token_panic() {
+        let (yas_faucet, yas_erc_20) = setup();
+
+        set_contract_address(OWNER());
+        yas_faucet.remove_token(yas_erc_20.contract_address);
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    fn test_faucet_mint_to() {
+        let (yas_faucet, yas_erc_20) = setup();
+        yas_faucet.set_relayer(OTHER());
+        assert(yas_faucet.get_relayer() == OTHER(), 'wrong relayer');
+
+        set_contract_address(OTHER());
+        yas_faucet.faucet_mint_to(WALLET());
+
+        assert(yas_erc_20.balanceOf(WALLET()) == 1000, 'wrong balance');
+        assert(yas_erc_20.balanceOf(OTHER()) == 0, 'wrong relayer balance');
+        assert(yas_faucet.get_user_unlock_time(WALLET()) == 86400, 'wrong unlock time');
+        assert(yas_faucet.get_user_unlock_time(OTHER()) == 0, 'wrong relayer unlock time');
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    #[should_panic(expected: ('Not allowed to withdraw', 'ENTRYPOINT_FAILED'))]
+    fn test_faucet_mint_to_respects_user_cooldown_panic() {
+        let (yas_faucet, _) = setup();
+        yas_faucet.set_relayer(OTHER());
+
+        set_contract_address(WALLET());
+        yas_faucet.faucet_mint();
+
+        set_contract_address(OTHER());
+        yas_faucet.faucet_mint_to(WALLET());
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    #[should_panic(expected: ('Caller is not the relayer', 'ENTRYPOINT_FAILED'))]
+    fn test_faucet_mint_to_not_relayer_panic() {
+        let (yas_faucet, _) = setup();
+        yas_faucet.set_relayer(OTHER());
+
+        set_contract_address(WALLET());
+        yas_faucet.faucet_mint_to(WALLET());
+    }
+
+    #[test]
+    #[available_gas(200000000)]
+    #[should_panic(expected: ('Caller is not the owner', 'ENTRYPOINT_FAILED'))]
+    fn test_set_relayer_not_owner_panic() {
+        let (yas_faucet, _) = setup();
+
+        set_contract_address(WALLET());
+        yas_faucet.set_relayer(WALLET());
+    }
 }
@@ -1,21 +1,55 @@
 use starknet::ContractAddress;
 
+// drip settings of a token registered in the faucet
+#[derive(Copy, Drop, Serde, starknet::Store)]
+struct TokenConfig {
+    // the amount sent to a user on each drip
+    withdrawal_amount: u256,
+    // the seconds a user has to wait between two drips
+    wait_time: u64
+}
+
 #[starknet::interface]
 trait IYASFaucet<TContractState> {
     fn faucet_mint(ref self: TContractState);
+    fn faucet_mint_token(ref self: TContractState, token: ContractAddress);
+    fn faucet_mint_all(ref self: TContractState);
+    fn faucet_mint_to(ref self: TContractState, user: ContractAddress);
     fn withdraw_all_balance(ref self: TContractState, user: ContractAddress);
+    fn withdraw_all_token_balance(
+        ref self: TContractState, token: ContractAddress, user: ContractAddress
+    );
     fn get_user_unlock_time(self: @TContractState, user: ContractAddress) -> u64;
+    fn get_user_token_unlock_time(
+        self: @TContractState, user: ContractAddress, token: ContractAddress
+    ) -> u64;
     fn get_amount_faucet(self: @TContractState) -> u256;
+    fn get_token_amount_faucet(self: @TContractState, token: ContractAddress) -> u256;
     fn get_token_address(self: @TContractState) -> ContractAddress;
+    fn get_tokens(self: @TContractState) -> Array<ContractAddress>;
+    fn get_token_config(self: @TContractState, token: ContractAddress) -> TokenConfig;
     fn get_withdrawal_amount(self: @TContractState) -> u256;
     fn get_wait_time(self: @TContractState) -> u64;
+    fn get_relayer(self: @TContractState) -> ContractAddress;
     fn set_withdrawal_amount(ref self: TContractState, amount: u256);
     fn set_wait_time(ref self: TContractState, wait_time: u64);
+    fn set_relayer(ref self: TContractState, relayer: ContractAddress);
+    fn add_token(
+        ref self: TContractState, token: ContractAddress, withdrawal_amount: u256, wait_time: u64
+    );
+    fn remove_token(ref self: TContractState, token: ContractAddress);
+    fn set_token_config(
+        ref self: TContractState, token: ContractAddress, withdrawal_amount: u256, wait_time: u64
+    );
 }
 
+/// Drips test tokens to users, at most once per wait time for each token.
+/// The token passed to the constructor is the default token, the one the single token entry points
+/// (`faucet_mint`, `get_withdrawal_amount`, ...) act on. More tokens can be registered by the owner.
+/// The owner can also set a relayer, allowed to drip the default token to users on their behalf.
 #[starknet::contract]
 mod YASFaucet {
-    use super::IYASFaucet;
+    use super::{IYASFaucet, TokenConfig};
 
     use starknet::{ContractAddress, get_block_timestamp, get_caller_address, get_contract_address};
     use openzeppelin::access::ownable::Ownable;
@@ -23,10 +57,18 @@ mod YASFaucet {
 
     #[storage]
     struct Storage {
-        user_unlock_time: LegacyMap<ContractAddress, u64>,
+        // (user, token) -> the timestamp after which user can drip token again
+        user_unlock_time: LegacyMap<(ContractAddress, ContractAddress), u64>,
+        // the default token
         token_address: ContractAddress,
-        withdrawal_amount: u256,
-        wait_time: u64,
+        // the registered tokens, in no particular order
+        tokens: LegacyMap<u32, ContractAddress>,
+        tokens_len: u32,
+        // 1-based position of a token in `tokens`, 0 if the token is not registered
+        token_position: LegacyMap<ContractAddress, u32>,
+        token_configs: LegacyMap<ContractAddress, TokenConfig>,
+        // the account allowed to call `faucet_mint_to`, zero if there is none
+        relayer: ContractAddress,
     }
 
     #[constructor]
@@ -40,70 +82,172 @@ mod YASFaucet {
         let mut unsafe_state = Ownable::unsafe_new_contract_state();
         Ownable::InternalImpl::initializer(ref unsafe_state, owner);
         self.token_address.write(token_address);
-        self.withdrawal_amount.write(withdrawal_amount);
-        self.wait_time.write(wait_time);
+        self.register_token(token_address, TokenConfig { withdrawal_amount, wait_time });
     }
 
     #[external(v0)]
     impl YASFaucetImpl of IYASFaucet<ContractState> {
         fn faucet_mint(ref self: ContractState) {
-            let withdrawal_amount = self.withdrawal_amount.read();
-            assert(self.get_amount_faucet() > withdrawal_amount, 'There is not enough balance');
+            self.faucet_mint_token(self.token_address.read());
+        }
+
+        fn faucet_mint_token(ref self: ContractState, token: ContractAddress) {
+            self.checked_drip(get_caller_address(), token);
+        }
+
+        // Drips every registered token the caller is allowed to withdraw and the faucet holds
+        // enough of, skipping the others. Fails if no token can be dripped
+        fn faucet_mint_all(ref self: ContractState) {
             let caller_address = get_caller_address();
-            assert(self.allowed_to_withdraw(caller_address), 'Not allowed to withdraw');
-            self
-                .user_unlock_time
-                .write(caller_address, get_block_timestamp() + self.wait_time.read());
-            IERC20Dispatcher { contract_address: self.token_address.read() }
-                .transfer(caller_address, withdrawal_amount);
+            let mut dripped = false;
+            let mut i = 0;
+            loop {
+                if i == self.tokens_len.read() {
+                    break;
+                }
+                let token = self.tokens.read(i);
+                let config = self.token_configs.read(token);
+                if self.allowed_to_withdraw(caller_address, token)
+                    && self.get_token_amount_faucet(token) > config.withdrawal_amount {
+                    self.drip(caller_address, token, config);
+                    dripped = true;
+                }
+                i += 1;
+            };
+            assert(dripped, 'Nothing to withdraw');
+        }
+
+        // Drips the default token to user, with the cooldown of user. Only callable by the relayer
+        fn faucet_mint_to(ref self: ContractState, user: ContractAddress) {
+            assert(get_caller_address() == self.relayer.read(), 'Caller is not the relayer');
+            self.checked_drip(user, self.token_address.read());
         }
 
         fn withdraw_all_balance(ref self: ContractState, user: ContractAddress) {
+            self.withdraw_all_token_balance(self.token_address.read(), user);
+        }
+
+        fn withdraw_all_token_balance(
+            ref self: ContractState, token: ContractAddress, user: ContractAddress
+        ) {
             let unsafe_state = Ownable::unsafe_new_contract_state();
             Ownable::InternalImpl::assert_only_owner(@unsafe_state);
-            let balance = self.get_amount_faucet();
-            IERC20Dispatcher { contract_address: self.token_address.read() }
-                .transfer(user, balance);
+            let balance = self.get_token_amount_faucet(token);
+            IERC20Dispatcher { contract_address: token }.transfer(user, balance);
         }
 
         fn get_amount_faucet(self: @ContractState) -> u256 {
-            IERC20Dispatcher { contract_address: self.token_address.read() }
-                .balanceOf(get_contract_address())
+            self.get_token_amount_faucet(self.token_address.read())
+        }
+
+        fn get_token_amount_faucet(self: @ContractState, token: ContractAddress) -> u256 {
+            IERC20Dispatcher { contract_address: token }.balanceOf(get_contract_address())
         }
 
         fn get_user_unlock_time(self: @ContractState, user: ContractAddress) -> u64 {
-            self.user_unlock_time.read(user)
+            self.get_user_token_unlock_time(user, self.token_address.read())
+        }
+
+        fn get_user_token_unlock_time(
+            self: @ContractState, user: ContractAddress, token: ContractAddress
+        ) -> u64 {
+            self.user_unlock_time.read((user, token))
         }
 
         fn get_token_address(self: @ContractState) -> ContractAddress {
             self.token_address.read()
         }
 
+        fn get_tokens(self: @ContractState) -> Array<ContractAddress> {
+            let mut tokens = array![];
+            let mut i = 0;
+            loop {
+                if i == self.tokens_len.read() {
+                    break;
+                }
+                tokens.append(self.tokens.read(i));
+                i += 1;
+            };
+            tokens
+        }
+
+        fn get_token_config(self: @ContractState, token: ContractAddress) -> TokenConfig {
+            self.registered_token_config(token)
+        }
+
         fn get_withdrawal_amount(self: @ContractState) -> u256 {
-            self.withdrawal_amount.read()
+            self.token_configs.read(self.token_address.read()).withdrawal_amount
         }
 
         fn get_wait_time(self: @ContractState) -> u64 {
-            self.wait_time.read()
+            self.token_configs.read(self.token_address.read()).wait_time
+        }
+
+        fn get_relayer(self: @ContractState) -> ContractAddress {
+            self.relayer.read()
         }
 
         fn set_withdrawal_amount(ref self: ContractState, amount: u256) {
+            self.set_token_config(self.token_address.read(), amount, self.get_wait_time());
+        }
+
+        fn set_wait_time(ref self: ContractState, wait_time: u64) {
+            let withdrawal_amount = self.get_withdrawal_amount();
+            self.set_token_config(self.token_address.read(), withdrawal_amount, wait_time);
+        }
+
+        // Sets the account allowed to call `faucet_mint_to`, zero to disable relaying
+        fn set_relayer(ref self: ContractState, relayer: ContractAddress) {
             let unsafe_state = Ownable::unsafe_new_contract_state();
             Ownable::InternalImpl::assert_only_owner(@unsafe_state);
-            self.withdrawal_amount.write(amount);
+            self.relayer.write(relayer);
         }
 
-        fn set_wait_time(ref self: ContractState, wait_time: u64) {
+        fn add_token(
+            ref self: ContractState, token: ContractAddress, withdrawal_amount: u256, wait_time: u64
+        ) {
+            let unsafe_state = Ownable::unsafe_new_contract_state();
+            Ownable::InternalImpl::assert_only_owner(@unsafe_state);
+            self.register_token(token, TokenConfig { withdrawal_amount, wait_time });
+        }
+
+        // Unregisters token. The faucet keeps its balance of token, which the owner can withdraw
+        fn remove_token(ref self: ContractState, token: ContractAddress) {
             let unsafe_state = Ownable::unsafe_new_contract_state();
             Ownable::InternalImpl::assert_only_owner(@unsafe_state);
-            self.wait_time.write(wait_time);
+            assert(token != self.token_address.read(), 'Cannot remove default token');
+
+            let position = self.token_position.read(token);
+            assert(position != 0, 'Token not registered');
+
+            // move the last token to the position of the removed one
+            let last_index = self.tokens_len.read() - 1;
+            let last_token = self.tokens.read(last_index);
+            self.tokens.write(position - 1, last_token);
+            self.token_position.write(last_token, position);
+
+            self.tokens.write(last_index, Zeroable::zero());
+            self.tokens_len.write(last_index);
+            self.token_position.write(token, 0);
+            self.token_configs.write(token, TokenConfig { withdrawal_amount: 0, wait_time: 0 });
+        }
+
+        fn set_token_config(
+            ref self: ContractState, token: ContractAddress, withdrawal_amount: u256, wait_time: u64
+        ) {
+            let unsafe_state = Ownable::unsafe_new_contract_state();
+            Ownable::InternalImpl::assert_only_owner(@unsafe_state);
+            assert(self.token_position.read(token) != 0, 'Token not registered');
+            self.token_configs.write(token, TokenConfig { withdrawal_amount, wait_time });
         }
     }
 
     #[generate_trait]
     impl InternalImpl of InternalTrait {
-        fn allowed_to_withdraw(self: @ContractState, user: ContractAddress) -> bool {
-            let unlock_time = self.user_unlock_time.read(user);
+        fn allowed_to_withdraw(
+            self: @ContractState, user: ContractAddress, token: ContractAddress
+        ) -> bool {
+            let unlock_time = self.user_unlock_time.read((user, token));
             if unlock_time == 0 {
                 return true;
             }
@@ -114,5 +258,41 @@ mod YASFaucet {
                 false
             }
         }
+
+        fn registered_token_config(self: @ContractState, token: ContractAddress) -> TokenConfig {
+            assert(self.token_position.read(token) != 0, 'Token not registered');
+            self.token_configs.read(token)
+        }
+
+        fn register_token(ref self: ContractState, token: ContractAddress, config: TokenConfig) {
+            assert(token.is_non_zero(), 'Token is zero');
+            assert(self.token_position.read(token) == 0, 'Token already registered');
+
+            let len = self.tokens_len.read();
+            self.tokens.write(len, token);
+            self.tokens_len.write(len + 1);
+            self.token_position.write(token, len + 1);
+            self.token_configs.write(token, config);
+        }
+
+        fn checked_drip(ref self: ContractState, user: ContractAddress, token: ContractAddress) {
+            let config = self.registered_token_config(token);
+            assert(
+                self.get_token_amount_faucet(token) > config.withdrawal_amount,
+                'There is not enough balance'
+            );
+            assert(self.allowed_to_withdraw(user, token), 'Not allowed to withdraw');
+            self.drip(user, token, config);
+        }
+
+        fn drip(
+            ref self: ContractState,
+            user: ContractAddress,
+            token: ContractAddress,
+            config: TokenConfig
+        ) {
+            self.user_unlock_time.write((user, token), get_block_timestamp() + config.wait_time);
+            IERC20Dispatcher { contract_address: token }.transfer(user, config.withdrawal_amount);
+        }
     }
 }
@@ -1,6 +1,14 @@
+mod nft_descriptor;
 mod yas_nft_position_manager;
+mod yas_quoter;
+mod yas_staker;
+mod yas_tick_lens;
 
 #[cfg(test)]
 mod tests {
+    mod test_nft_descriptor;
     mod test_yas_nft_position_manager;
+    mod test_yas_quoter;
+    mod test_yas_staker;
+    mod test_yas_tick_lens;
 }
@@ -0,0 +1,176 @@
+use starknet::ContractAddress;
+
+use yas_core::numbers::signed_integer::i32::i32;
+
+#[derive(Copy, Drop)]
+struct ConstructTokenURIParams {
+    token_id: u256,
+    pool: ContractAddress,
+    token_0: ContractAddress,
+    token_1: ContractAddress,
+    fee: u32,
+    tick_lower: i32,
+    tick_upper: i32,
+    liquidity: u128
+}
+
+/// Renders the metadata of a position NFT on-chain.
+/// Cairo 2.2 has no string type, so the URI is returned as a list of short strings: the metadata
+/// is the concatenation of every felt in the array, each one holding at most 31 ASCII characters.
+mod NFTDescriptor {
+    use super::ConstructTokenURIParams;
+
+    use starknet::{ContractAddress, contract_address_to_felt252};
+
+    use yas_core::libraries::tick_math::TickMath::get_sqrt_ratio_at_tick;
+    use yas_core::numbers::signed_integer::i32::i32;
+
+    /// Maximum number of characters a short string can hold.
+    const MAX_CHUNK_LEN: u32 = 31;
+
+    /// Builds a `data:` URI holding the JSON metadata of a position.
+    /// params:
+    ///     - params: The position and the pool it belongs to.
+    /// return:
+    ///     - The URI, split in short strings.
+    fn construct_token_uri(params: ConstructTokenURIParams) -> Array<felt252> {
+        let mut uri = array!['data:application/json;utf8,'];
+
+        uri.append('{"name":"YAS - ');
+        append_fee_percent(ref uri, params.fee);
+        uri.append(' - ');
+        append_i32(ref uri, params.tick_lower);
+        uri.append('<>');
+        append_i32(ref uri, params.tick_upper);
+        uri.append('","description":"');
+        uri.append('A YAS liquidity position');
+
+        uri.append('","token_id":"');
+        append_u256(ref uri, params.token_id);
+        uri.append('","pool":"');
+        append_address(ref uri, params.pool);
+        uri.append('","token_0":"');
+        append_address(ref uri, params.token_0);
+        uri.append('","token_1":"');
+        append_address(ref uri, params.token_1);
+        uri.append('","fee":');
+        append_u256(ref uri, params.fee.into());
+        uri.append(',"tick_lower":');
+        append_i32(ref uri, params.tick_lower);
+        uri.append(',"tick_upper":');
+        append_i32(ref uri, params.tick_upper);
+        uri.append(',"sqrt_price_lower_X96":"');
+        append_u256(ref uri, get_sqrt_ratio_at_tick(params.tick_lower).mag);
+        uri.append('","sqrt_price_upper_X96":"');
+        append_u256(ref uri, get_sqrt_ratio_at_tick(params.tick_upper).mag);
+        uri.append('","liquidity":"');
+        append_u256(ref uri, params.liquidity.into());
+        uri.append('"}');
+
+        uri
+    }
+
+    /// Appends the decimal representation of `value`.
+    fn append_u256(ref out: Array<felt252>, value: u256) {
+        let mut value = value;
+        // least significant digit first
+        let mut chars: Array<u8> = array![];
+        loop {
+            chars.append(digit_to_ascii((value % 10).try_into().unwrap()));
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        };
+        append_chars_reversed(ref out, chars.span());
+    }
+
+    /// Appends the decimal representation of `value`, prefixed with `-` if it is negative.
+    fn append_i32(ref out: Array<felt252>, value: i32) {
+        if value.sign && value.mag != 0 {
+            out.append('-');
+        }
+        append_u256(ref out, value.mag.into());
+    }
+
+    /// Appends `address` as a `0x` prefixed, zero padded, 64 characters hex string.
+    fn append_address(ref out: Array<felt252>, address: ContractAddress) {
+        let mut value: u256 = contract_address_to_felt252(address).into();
+        // least significant digit first
+        let mut chars: Array<u8> = array![];
+        let mut i = 0;
+        loop {
+            if i == 64 {
+                break;
+            }
+            chars.append(digit_to_ascii((value % 16).try_into().unwrap()));
+            value /= 16;
+            i += 1;
+        };
+        out.append('0x');
+        append_chars_reversed(ref out, chars.span());
+    }
+
+    /// Appends `fee`, denominated in hundredths of a bip, as a percentage (e.g. 3000 -> 0.3%).
+    /// The `%` sign is percent-encoded, as required inside a `data:` URI.
+    fn append_fee_percent(ref out: Array<felt252>, fee: u32) {
+        append_u256(ref out, (fee / 10000).into());
+
+        let mut decimals = fee % 10000;
+        if decimals != 0 {
+            // least significant digit first, trailing zeros dropped
+            let mut chars: Array<u8> = array![];
+            let mut significant = false;
+            let mut i = 0;
+            loop {
+                if i == 4 {
+                    break;
+                }
+                let digit: u8 = (decimals % 10).try_into().unwrap();
+                if digit != 0 || significant {
+                    significant = true;
+                    chars.append(digit_to_ascii(digit));
+                }
+                decimals /= 10;
+                i += 1;
+            };
+            out.append('.');
+            append_chars_reversed(ref out, chars.span());
+        }
+
+        out.append('%25');
+    }
+
+    /// Packs `chars`, given in reverse order, into short strings of at most MAX_CHUNK_LEN characters.
+    fn append_chars_reversed(ref out: Array<felt252>, mut chars: Span<u8>) {
+        let mut chunk: felt252 = 0;
+        let mut chunk_len = 0;
+        loop {
+            match chars.pop_back() {
+                Option::Some(char) => {
+                    chunk = chunk * 256 + (*char).into();
+                    chunk_len += 1;
+                    if chunk_len == MAX_CHUNK_LEN {
+                        out.append(chunk);
+                        chunk = 0;
+                        chunk_len = 0;
+                    }
+                },
+                Option::None => {
+                    break;
+                }
+            };
+        };
+        if chunk_len != 0 {
+            out.append(chunk);
+        }
+    }
+
+    fn digit_to_ascii(digit: u8) -> u8 {
+        if digit < 10 {
+            '0' + digit
+        } else {
+            'a' + digit - 10
+        }
+    }
+}
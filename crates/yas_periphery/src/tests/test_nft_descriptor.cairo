@@ -0,0 +1,110 @@
+mod NFTDescriptorTests {
+    mod AppendU256 {
+        use integer::BoundedInt;
+        use yas_periphery::nft_descriptor::NFTDescriptor::append_u256;
+
+        #[test]
+        fn test_zero() {
+            let mut out = array![];
+            append_u256(ref out, 0);
+            assert(out == array!['0'], 'wrong zero');
+        }
+
+        #[test]
+        fn test_small_number() {
+            let mut out = array![];
+            append_u256(ref out, 1234567890);
+            assert(out == array!['1234567890'], 'wrong small number');
+        }
+
+        #[test]
+        fn test_splits_in_short_strings() {
+            let mut out = array![];
+            append_u256(ref out, BoundedInt::max());
+            assert(
+                out == array![
+                    '1157920892373161954235709850086',
+                    '8790785326998466564056403945758',
+                    '4007913129639935'
+                ],
+                'wrong max u256'
+            );
+        }
+    }
+
+    mod AppendI32 {
+        use yas_core::numbers::signed_integer::{i32::i32, integer_trait::IntegerTrait};
+        use yas_periphery::nft_descriptor::NFTDescriptor::append_i32;
+
+        #[test]
+        fn test_positive() {
+            let mut out = array![];
+            append_i32(ref out, IntegerTrait::<i32>::new(887220, false));
+            assert(out == array!['887220'], 'wrong positive');
+        }
+
+        #[test]
+        fn test_negative() {
+            let mut out = array![];
+            append_i32(ref out, IntegerTrait::<i32>::new(887220, true));
+            assert(out == array!['-', '887220'], 'wrong negative');
+        }
+
+        #[test]
+        fn test_negative_zero() {
+            let mut out = array![];
+            append_i32(ref out, IntegerTrait::<i32>::new(0, true));
+            assert(out == array!['0'], 'wrong negative zero');
+        }
+    }
+
+    mod AppendAddress {
+        use starknet::contract_address_const;
+        use yas_periphery::nft_descriptor::NFTDescriptor::append_address;
+
+        #[test]
+        fn test_pads_to_64_characters() {
+            let mut out = array![];
+            append_address(ref out, contract_address_const::<0xabc>());
+            assert(
+                out == array![
+                    '0x',
+                    '0000000000000000000000000000000',
+                    '000000000000000000000000000000a',
+                    'bc'
+                ],
+                'wrong address'
+            );
+        }
+    }
+
+    mod AppendFeePercent {
+        use yas_periphery::nft_descriptor::NFTDescriptor::append_fee_percent;
+
+        #[test]
+        fn test_fee_tiers() {
+            let mut out = array![];
+            append_fee_percent(ref out, 100);
+            assert(out == array!['0', '.', '01', '%25'], 'wrong 100 fee');
+
+            let mut out = array![];
+            append_fee_percent(ref out, 500);
+            assert(out == array!['0', '.', '05', '%25'], 'wrong 500 fee');
+
+            let mut out = array![];
+            append_fee_percent(ref out, 3000);
+            assert(out == array!['0', '.', '3', '%25'], 'wrong 3000 fee');
+
+            let mut out = array![];
+            append_fee_percent(ref out, 10000);
+            assert(out == array!['1', '%25'], 'wrong 10000 fee');
+        }
+
+        #[test]
+        fn test_odd_fee() {
+            let mut out = array![];
+            append_fee_percent(ref out, 12345);
+            assert(out == array!['1', '.', '2345', '%25'], 'wrong 12345 fee');
+        }
+    }
+}
@@ -1,28 +1,22 @@
 mod YASNFTPositionManagerTests {
-    use starknet::{ClassHash, ContractAddress, SyscallResultTrait};
+    use starknet::{ContractAddress, SyscallResultTrait};
     use starknet::syscalls::deploy_syscall;
     use starknet::testing::{set_contract_address};
     use integer::BoundedInt;
 
-    use yas_core::contracts::yas_erc20::{
-        ERC20, ERC20::ERC20Impl, IERC20Dispatcher, IERC20DispatcherTrait
-    };
-    use yas_core::contracts::yas_factory::{
-        YASFactory, IYASFactory, IYASFactoryDispatcher, IYASFactoryDispatcherTrait
-    };
-    use yas_core::libraries::tick_math::{TickMath::MIN_TICK, TickMath::MAX_TICK};
+    use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
     use yas_core::numbers::fixed_point::implementations::impl_64x96::{
         FP64x96Impl, FixedType, FixedTrait
     };
-    use yas_core::numbers::signed_integer::{
-        i32::i32, i32::i32_div_no_round, integer_trait::IntegerTrait
-    };
     use yas_core::tests::utils::constants::FactoryConstants::{
-        POOL_CLASS_HASH, FeeAmount, fee_amount, tick_spacing, OWNER
+        POOL_CLASS_HASH, FeeAmount, fee_amount, OWNER
     };
     use yas_core::tests::utils::constants::PoolConstants::{
         TOKEN_A, TOKEN_B, POOL_ADDRESS, WALLET, encode_price_sqrt_1_1
     };
+    use yas_core::tests::utils::deployers::Deployers::{
+        deploy_factory, deploy_erc20, deploy_mock_account, get_min_tick_and_max_tick
+    };
 
     use yas_periphery::yas_nft_position_manager::{
         YASNFTPositionManager, IYASNFTPositionManager, IYASNFTPositionManagerDispatcher,
@@ -49,35 +43,6 @@ mod YASNFTPositionManagerTests {
         (nft_position_manager, token_0, token_1)
     }
 
-    fn deploy_factory(
-        deployer: ContractAddress, pool_class_hash: ClassHash
-    ) -> IYASFactoryDispatcher {
-        let (address, _) = deploy_syscall(
-            YASFactory::TEST_CLASS_HASH.try_into().unwrap(),
-            0,
-            array![deployer.into(), pool_class_hash.into()].span(),
-            true
-        )
-            .unwrap_syscall();
-
-        return IYASFactoryDispatcher { contract_address: address };
-    }
-
-    fn deploy_erc20(
-        name: felt252, symbol: felt252, initial_supply: u256, recipent: ContractAddress
-    ) -> IERC20Dispatcher {
-        let mut calldata = array![name, symbol];
-        Serde::serialize(@initial_supply, ref calldata);
-        calldata.append(recipent.into());
-
-        let (address, _) = deploy_syscall(
-            ERC20::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), true
-        )
-            .unwrap_syscall();
-
-        return IERC20Dispatcher { contract_address: address };
-    }
-
     fn deploy_nft_position_manager(factory: ContractAddress) -> IYASNFTPositionManagerDispatcher {
         let (address, _) = deploy_syscall(
             YASNFTPositionManager::TEST_CLASS_HASH.try_into().unwrap(),
@@ -90,16 +55,43 @@ mod YASNFTPositionManagerTests {
         return IYASNFTPositionManagerDispatcher { contract_address: address };
     }
 
-    fn get_min_tick_and_max_tick() -> (i32, i32) {
-        let tick_spacing = IntegerTrait::<i32>::new(tick_spacing(FeeAmount::MEDIUM), false);
-        let min_tick = i32_div_no_round(MIN_TICK(), tick_spacing) * tick_spacing;
-        let max_tick = i32_div_no_round(MAX_TICK(), tick_spacing) * tick_spacing;
-        (min_tick, max_tick)
+    fn mint_position(
+        yas_nft_position_manager: IYASNFTPositionManagerDispatcher,
+        token_0: IERC20Dispatcher,
+        token_1: IERC20Dispatcher,
+        recipient: ContractAddress
+    ) -> u256 {
+        yas_nft_position_manager
+            .create_and_initialize_pool_if_necessary(
+                token_0.contract_address,
+                token_1.contract_address,
+                fee_amount(FeeAmount::MEDIUM),
+                encode_price_sqrt_1_1()
+            );
+
+        let (min_tick, max_tick) = get_min_tick_and_max_tick();
+        let (token_id, _, _, _) = yas_nft_position_manager
+            .mint(
+                MintParams {
+                    token_0: token_0.contract_address,
+                    token_1: token_1.contract_address,
+                    fee: fee_amount(FeeAmount::MEDIUM),
+                    recipient,
+                    tick_lower: min_tick,
+                    tick_upper: max_tick,
+                    amount_0_desired: 100,
+                    amount_1_desired: 100,
+                    amount_0_min: 0,
+                    amount_1_min: 0,
+                    deadline: 1
+                }
+            );
+        token_id
     }
 
     mod Mint {
         use super::{setup, get_min_tick_and_max_tick};
-        use starknet::testing::{set_contract_address};
+        use starknet::testing::{set_contract_address, set_block_timestamp};
 
         use yas_core::contracts::yas_erc20::{
             ERC20, ERC20::ERC20Impl, IERC20Dispatcher, IERC20DispatcherTrait
@@ -225,7 +217,10 @@ mod YASNFTPositionManagerTests {
                 );
 
             assert(yas_nft_position_manager.balance_of(OTHER()) == 1, 'wrong balance_of OTHER');
-            // assert(yas_nft_position_manager.token_of_owner_by_index(other.address, 0) == 1, ''); // TODO: ERC721Enumerable
+            assert(
+                yas_nft_position_manager.token_of_owner_by_index(OTHER(), 0) == 1,
+                'wrong token_of_owner_by_index'
+            );
 
             let (position, pool_key) = yas_nft_position_manager.positions(1);
             assert(pool_key.token_0 == token_0.contract_address, 'wrong token_0');
@@ -239,5 +234,979 @@ mod YASNFTPositionManagerTests {
             assert(position.fee_growth_inside_0_last_X128 == 0, 'wrong fee_growth_inside_0');
             assert(position.fee_growth_inside_1_last_X128 == 0, 'wrong fee_growth_inside_1');
         }
+
+        #[test]
+        #[available_gas(200000000)]
+        #[should_panic(expected: ('Transaction too old', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_past_deadline() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+
+            yas_nft_position_manager
+                .create_and_initialize_pool_if_necessary(
+                    token_0.contract_address,
+                    token_1.contract_address,
+                    fee_amount(FeeAmount::MEDIUM),
+                    encode_price_sqrt_1_1()
+                );
+
+            set_block_timestamp(2);
+            let (min_tick, max_tick) = get_min_tick_and_max_tick();
+            yas_nft_position_manager
+                .mint(
+                    MintParams {
+                        token_0: token_0.contract_address,
+                        token_1: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        recipient: OTHER(),
+                        tick_lower: min_tick,
+                        tick_upper: max_tick,
+                        amount_0_desired: 15,
+                        amount_1_desired: 15,
+                        amount_0_min: 0,
+                        amount_1_min: 0,
+                        deadline: 1
+                    }
+                );
+        }
+    }
+
+    mod IncreaseLiquidity {
+        use super::{setup, mint_position};
+        use starknet::testing::set_block_timestamp;
+
+        use yas_core::tests::utils::constants::PoolConstants::OTHER;
+
+        use yas_periphery::yas_nft_position_manager::{
+            IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait,
+            IncreaseLiquidityParams
+        };
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_increases_position_liquidity() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            yas_nft_position_manager
+                .increase_liquidity(
+                    IncreaseLiquidityParams {
+                        token_id,
+                        amount_0_desired: 100,
+                        amount_1_desired: 100,
+                        amount_0_min: 0,
+                        amount_1_min: 0,
+                        deadline: 1
+                    }
+                );
+
+            let (position, _) = yas_nft_position_manager.positions(token_id);
+            assert(position.liquidity == 200, 'wrong liquidity');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Transaction too old', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_past_deadline() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_block_timestamp(2);
+            yas_nft_position_manager
+                .increase_liquidity(
+                    IncreaseLiquidityParams {
+                        token_id,
+                        amount_0_desired: 100,
+                        amount_1_desired: 100,
+                        amount_0_min: 0,
+                        amount_1_min: 0,
+                        deadline: 1
+                    }
+                );
+        }
+    }
+
+    mod DecreaseLiquidity {
+        use super::{setup, mint_position};
+        use starknet::testing::{set_contract_address, set_block_timestamp};
+
+        use yas_core::tests::utils::constants::PoolConstants::{OWNER, WALLET, OTHER};
+
+        use yas_periphery::yas_nft_position_manager::{
+            IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait,
+            DecreaseLiquidityParams
+        };
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not approved', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_not_approved() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            // WALLET() is neither the owner nor an approved operator
+            set_contract_address(WALLET());
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 50, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not approved', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_operator_was_approved_by_previous_owner() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager.approve(WALLET(), token_id);
+            yas_nft_position_manager.transfer_from(OTHER(), OWNER(), token_id);
+
+            set_contract_address(WALLET());
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 50, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_transfer_clears_the_operator() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager.approve(WALLET(), token_id);
+            yas_nft_position_manager.transfer_from(OTHER(), OWNER(), token_id);
+
+            assert(
+                yas_nft_position_manager.get_approved(token_id).is_zero(), 'operator not cleared'
+            );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Transaction too old', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_past_deadline() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            set_block_timestamp(2);
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 50, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_decreases_position_liquidity() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 25, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+
+            let (position, _) = yas_nft_position_manager.positions(token_id);
+            assert(position.liquidity == 75, 'wrong liquidity');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_increases_position_tokens_owed() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            let (amount_0, amount_1) = yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 25, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+
+            let (position, _) = yas_nft_position_manager.positions(token_id);
+            assert(amount_0 > 0 && amount_1 > 0, 'wrong amounts');
+            assert(position.tokens_owed_0.into() == amount_0, 'wrong tokens_owed_0');
+            assert(position.tokens_owed_1.into() == amount_1, 'wrong tokens_owed_1');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_can_decrease_all_the_liquidity() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 100, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+
+            let (position, _) = yas_nft_position_manager.positions(token_id);
+            assert(position.liquidity == 0, 'wrong liquidity');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not enough liquidity', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_decreasing_more_than_all_the_liquidity() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 101, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Price slippage check', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_amounts_are_below_minimum() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 25, amount_0_min: 100, amount_1_min: 0, deadline: 1
+                    }
+                );
+        }
+    }
+
+    mod Collect {
+        use super::{setup, mint_position};
+        use starknet::testing::set_contract_address;
+        use integer::BoundedInt;
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        use yas_periphery::yas_nft_position_manager::{
+            IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait,
+            DecreaseLiquidityParams
+        };
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not approved', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_not_approved() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(WALLET());
+            yas_nft_position_manager
+                .collect(token_id, WALLET(), BoundedInt::max(), BoundedInt::max());
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Invalid amounts to collect', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_both_amounts_are_zero() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager.collect(token_id, OTHER(), 0, 0);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_transfers_tokens_owed_from_burn() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            let (amount_0, amount_1) = yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 50, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+
+            let (amount_0_collected, amount_1_collected) = yas_nft_position_manager
+                .collect(token_id, OTHER(), BoundedInt::max(), BoundedInt::max());
+
+            assert(amount_0_collected == amount_0, 'wrong amount_0 collected');
+            assert(amount_1_collected == amount_1, 'wrong amount_1 collected');
+            assert(token_0.balanceOf(OTHER()) == amount_0, 'wrong token_0 balance');
+            assert(token_1.balanceOf(OTHER()) == amount_1, 'wrong token_1 balance');
+
+            let (position, _) = yas_nft_position_manager.positions(token_id);
+            assert(position.tokens_owed_0 == 0, 'wrong tokens_owed_0');
+            assert(position.tokens_owed_1 == 0, 'wrong tokens_owed_1');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_collects_up_to_the_maximum_amounts() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            let (amount_0, amount_1) = yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 50, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+
+            let (amount_0_collected, amount_1_collected) = yas_nft_position_manager
+                .collect(token_id, OTHER(), 1, 0);
+
+            assert(amount_0_collected == 1, 'wrong amount_0 collected');
+            assert(amount_1_collected == 0, 'wrong amount_1 collected');
+
+            let (position, _) = yas_nft_position_manager.positions(token_id);
+            assert(position.tokens_owed_0.into() == amount_0 - 1, 'wrong tokens_owed_0');
+            assert(position.tokens_owed_1.into() == amount_1, 'wrong tokens_owed_1');
+        }
+    }
+
+    mod Burn {
+        use super::{setup, mint_position};
+        use starknet::testing::set_contract_address;
+        use integer::BoundedInt;
+
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        use yas_periphery::yas_nft_position_manager::{
+            IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait,
+            DecreaseLiquidityParams
+        };
+
+        fn clear_position(
+            yas_nft_position_manager: IYASNFTPositionManagerDispatcher, token_id: u256
+        ) {
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 100, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+            yas_nft_position_manager
+                .collect(token_id, OTHER(), BoundedInt::max(), BoundedInt::max());
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not approved', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_not_approved() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            clear_position(yas_nft_position_manager, token_id);
+
+            set_contract_address(WALLET());
+            yas_nft_position_manager.burn(token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not cleared', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_there_is_still_liquidity() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager.burn(token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not cleared', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_there_are_tokens_owed() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 100, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+            yas_nft_position_manager.burn(token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_deletes_the_token() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            clear_position(yas_nft_position_manager, token_id);
+            yas_nft_position_manager.burn(token_id);
+
+            assert(yas_nft_position_manager.balance_of(OTHER()) == 0, 'wrong balance_of OTHER');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Invalid token ID', 'ENTRYPOINT_FAILED'))]
+        fn test_deletes_the_position() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            clear_position(yas_nft_position_manager, token_id);
+            yas_nft_position_manager.burn(token_id);
+
+            yas_nft_position_manager.positions(token_id);
+        }
+    }
+
+    mod Enumerable {
+        use super::{setup, mint_position};
+        use starknet::testing::set_contract_address;
+        use integer::BoundedInt;
+
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        use yas_periphery::yas_nft_position_manager::{
+            IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait,
+            DecreaseLiquidityParams
+        };
+
+        fn burn_position(
+            yas_nft_position_manager: IYASNFTPositionManagerDispatcher, token_id: u256
+        ) {
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 100, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+            yas_nft_position_manager
+                .collect(token_id, OTHER(), BoundedInt::max(), BoundedInt::max());
+            yas_nft_position_manager.burn(token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_enumerates_minted_tokens() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id_1 = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+            let token_id_2 = mint_position(yas_nft_position_manager, token_0, token_1, WALLET());
+            let token_id_3 = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            assert(yas_nft_position_manager.total_supply() == 3, 'wrong total_supply');
+            assert(yas_nft_position_manager.token_by_index(0) == token_id_1, 'wrong token 0');
+            assert(yas_nft_position_manager.token_by_index(1) == token_id_2, 'wrong token 1');
+            assert(yas_nft_position_manager.token_by_index(2) == token_id_3, 'wrong token 2');
+
+            assert(
+                yas_nft_position_manager.token_of_owner_by_index(OTHER(), 0) == token_id_1,
+                'wrong OTHER token 0'
+            );
+            assert(
+                yas_nft_position_manager.token_of_owner_by_index(OTHER(), 1) == token_id_3,
+                'wrong OTHER token 1'
+            );
+            assert(
+                yas_nft_position_manager.token_of_owner_by_index(WALLET(), 0) == token_id_2,
+                'wrong WALLET token 0'
+            );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('ERC721: index out of bounds', 'ENTRYPOINT_FAILED'))]
+        fn test_token_by_index_fails_if_out_of_bounds() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            yas_nft_position_manager.token_by_index(1);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('ERC721: index out of bounds', 'ENTRYPOINT_FAILED'))]
+        fn test_token_of_owner_by_index_fails_if_out_of_bounds() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            yas_nft_position_manager.token_of_owner_by_index(WALLET(), 0);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_updates_owner_tokens_on_transfer() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id_1 = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+            let token_id_2 = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager.transfer_from(OTHER(), WALLET(), token_id_1);
+
+            assert(yas_nft_position_manager.total_supply() == 2, 'wrong total_supply');
+            assert(
+                yas_nft_position_manager.token_of_owner_by_index(OTHER(), 0) == token_id_2,
+                'wrong OTHER token 0'
+            );
+            assert(
+                yas_nft_position_manager.token_of_owner_by_index(WALLET(), 0) == token_id_1,
+                'wrong WALLET token 0'
+            );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_approved_address_can_transfer() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager.approve(WALLET(), token_id);
+            set_contract_address(WALLET());
+            yas_nft_position_manager.transfer_from(OTHER(), WALLET(), token_id);
+
+            assert(yas_nft_position_manager.owner_of(token_id) == WALLET(), 'wrong owner');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('ERC721: unauthorized caller', 'ENTRYPOINT_FAILED'))]
+        fn test_transfer_fails_if_not_approved() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(WALLET());
+            yas_nft_position_manager.transfer_from(OTHER(), WALLET(), token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_removes_burned_tokens() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id_1 = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+            let token_id_2 = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            burn_position(yas_nft_position_manager, token_id_1);
+
+            assert(yas_nft_position_manager.total_supply() == 1, 'wrong total_supply');
+            assert(yas_nft_position_manager.token_by_index(0) == token_id_2, 'wrong token 0');
+            assert(
+                yas_nft_position_manager.token_of_owner_by_index(OTHER(), 0) == token_id_2,
+                'wrong OTHER token 0'
+            );
+            assert(yas_nft_position_manager.balance_of(OTHER()) == 1, 'wrong balance_of OTHER');
+        }
+    }
+
+    mod TokenURI {
+        use super::{setup, mint_position};
+
+        use yas_core::tests::utils::constants::PoolConstants::OTHER;
+
+        use yas_periphery::yas_nft_position_manager::{
+            IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait
+        };
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('ERC721: invalid token ID', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_token_does_not_exist() {
+            let (yas_nft_position_manager, _, _) = setup();
+            yas_nft_position_manager.token_uri(1);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_returns_a_json_data_uri() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            let uri = yas_nft_position_manager.token_uri(token_id);
+            assert(*uri.at(0) == 'data:application/json;utf8,', 'wrong uri scheme');
+            assert(*uri.at(1) == '{"name":"YAS - ', 'wrong name prefix');
+            // 0.3% - -887220<>887220
+            assert(*uri.at(2) == '0', 'wrong fee integer part');
+            assert(*uri.at(4) == '3', 'wrong fee decimals');
+            assert(*uri.at(7) == '-', 'wrong tick_lower sign');
+            assert(*uri.at(8) == '887220', 'wrong tick_lower');
+            assert(*uri.at(10) == '887220', 'wrong tick_upper');
+            assert(*uri.at(uri.len() - 2) == '100', 'wrong liquidity');
+            assert(*uri.at(uri.len() - 1) == '"}', 'wrong uri end');
+        }
+    }
+
+    mod Multicall {
+        use super::{setup, mint_position, get_min_tick_and_max_tick};
+        use starknet::ContractAddress;
+        use starknet::testing::set_contract_address;
+        use integer::BoundedInt;
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::tests::utils::constants::FactoryConstants::{FeeAmount, fee_amount};
+        use yas_core::tests::utils::constants::PoolConstants::{
+            WALLET, OTHER, encode_price_sqrt_1_1
+        };
+
+        use yas_periphery::yas_nft_position_manager::{
+            YASNFTPositionManager::Selectors, IYASNFTPositionManagerDispatcher,
+            IYASNFTPositionManagerDispatcherTrait, DecreaseLiquidityParams, MintParams
+        };
+
+        fn decrease_liquidity_call(token_id: u256, liquidity: u128) -> Span<felt252> {
+            let mut call = array![Selectors::DECREASE_LIQUIDITY];
+            Serde::serialize(
+                @DecreaseLiquidityParams {
+                    token_id, liquidity, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                },
+                ref call
+            );
+            call.span()
+        }
+
+        fn collect_call(token_id: u256, recipient: ContractAddress) -> Span<felt252> {
+            let mut call = array![Selectors::COLLECT];
+            Serde::serialize(@token_id, ref call);
+            Serde::serialize(@recipient, ref call);
+            Serde::serialize(@BoundedInt::<u128>::max(), ref call);
+            Serde::serialize(@BoundedInt::<u128>::max(), ref call);
+            call.span()
+        }
+
+        fn burn_call(token_id: u256) -> Span<felt252> {
+            let mut call = array![Selectors::BURN];
+            Serde::serialize(@token_id, ref call);
+            call.span()
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_creates_a_pool_and_mints_a_position() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+
+            let mut create_pool_call = array![Selectors::CREATE_AND_INITIALIZE_POOL_IF_NECESSARY];
+            Serde::serialize(@token_0.contract_address, ref create_pool_call);
+            Serde::serialize(@token_1.contract_address, ref create_pool_call);
+            Serde::serialize(@fee_amount(FeeAmount::MEDIUM), ref create_pool_call);
+            Serde::serialize(@encode_price_sqrt_1_1(), ref create_pool_call);
+
+            let (min_tick, max_tick) = get_min_tick_and_max_tick();
+            let mut mint_call = array![Selectors::MINT];
+            Serde::serialize(
+                @MintParams {
+                    token_0: token_0.contract_address,
+                    token_1: token_1.contract_address,
+                    fee: fee_amount(FeeAmount::MEDIUM),
+                    recipient: OTHER(),
+                    tick_lower: min_tick,
+                    tick_upper: max_tick,
+                    amount_0_desired: 100,
+                    amount_1_desired: 100,
+                    amount_0_min: 0,
+                    amount_1_min: 0,
+                    deadline: 1
+                },
+                ref mint_call
+            );
+
+            let results = yas_nft_position_manager
+                .multicall(array![create_pool_call.span(), mint_call.span()]);
+            assert(results.len() == 2, 'wrong results len');
+
+            let mut create_pool_result = *results[0];
+            let pool = Serde::<ContractAddress>::deserialize(ref create_pool_result).unwrap();
+            assert(pool.is_non_zero(), 'pool should be created');
+
+            let mut mint_result = *results[1];
+            let token_id = Serde::<u256>::deserialize(ref mint_result).unwrap();
+            let liquidity = Serde::<u128>::deserialize(ref mint_result).unwrap();
+            let amount_0 = Serde::<u256>::deserialize(ref mint_result).unwrap();
+            let amount_1 = Serde::<u256>::deserialize(ref mint_result).unwrap();
+            assert(token_id == 1, 'wrong token_id');
+            assert(liquidity == 100, 'wrong liquidity');
+            assert(token_0.balanceOf(pool) == amount_0, 'wrong pool token_0 balance');
+            assert(token_1.balanceOf(pool) == amount_1, 'wrong pool token_1 balance');
+            assert(yas_nft_position_manager.owner_of(token_id) == OTHER(), 'wrong owner');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_decreases_collects_and_burns_a_position() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            let results = yas_nft_position_manager
+                .multicall(
+                    array![
+                        decrease_liquidity_call(token_id, 100),
+                        collect_call(token_id, WALLET()),
+                        burn_call(token_id)
+                    ]
+                );
+            assert(results.len() == 3, 'wrong results len');
+            assert((*results[2]).is_empty(), 'burn should return nothing');
+
+            let mut collect_result = *results[1];
+            let amount_0 = Serde::<u256>::deserialize(ref collect_result).unwrap();
+            let amount_1 = Serde::<u256>::deserialize(ref collect_result).unwrap();
+            assert(amount_0 > 0 && amount_1 > 0, 'amounts should be collected');
+            assert(yas_nft_position_manager.balance_of(OTHER()) == 0, 'wrong balance_of OTHER');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not cleared', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_any_call_fails() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager
+                .multicall(array![decrease_liquidity_call(token_id, 100), burn_call(token_id)]);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not approved', 'ENTRYPOINT_FAILED'))]
+        fn test_keeps_the_caller_of_multicall() {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, OTHER());
+
+            // WALLET is neither the owner nor an operator of the position
+            set_contract_address(WALLET());
+            yas_nft_position_manager.multicall(array![decrease_liquidity_call(token_id, 100)]);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('unsupported selector', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_selector_is_not_supported() {
+            let (yas_nft_position_manager, _, _) = setup();
+
+            yas_nft_position_manager.multicall(array![array!['transfer_from'].span()]);
+        }
+    }
+
+    mod Permit {
+        use super::{setup, mint_position, deploy_mock_account};
+        use starknet::{ContractAddress, contract_address_const, get_tx_info};
+        use starknet::testing::{set_contract_address, set_block_timestamp, set_chain_id};
+
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+        use yas_core::utils::typed_data::TypedData::{
+            StarknetDomain, compute_hash_on_elements, hash_u256, hash_message
+        };
+
+        use yas_periphery::yas_nft_position_manager::{
+            YASNFTPositionManager, YASNFTPositionManager::{PERMIT_TYPE_HASH, Selectors},
+            IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait,
+            DecreaseLiquidityParams
+        };
+
+        // Mints a position owned by a MockAccount
+        fn setup_with_position() -> (IYASNFTPositionManagerDispatcher, ContractAddress, u256) {
+            let (yas_nft_position_manager, token_0, token_1) = setup();
+            let owner = deploy_mock_account();
+
+            set_contract_address(WALLET());
+            let token_id = mint_position(yas_nft_position_manager, token_0, token_1, owner);
+            (yas_nft_position_manager, owner, token_id)
+        }
+
+        // Returns the signature a MockAccount accepts for the permit, i.e. its message hash
+        fn sign_permit(
+            yas_nft_position_manager: IYASNFTPositionManagerDispatcher,
+            owner: ContractAddress,
+            spender: ContractAddress,
+            token_id: u256,
+            deadline: u256
+        ) -> Array<felt252> {
+            let domain = StarknetDomain {
+                name: yas_nft_position_manager.name(),
+                version: '1',
+                chain_id: get_tx_info().unbox().chain_id
+            };
+            let struct_hash = compute_hash_on_elements(
+                array![
+                    PERMIT_TYPE_HASH,
+                    spender.into(),
+                    hash_u256(token_id),
+                    yas_nft_position_manager.nonces(token_id),
+                    hash_u256(deadline),
+                    yas_nft_position_manager.contract_address.into()
+                ]
+                    .span()
+            );
+            array![hash_message(domain, owner, struct_hash)]
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_approves_the_spender() {
+            let (yas_nft_position_manager, owner, token_id) = setup_with_position();
+            let signature = sign_permit(yas_nft_position_manager, owner, OTHER(), token_id, 1);
+
+            // anyone can submit the signature
+            yas_nft_position_manager.permit(OTHER(), token_id, 1, signature);
+
+            assert(yas_nft_position_manager.get_approved(token_id) == OTHER(), 'wrong approved');
+            assert(yas_nft_position_manager.nonces(token_id) == 1, 'wrong nonce');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_spender_can_manage_the_position() {
+            let (yas_nft_position_manager, owner, token_id) = setup_with_position();
+            let signature = sign_permit(yas_nft_position_manager, owner, OTHER(), token_id, 1);
+            yas_nft_position_manager.permit(OTHER(), token_id, 1, signature);
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager
+                .decrease_liquidity(
+                    DecreaseLiquidityParams {
+                        token_id, liquidity: 50, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                    }
+                );
+
+            let (position, _) = yas_nft_position_manager.positions(token_id);
+            assert(position.liquidity == 50, 'wrong liquidity');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_spender_can_transfer_the_position() {
+            let (yas_nft_position_manager, owner, token_id) = setup_with_position();
+            let signature = sign_permit(yas_nft_position_manager, owner, OTHER(), token_id, 1);
+            yas_nft_position_manager.permit(OTHER(), token_id, 1, signature);
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager.transfer_from(owner, WALLET(), token_id);
+
+            assert(yas_nft_position_manager.owner_of(token_id) == WALLET(), 'wrong owner');
+            assert(
+                yas_nft_position_manager.get_approved(token_id).is_zero(), 'approval not cleared'
+            );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_spender_can_permit_and_manage_in_a_multicall() {
+            let (yas_nft_position_manager, owner, token_id) = setup_with_position();
+
+            let mut permit_call = array![Selectors::PERMIT];
+            Serde::serialize(@OTHER(), ref permit_call);
+            Serde::serialize(@token_id, ref permit_call);
+            Serde::serialize(@1_u256, ref permit_call);
+            Serde::serialize(
+                @sign_permit(yas_nft_position_manager, owner, OTHER(), token_id, 1),
+                ref permit_call
+            );
+            let mut decrease_call = array![Selectors::DECREASE_LIQUIDITY];
+            Serde::serialize(
+                @DecreaseLiquidityParams {
+                    token_id, liquidity: 50, amount_0_min: 0, amount_1_min: 0, deadline: 1
+                },
+                ref decrease_call
+            );
+
+            set_contract_address(OTHER());
+            yas_nft_position_manager.multicall(array![permit_call.span(), decrease_call.span()]);
+
+            let (position, _) = yas_nft_position_manager.positions(token_id);
+            assert(position.liquidity == 50, 'wrong liquidity');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Invalid signature', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_with_an_invalid_signature() {
+            let (yas_nft_position_manager, _, token_id) = setup_with_position();
+
+            yas_nft_position_manager.permit(OTHER(), token_id, 1, array!['not a signature']);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Invalid signature', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_with_a_signature_for_another_spender() {
+            let (yas_nft_position_manager, owner, token_id) = setup_with_position();
+            let signature = sign_permit(yas_nft_position_manager, owner, OTHER(), token_id, 1);
+
+            yas_nft_position_manager.permit(WALLET(), token_id, 1, signature);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Invalid signature', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_the_signature_is_reused() {
+            let (yas_nft_position_manager, owner, token_id) = setup_with_position();
+            let signature = sign_permit(yas_nft_position_manager, owner, OTHER(), token_id, 1);
+            yas_nft_position_manager.permit(OTHER(), token_id, 1, signature.clone());
+
+            yas_nft_position_manager.permit(OTHER(), token_id, 1, signature);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Permit expired', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_deadline_has_passed() {
+            let (yas_nft_position_manager, owner, token_id) = setup_with_position();
+            let signature = sign_permit(yas_nft_position_manager, owner, OTHER(), token_id, 9);
+
+            set_block_timestamp(10);
+            yas_nft_position_manager.permit(OTHER(), token_id, 9, signature);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Approval to current owner', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_spender_is_the_owner() {
+            let (yas_nft_position_manager, owner, token_id) = setup_with_position();
+            let signature = sign_permit(yas_nft_position_manager, owner, owner, token_id, 1);
+
+            yas_nft_position_manager.permit(owner, token_id, 1, signature);
+        }
+
+        // Same permit as the known-answer test of deprecated_scripts/src/permit.rs
+        #[test]
+        #[available_gas(20000000)]
+        fn test_permit_message_hash_matches_the_offline_hash() {
+            set_contract_address(contract_address_const::<'NFT_POSITION_MANAGER'>());
+            set_chain_id('SN_GOERLI');
+            let mut state = YASNFTPositionManager::contract_state_for_testing();
+            YASNFTPositionManager::constructor(ref state, contract_address_const::<'FACTORY'>());
+
+            let hash = YASNFTPositionManager::InternalFunctions::permit_message_hash(
+                @state,
+                contract_address_const::<'OWNER'>(),
+                contract_address_const::<'SPENDER'>(),
+                1,
+                0,
+                1700000000
+            );
+            assert(
+                hash == 0x24c0233f8bb118013c630e0e7e797c7fe9858a683dc4a5564581a0ff6796af0,
+                'wrong message hash'
+            );
+        }
     }
 }
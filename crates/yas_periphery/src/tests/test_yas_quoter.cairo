@@ -0,0 +1,606 @@
+mod YASQuoterTests {
+    use starknet::{ContractAddress, SyscallResultTrait};
+    use starknet::syscalls::deploy_syscall;
+    use starknet::testing::set_contract_address;
+    use integer::BoundedInt;
+
+    use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+    use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
+    use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+    use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+    use yas_core::numbers::signed_integer::{i32::i32, integer_trait::IntegerTrait};
+    use yas_core::tests::utils::constants::FactoryConstants::{
+        POOL_CLASS_HASH, FeeAmount, fee_amount, OWNER
+    };
+    use yas_core::tests::utils::constants::PoolConstants::{WALLET, encode_price_sqrt_1_1};
+    use yas_core::tests::utils::deployers::Deployers::{
+        deploy_factory, deploy_yas_router, deploy_erc20, get_min_tick_and_max_tick
+    };
+
+    use yas_periphery::yas_quoter::{YASQuoter, IYASQuoterDispatcher, IYASQuoterDispatcherTrait};
+
+    const LIQUIDITY: u128 = 1000000000000000000;
+
+    fn setup() -> (
+        IYASQuoterDispatcher,
+        IYASRouterDispatcher,
+        IYASPoolDispatcher,
+        IERC20Dispatcher,
+        IERC20Dispatcher,
+        IERC20Dispatcher
+    ) {
+        let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH()); // 0x1
+        let yas_router = deploy_yas_router(yas_factory.contract_address); // 0x2
+        let yas_quoter = deploy_yas_quoter(yas_factory.contract_address); // 0x3
+
+        let token_0 = deploy_erc20('YAS0', '$YAS0', BoundedInt::max(), OWNER()); // 0x4
+        let token_1 = deploy_erc20('YAS1', '$YAS1', BoundedInt::max(), OWNER()); // 0x5
+        let token_2 = deploy_erc20('YAS2', '$YAS2', BoundedInt::max(), OWNER()); // 0x6
+
+        set_contract_address(OWNER());
+        token_0.transfer(WALLET(), BoundedInt::max());
+        token_1.transfer(WALLET(), BoundedInt::max());
+        token_2.transfer(WALLET(), BoundedInt::max());
+
+        // Give permissions to expend WALLET() tokens
+        set_contract_address(WALLET());
+        token_0.approve(yas_router.contract_address, BoundedInt::max());
+        token_1.approve(yas_router.contract_address, BoundedInt::max());
+        token_2.approve(yas_router.contract_address, BoundedInt::max());
+
+        let pool_0_1 = create_pool(yas_factory, token_0, token_1); // 0x7
+        let pool_1_2 = create_pool(yas_factory, token_1, token_2); // 0x8
+
+        let (min_tick, max_tick) = get_min_tick_and_max_tick();
+        yas_router.mint(pool_0_1.contract_address, WALLET(), min_tick, max_tick, LIQUIDITY);
+        yas_router.mint(pool_1_2.contract_address, WALLET(), min_tick, max_tick, LIQUIDITY);
+
+        (yas_quoter, yas_router, pool_0_1, token_0, token_1, token_2)
+    }
+
+    fn create_pool(
+        yas_factory: IYASFactoryDispatcher, token_a: IERC20Dispatcher, token_b: IERC20Dispatcher
+    ) -> IYASPoolDispatcher {
+        let yas_pool_address = yas_factory
+            .create_pool(
+                token_a.contract_address, token_b.contract_address, fee_amount(FeeAmount::MEDIUM)
+            );
+        let yas_pool = IYASPoolDispatcher { contract_address: yas_pool_address };
+        yas_pool.initialize(encode_price_sqrt_1_1());
+        yas_pool
+    }
+
+    fn encode_path(tokens: Span<IERC20Dispatcher>) -> Array<felt252> {
+        let mut path = array![];
+        let mut i = 0;
+        loop {
+            if i == tokens.len() {
+                break;
+            }
+            if i != 0 {
+                path.append(fee_amount(FeeAmount::MEDIUM).into());
+            }
+            path.append((*tokens[i]).contract_address.into());
+            i += 1;
+        };
+        path
+    }
+
+    fn deploy_yas_quoter(factory: ContractAddress) -> IYASQuoterDispatcher {
+        let (address, _) = deploy_syscall(
+            YASQuoter::TEST_CLASS_HASH.try_into().unwrap(), 0, array![factory.into()].span(), true
+        )
+            .unwrap_syscall();
+
+        return IYASQuoterDispatcher { contract_address: address };
+    }
+
+    // Mints a position in [-60, 60], which initializes both ticks
+    fn mint_in_range(yas_router: IYASRouterDispatcher, yas_pool: IYASPoolDispatcher) {
+        yas_router
+            .mint(
+                yas_pool.contract_address,
+                WALLET(),
+                IntegerTrait::<i32>::new(60, true),
+                IntegerTrait::<i32>::new(60, false),
+                LIQUIDITY
+            );
+    }
+
+    // Quotes are compared against real swaps executed on a freshly deployed copy of the same setup
+    mod QuoteExactInputSingle {
+        use super::{setup, mint_in_range, LIQUIDITY};
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+        use yas_core::contracts::yas_router::{
+            IYASRouterDispatcher, IYASRouterDispatcherTrait, ExactInputSingleParams
+        };
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::{
+            FP64x96PartialEq, FP64x96Zeroable
+        };
+        use yas_core::tests::utils::constants::FactoryConstants::{FeeAmount, fee_amount};
+        use yas_core::tests::utils::constants::PoolConstants::OTHER;
+
+        use yas_periphery::yas_quoter::{IYASQuoterDispatcherTrait, QuoteExactInputSingleParams};
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_0_to_1() {
+            let (yas_quoter, _, _, token_0, token_1, _) = setup();
+            let (amount_out, sqrt_price_X96_after, initialized_ticks_crossed) = yas_quoter
+                .quote_exact_input_single(
+                    QuoteExactInputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount_in: 10000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            let (_, yas_router, yas_pool, token_0, token_1, _) = setup();
+            let swapped_amount_out = yas_router
+                .exact_input_single(
+                    ExactInputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        recipient: OTHER(),
+                        deadline: 1,
+                        amount_in: 10000,
+                        amount_out_minimum: 0,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            assert(amount_out == swapped_amount_out, 'wrong amount_out');
+            assert(
+                sqrt_price_X96_after == yas_pool.get_slot_0().sqrt_price_X96,
+                'wrong sqrt_price_X96_after'
+            );
+            assert(initialized_ticks_crossed == 0, 'wrong ticks crossed');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_1_to_0() {
+            let (yas_quoter, _, _, token_0, token_1, _) = setup();
+            let (amount_out, sqrt_price_X96_after, initialized_ticks_crossed) = yas_quoter
+                .quote_exact_input_single(
+                    QuoteExactInputSingleParams {
+                        token_in: token_1.contract_address,
+                        token_out: token_0.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount_in: 10000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            let (_, yas_router, yas_pool, token_0, token_1, _) = setup();
+            let swapped_amount_out = yas_router
+                .exact_input_single(
+                    ExactInputSingleParams {
+                        token_in: token_1.contract_address,
+                        token_out: token_0.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        recipient: OTHER(),
+                        deadline: 1,
+                        amount_in: 10000,
+                        amount_out_minimum: 0,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            assert(amount_out == swapped_amount_out, 'wrong amount_out');
+            assert(
+                sqrt_price_X96_after == yas_pool.get_slot_0().sqrt_price_X96,
+                'wrong sqrt_price_X96_after'
+            );
+            assert(initialized_ticks_crossed == 0, 'wrong ticks crossed');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_counts_initialized_ticks_crossed() {
+            let (yas_quoter, yas_router, yas_pool, token_0, token_1, _) = setup();
+
+            // swapping down only crosses -60
+            mint_in_range(yas_router, yas_pool);
+
+            let (_, _, initialized_ticks_crossed) = yas_quoter
+                .quote_exact_input_single(
+                    QuoteExactInputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount_in: 10000000000000000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            assert(initialized_ticks_crossed == 1, 'wrong ticks crossed');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_matches_a_swap_crossing_ticks() {
+            let (yas_quoter, yas_router, yas_pool, token_0, token_1, _) = setup();
+            mint_in_range(yas_router, yas_pool);
+            let (amount_out, sqrt_price_X96_after, initialized_ticks_crossed) = yas_quoter
+                .quote_exact_input_single(
+                    QuoteExactInputSingleParams {
+                        token_in: token_1.contract_address,
+                        token_out: token_0.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount_in: 10000000000000000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            let (_, yas_router, yas_pool, token_0, token_1, _) = setup();
+            mint_in_range(yas_router, yas_pool);
+            let swapped_amount_out = yas_router
+                .exact_input_single(
+                    ExactInputSingleParams {
+                        token_in: token_1.contract_address,
+                        token_out: token_0.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        recipient: OTHER(),
+                        deadline: 1,
+                        amount_in: 10000000000000000,
+                        amount_out_minimum: 0,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            assert(amount_out == swapped_amount_out, 'wrong amount_out');
+            assert(
+                sqrt_price_X96_after == yas_pool.get_slot_0().sqrt_price_X96,
+                'wrong sqrt_price_X96_after'
+            );
+            // swapping up only crosses 60
+            assert(initialized_ticks_crossed == 1, 'wrong ticks crossed');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_matches_a_swap_crossing_negative_ticks() {
+            let (yas_quoter, yas_router, yas_pool, token_0, token_1, _) = setup();
+            mint_in_range(yas_router, yas_pool);
+            let (amount_out, sqrt_price_X96_after, initialized_ticks_crossed) = yas_quoter
+                .quote_exact_input_single(
+                    QuoteExactInputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount_in: 10000000000000000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            let (_, yas_router, yas_pool, token_0, token_1, _) = setup();
+            mint_in_range(yas_router, yas_pool);
+            let swapped_amount_out = yas_router
+                .exact_input_single(
+                    ExactInputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        recipient: OTHER(),
+                        deadline: 1,
+                        amount_in: 10000000000000000,
+                        amount_out_minimum: 0,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            assert(amount_out == swapped_amount_out, 'wrong amount_out');
+            assert(
+                sqrt_price_X96_after == yas_pool.get_slot_0().sqrt_price_X96,
+                'wrong sqrt_price_X96_after'
+            );
+            // swapping down only crosses -60
+            assert(initialized_ticks_crossed == 1, 'wrong ticks crossed');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_does_not_change_the_pool() {
+            let (yas_quoter, _, yas_pool, token_0, token_1, _) = setup();
+            let slot_0_before = yas_pool.get_slot_0();
+
+            yas_quoter
+                .quote_exact_input_single(
+                    QuoteExactInputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount_in: 10000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            let slot_0_after = yas_pool.get_slot_0();
+            assert(slot_0_after.sqrt_price_X96 == slot_0_before.sqrt_price_X96, 'price changed');
+            assert(slot_0_after.tick == slot_0_before.tick, 'tick changed');
+            assert(yas_pool.get_liquidity() == LIQUIDITY, 'liquidity changed');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('AS', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_amount_is_zero() {
+            let (yas_quoter, _, _, token_0, token_1, _) = setup();
+
+            yas_quoter
+                .quote_exact_input_single(
+                    QuoteExactInputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount_in: 0,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+        }
+    }
+
+    mod QuoteExactOutputSingle {
+        use super::{setup, mint_in_range};
+
+        use integer::BoundedInt;
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+        use yas_core::contracts::yas_router::{
+            IYASRouterDispatcher, IYASRouterDispatcherTrait, ExactOutputSingleParams
+        };
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::{
+            FP64x96PartialEq, FP64x96Zeroable
+        };
+        use yas_core::tests::utils::constants::FactoryConstants::{FeeAmount, fee_amount};
+        use yas_core::tests::utils::constants::PoolConstants::OTHER;
+
+        use yas_periphery::yas_quoter::{IYASQuoterDispatcherTrait, QuoteExactOutputSingleParams};
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_0_to_1() {
+            let (yas_quoter, _, _, token_0, token_1, _) = setup();
+            let (amount_in, sqrt_price_X96_after, initialized_ticks_crossed) = yas_quoter
+                .quote_exact_output_single(
+                    QuoteExactOutputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount: 10000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            let (_, yas_router, yas_pool, token_0, token_1, _) = setup();
+            let swapped_amount_in = yas_router
+                .exact_output_single(
+                    ExactOutputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        recipient: OTHER(),
+                        deadline: 1,
+                        amount_out: 10000,
+                        amount_in_maximum: 20000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            assert(amount_in == swapped_amount_in, 'wrong amount_in');
+            assert(
+                sqrt_price_X96_after == yas_pool.get_slot_0().sqrt_price_X96,
+                'wrong sqrt_price_X96_after'
+            );
+            assert(initialized_ticks_crossed == 0, 'wrong ticks crossed');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_1_to_0() {
+            let (yas_quoter, _, _, token_0, token_1, _) = setup();
+            let (amount_in, sqrt_price_X96_after, initialized_ticks_crossed) = yas_quoter
+                .quote_exact_output_single(
+                    QuoteExactOutputSingleParams {
+                        token_in: token_1.contract_address,
+                        token_out: token_0.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount: 10000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            let (_, yas_router, yas_pool, token_0, token_1, _) = setup();
+            let swapped_amount_in = yas_router
+                .exact_output_single(
+                    ExactOutputSingleParams {
+                        token_in: token_1.contract_address,
+                        token_out: token_0.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        recipient: OTHER(),
+                        deadline: 1,
+                        amount_out: 10000,
+                        amount_in_maximum: 20000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            assert(amount_in == swapped_amount_in, 'wrong amount_in');
+            assert(
+                sqrt_price_X96_after == yas_pool.get_slot_0().sqrt_price_X96,
+                'wrong sqrt_price_X96_after'
+            );
+            assert(initialized_ticks_crossed == 0, 'wrong ticks crossed');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_0_to_1_crossing_ticks() {
+            let (yas_quoter, yas_router, yas_pool, token_0, token_1, _) = setup();
+            mint_in_range(yas_router, yas_pool);
+            let (amount_in, sqrt_price_X96_after, initialized_ticks_crossed) = yas_quoter
+                .quote_exact_output_single(
+                    QuoteExactOutputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount: 10000000000000000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            let (_, yas_router, yas_pool, token_0, token_1, _) = setup();
+            mint_in_range(yas_router, yas_pool);
+            let swapped_amount_in = yas_router
+                .exact_output_single(
+                    ExactOutputSingleParams {
+                        token_in: token_0.contract_address,
+                        token_out: token_1.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        recipient: OTHER(),
+                        deadline: 1,
+                        amount_out: 10000000000000000,
+                        amount_in_maximum: BoundedInt::max(),
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            assert(amount_in == swapped_amount_in, 'wrong amount_in');
+            assert(
+                sqrt_price_X96_after == yas_pool.get_slot_0().sqrt_price_X96,
+                'wrong sqrt_price_X96_after'
+            );
+            // swapping down only crosses -60
+            assert(initialized_ticks_crossed == 1, 'wrong ticks crossed');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_1_to_0_crossing_ticks() {
+            let (yas_quoter, yas_router, yas_pool, token_0, token_1, _) = setup();
+            mint_in_range(yas_router, yas_pool);
+            let (amount_in, sqrt_price_X96_after, initialized_ticks_crossed) = yas_quoter
+                .quote_exact_output_single(
+                    QuoteExactOutputSingleParams {
+                        token_in: token_1.contract_address,
+                        token_out: token_0.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        amount: 10000000000000000,
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            let (_, yas_router, yas_pool, token_0, token_1, _) = setup();
+            mint_in_range(yas_router, yas_pool);
+            let swapped_amount_in = yas_router
+                .exact_output_single(
+                    ExactOutputSingleParams {
+                        token_in: token_1.contract_address,
+                        token_out: token_0.contract_address,
+                        fee: fee_amount(FeeAmount::MEDIUM),
+                        recipient: OTHER(),
+                        deadline: 1,
+                        amount_out: 10000000000000000,
+                        amount_in_maximum: BoundedInt::max(),
+                        sqrt_price_limit_X96: Zeroable::zero()
+                    }
+                );
+
+            assert(amount_in == swapped_amount_in, 'wrong amount_in');
+            assert(
+                sqrt_price_X96_after == yas_pool.get_slot_0().sqrt_price_X96,
+                'wrong sqrt_price_X96_after'
+            );
+            // swapping up only crosses 60
+            assert(initialized_ticks_crossed == 1, 'wrong ticks crossed');
+        }
+    }
+
+    mod QuoteMultiplePools {
+        use super::{setup, encode_path, mint_in_range};
+
+        use integer::BoundedInt;
+
+        use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+        use yas_core::contracts::yas_router::{IYASRouterDispatcher, IYASRouterDispatcherTrait};
+        use yas_core::numbers::fixed_point::implementations::impl_64x96::FP64x96PartialEq;
+        use yas_core::tests::utils::constants::PoolConstants::OTHER;
+
+        use yas_periphery::yas_quoter::IYASQuoterDispatcherTrait;
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_quote_exact_input() {
+            let (yas_quoter, _, _, token_0, token_1, token_2) = setup();
+            let (amount_out, sqrt_price_list, ticks_crossed_list) = yas_quoter
+                .quote_exact_input(encode_path(array![token_0, token_1, token_2].span()), 10000);
+
+            let (_, yas_router, _, token_0, token_1, token_2) = setup();
+            let swapped_amount_out = yas_router
+                .exact_input(
+                    encode_path(array![token_0, token_1, token_2].span()), OTHER(), 10000, 0, 1
+                );
+
+            assert(amount_out == swapped_amount_out, 'wrong amount_out');
+            assert(sqrt_price_list.len() == 2, 'wrong sqrt price list len');
+            assert(ticks_crossed_list.len() == 2, 'wrong ticks crossed list len');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_quote_exact_output() {
+            let (yas_quoter, _, _, token_0, token_1, token_2) = setup();
+            let (amount_in, sqrt_price_list, ticks_crossed_list) = yas_quoter
+                .quote_exact_output(encode_path(array![token_2, token_1, token_0].span()), 10000);
+
+            let (_, yas_router, _, token_0, token_1, token_2) = setup();
+            let swapped_amount_in = yas_router
+                .exact_output(
+                    encode_path(array![token_2, token_1, token_0].span()), OTHER(), 10000, 30000, 1
+                );
+
+            assert(amount_in == swapped_amount_in, 'wrong amount_in');
+            assert(sqrt_price_list.len() == 2, 'wrong sqrt price list len');
+            assert(ticks_crossed_list.len() == 2, 'wrong ticks crossed list len');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_quote_exact_output_crossing_ticks() {
+            let (yas_quoter, yas_router, yas_pool, token_0, token_1, token_2) = setup();
+            mint_in_range(yas_router, yas_pool);
+            let (amount_in, sqrt_price_list, ticks_crossed_list) = yas_quoter
+                .quote_exact_output(
+                    encode_path(array![token_2, token_1, token_0].span()), 10000000000000000
+                );
+
+            let (_, yas_router, yas_pool, token_0, token_1, token_2) = setup();
+            mint_in_range(yas_router, yas_pool);
+            let swapped_amount_in = yas_router
+                .exact_output(
+                    encode_path(array![token_2, token_1, token_0].span()),
+                    OTHER(),
+                    10000000000000000,
+                    BoundedInt::max(),
+                    1
+                );
+
+            assert(amount_in == swapped_amount_in, 'wrong amount_in');
+            // the path is reversed, so the last pool quoted is the token_0/token_1 one
+            assert(
+                *sqrt_price_list[1] == yas_pool.get_slot_0().sqrt_price_X96,
+                'wrong sqrt_price_X96_after'
+            );
+            assert(*ticks_crossed_list[0] == 0, 'wrong ticks crossed');
+            // swapping down only crosses -60
+            assert(*ticks_crossed_list[1] == 1, 'wrong ticks crossed');
+        }
+    }
+}
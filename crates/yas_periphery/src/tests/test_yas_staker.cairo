@@ -0,0 +1,716 @@
+mod YASStakerTests {
+    use starknet::{ContractAddress, SyscallResultTrait};
+    use starknet::syscalls::deploy_syscall;
+    use starknet::testing::{set_contract_address, set_block_timestamp};
+    use integer::BoundedInt;
+
+    use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+    use yas_core::numbers::signed_integer::i32::i32;
+    use yas_core::tests::utils::constants::FactoryConstants::{
+        POOL_CLASS_HASH, FeeAmount, fee_amount, OWNER
+    };
+    use yas_core::tests::utils::constants::PoolConstants::{WALLET, encode_price_sqrt_1_1};
+    use yas_core::tests::utils::deployers::Deployers::{
+        deploy_factory, deploy_erc20, get_min_tick_and_max_tick
+    };
+
+    use yas_periphery::yas_nft_position_manager::{
+        YASNFTPositionManager, IYASNFTPositionManagerDispatcher,
+        IYASNFTPositionManagerDispatcherTrait, MintParams, PoolKey
+    };
+    use yas_periphery::yas_staker::{
+        YASStaker, IncentiveKey, IYASStakerDispatcher, IYASStakerDispatcherTrait
+    };
+
+    const MAX_INCENTIVE_START_LEAD_TIME: u64 = 2592000; // 30 days
+    const MAX_INCENTIVE_DURATION: u64 = 63072000; // 2 years
+    const REWARD: u256 = 1000;
+
+    // Deploys the staker and a pool at a 1:1 price with a full range position of WALLET, not
+    // deposited yet. The staker is approved for all the NFTs of WALLET and the reward tokens of
+    // OWNER, the incentive creator
+    fn setup() -> (
+        IYASStakerDispatcher, IYASNFTPositionManagerDispatcher, IERC20Dispatcher, IncentiveKey, u256
+    ) {
+        let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH());
+        let nft_position_manager = deploy_nft_position_manager(yas_factory.contract_address);
+        let yas_staker = deploy_yas_staker(
+            yas_factory.contract_address, nft_position_manager.contract_address
+        );
+
+        let token_0 = deploy_erc20('YAS0', '$YAS0', BoundedInt::max(), OWNER());
+        let token_1 = deploy_erc20('YAS1', '$YAS1', BoundedInt::max(), OWNER());
+        let reward_token = deploy_erc20('RWD', '$RWD', 1000000, OWNER());
+
+        set_contract_address(OWNER());
+        token_0.transfer(WALLET(), BoundedInt::max());
+        token_1.transfer(WALLET(), BoundedInt::max());
+        reward_token.approve(yas_staker.contract_address, BoundedInt::max());
+
+        set_contract_address(WALLET());
+        token_0.approve(nft_position_manager.contract_address, BoundedInt::max());
+        token_1.approve(nft_position_manager.contract_address, BoundedInt::max());
+        nft_position_manager.set_approval_for_all(yas_staker.contract_address, true);
+
+        let pool = nft_position_manager
+            .create_and_initialize_pool_if_necessary(
+                token_0.contract_address,
+                token_1.contract_address,
+                fee_amount(FeeAmount::MEDIUM),
+                encode_price_sqrt_1_1()
+            );
+        let (min_tick, max_tick) = get_min_tick_and_max_tick();
+        let pool_key = PoolKey {
+            token_0: token_0.contract_address,
+            token_1: token_1.contract_address,
+            fee: fee_amount(FeeAmount::MEDIUM)
+        };
+        let token_id = mint_position(nft_position_manager, pool_key, min_tick, max_tick, 100, 100);
+
+        let key = IncentiveKey {
+            reward_token: reward_token.contract_address,
+            pool,
+            start_time: 100,
+            end_time: 1100,
+            refundee: OWNER()
+        };
+        (yas_staker, nft_position_manager, reward_token, key, token_id)
+    }
+
+    fn deploy_nft_position_manager(factory: ContractAddress) -> IYASNFTPositionManagerDispatcher {
+        let (address, _) = deploy_syscall(
+            YASNFTPositionManager::TEST_CLASS_HASH.try_into().unwrap(),
+            0,
+            array![factory.into()].span(),
+            true
+        )
+            .unwrap_syscall();
+
+        return IYASNFTPositionManagerDispatcher { contract_address: address };
+    }
+
+    fn deploy_yas_staker(
+        factory: ContractAddress, position_manager: ContractAddress
+    ) -> IYASStakerDispatcher {
+        let calldata = array![
+            factory.into(),
+            position_manager.into(),
+            MAX_INCENTIVE_START_LEAD_TIME.into(),
+            MAX_INCENTIVE_DURATION.into()
+        ];
+        let (address, _) = deploy_syscall(
+            YASStaker::TEST_CLASS_HASH.try_into().unwrap(), 0, calldata.span(), true
+        )
+            .unwrap_syscall();
+
+        return IYASStakerDispatcher { contract_address: address };
+    }
+
+    // Mints a position of WALLET, the caller must be WALLET
+    fn mint_position(
+        nft_position_manager: IYASNFTPositionManagerDispatcher,
+        pool_key: PoolKey,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount_0: u256,
+        amount_1: u256
+    ) -> u256 {
+        let (token_id, _, _, _) = nft_position_manager
+            .mint(
+                MintParams {
+                    token_0: pool_key.token_0,
+                    token_1: pool_key.token_1,
+                    fee: pool_key.fee,
+                    recipient: WALLET(),
+                    tick_lower,
+                    tick_upper,
+                    amount_0_desired: amount_0,
+                    amount_1_desired: amount_1,
+                    amount_0_min: 0,
+                    amount_1_min: 0,
+                    deadline: BoundedInt::max()
+                }
+            );
+        token_id
+    }
+
+    fn create_incentive(yas_staker: IYASStakerDispatcher, key: IncentiveKey) {
+        set_contract_address(OWNER());
+        yas_staker.create_incentive(key, REWARD);
+    }
+
+    // Deposits and stakes token_id of WALLET, at the start of the incentive
+    fn deposit_and_stake(yas_staker: IYASStakerDispatcher, key: IncentiveKey, token_id: u256) {
+        set_block_timestamp(key.start_time);
+        set_contract_address(WALLET());
+        yas_staker.deposit_token(token_id);
+        yas_staker.stake_token(key, token_id);
+    }
+
+    mod CreateIncentive {
+        use super::{setup, create_incentive, REWARD};
+        use starknet::testing::{set_contract_address, set_block_timestamp};
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::tests::utils::constants::FactoryConstants::OWNER;
+
+        use yas_periphery::yas_staker::{IYASStakerDispatcher, IYASStakerDispatcherTrait};
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_creates_incentive() {
+            let (yas_staker, _, reward_token, key, _) = setup();
+
+            create_incentive(yas_staker, key);
+
+            let incentive = yas_staker.incentives(yas_staker.compute_incentive_id(key));
+            assert(incentive.total_reward_unclaimed == REWARD, 'wrong reward unclaimed');
+            assert(incentive.total_seconds_claimed_X128 == 0, 'wrong seconds claimed');
+            assert(incentive.number_of_stakes == 0, 'wrong number of stakes');
+            assert(
+                reward_token.balanceOf(yas_staker.contract_address) == REWARD,
+                'wrong staker balance'
+            );
+            assert(reward_token.balanceOf(OWNER()) == 1000000 - REWARD, 'wrong OWNER balance');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_adds_reward_to_existing_incentive() {
+            let (yas_staker, _, reward_token, key, _) = setup();
+
+            create_incentive(yas_staker, key);
+            create_incentive(yas_staker, key);
+
+            let incentive = yas_staker.incentives(yas_staker.compute_incentive_id(key));
+            assert(incentive.total_reward_unclaimed == 2 * REWARD, 'wrong reward unclaimed');
+            assert(
+                reward_token.balanceOf(yas_staker.contract_address) == 2 * REWARD,
+                'wrong staker balance'
+            );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Reward must be positive', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_with_zero_reward() {
+            let (yas_staker, _, _, key, _) = setup();
+
+            set_contract_address(OWNER());
+            yas_staker.create_incentive(key, 0);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Start time in the past', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_start_time_in_the_past() {
+            let (yas_staker, _, _, key, _) = setup();
+
+            set_block_timestamp(key.start_time + 1);
+            create_incentive(yas_staker, key);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Start time too far in future', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_start_time_too_far_in_future() {
+            let (yas_staker, _, _, mut key, _) = setup();
+
+            key.start_time = 2592001;
+            key.end_time = 2593001;
+            create_incentive(yas_staker, key);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Start time after end time', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_start_time_after_end_time() {
+            let (yas_staker, _, _, mut key, _) = setup();
+
+            key.end_time = key.start_time;
+            create_incentive(yas_staker, key);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Incentive duration too long', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_duration_too_long() {
+            let (yas_staker, _, _, mut key, _) = setup();
+
+            key.end_time = key.start_time + 63072001;
+            create_incentive(yas_staker, key);
+        }
+    }
+
+    mod Deposit {
+        use super::{setup, create_incentive, deposit_and_stake};
+        use starknet::testing::set_contract_address;
+
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        use yas_periphery::yas_nft_position_manager::{
+            IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait
+        };
+        use yas_periphery::yas_staker::{IYASStakerDispatcher, IYASStakerDispatcherTrait};
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_deposits_token() {
+            let (yas_staker, nft_position_manager, _, _, token_id) = setup();
+
+            set_contract_address(WALLET());
+            yas_staker.deposit_token(token_id);
+
+            assert(
+                nft_position_manager.owner_of(token_id) == yas_staker.contract_address,
+                'wrong NFT owner'
+            );
+            let (position, _) = nft_position_manager.positions(token_id);
+            let deposit = yas_staker.deposits(token_id);
+            assert(deposit.owner == WALLET(), 'wrong deposit owner');
+            assert(deposit.number_of_stakes == 0, 'wrong number of stakes');
+            assert(deposit.tick_lower == position.tick_lower, 'wrong tick_lower');
+            assert(deposit.tick_upper == position.tick_upper, 'wrong tick_upper');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_withdraws_token() {
+            let (yas_staker, nft_position_manager, _, _, token_id) = setup();
+
+            set_contract_address(WALLET());
+            yas_staker.deposit_token(token_id);
+            yas_staker.withdraw_token(token_id, OTHER());
+
+            assert(nft_position_manager.owner_of(token_id) == OTHER(), 'wrong NFT owner');
+            assert(yas_staker.deposits(token_id).owner.is_zero(), 'deposit not cleared');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_transfers_deposit() {
+            let (yas_staker, nft_position_manager, _, _, token_id) = setup();
+
+            set_contract_address(WALLET());
+            yas_staker.deposit_token(token_id);
+            yas_staker.transfer_deposit(token_id, OTHER());
+            assert(yas_staker.deposits(token_id).owner == OTHER(), 'wrong deposit owner');
+
+            set_contract_address(OTHER());
+            yas_staker.withdraw_token(token_id, OTHER());
+            assert(nft_position_manager.owner_of(token_id) == OTHER(), 'wrong NFT owner');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not the deposit owner', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_to_transfer_deposit_if_not_owner() {
+            let (yas_staker, _, _, _, token_id) = setup();
+
+            set_contract_address(WALLET());
+            yas_staker.deposit_token(token_id);
+
+            set_contract_address(OTHER());
+            yas_staker.transfer_deposit(token_id, OTHER());
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not the deposit owner', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_to_withdraw_if_not_owner() {
+            let (yas_staker, _, _, _, token_id) = setup();
+
+            set_contract_address(WALLET());
+            yas_staker.deposit_token(token_id);
+
+            set_contract_address(OTHER());
+            yas_staker.withdraw_token(token_id, OTHER());
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Cannot withdraw while staked', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_to_withdraw_if_staked() {
+            let (yas_staker, _, _, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+
+            yas_staker.withdraw_token(token_id, WALLET());
+        }
+    }
+
+    mod Stake {
+        use super::{setup, create_incentive, deposit_and_stake};
+        use starknet::testing::{set_contract_address, set_block_timestamp};
+
+        use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        use yas_periphery::yas_nft_position_manager::{
+            IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait
+        };
+        use yas_periphery::yas_staker::{IYASStakerDispatcher, IYASStakerDispatcherTrait};
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_stakes_token() {
+            let (yas_staker, nft_position_manager, _, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+
+            deposit_and_stake(yas_staker, key, token_id);
+
+            let incentive_id = yas_staker.compute_incentive_id(key);
+            let (position, _) = nft_position_manager.positions(token_id);
+            let stake = yas_staker.stakes(token_id, incentive_id);
+            assert(stake.liquidity == position.liquidity, 'wrong stake liquidity');
+            assert(
+                stake
+                    .seconds_per_liquidity_inside_initial_X128 == IYASPoolDispatcher {
+                        contract_address: key.pool
+                    }
+                    .get_seconds_per_liquidity_inside(position.tick_lower, position.tick_upper),
+                'wrong initial seconds'
+            );
+            assert(yas_staker.deposits(token_id).number_of_stakes == 1, 'wrong deposit stakes');
+            assert(yas_staker.incentives(incentive_id).number_of_stakes == 1, 'wrong stakes');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Incentive not started', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_before_start_time() {
+            let (yas_staker, _, _, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+
+            set_contract_address(WALLET());
+            yas_staker.deposit_token(token_id);
+            yas_staker.stake_token(key, token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Incentive ended', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_after_end_time() {
+            let (yas_staker, _, _, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+
+            set_block_timestamp(key.end_time);
+            set_contract_address(WALLET());
+            yas_staker.deposit_token(token_id);
+            yas_staker.stake_token(key, token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Not the deposit owner', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_not_deposit_owner() {
+            let (yas_staker, _, _, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+
+            set_block_timestamp(key.start_time);
+            set_contract_address(WALLET());
+            yas_staker.deposit_token(token_id);
+
+            set_contract_address(OTHER());
+            yas_staker.stake_token(key, token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Token already staked', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_already_staked() {
+            let (yas_staker, _, _, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+
+            yas_staker.stake_token(key, token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Incentive does not exist', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_incentive_does_not_exist() {
+            let (yas_staker, _, _, key, token_id) = setup();
+
+            deposit_and_stake(yas_staker, key, token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Token pool is not incentivized', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_pool_is_not_incentivized() {
+            let (yas_staker, _, _, mut key, token_id) = setup();
+            key.pool = OTHER();
+            create_incentive(yas_staker, key);
+
+            deposit_and_stake(yas_staker, key, token_id);
+        }
+    }
+
+    mod Unstake {
+        use super::{
+            setup, create_incentive, deposit_and_stake, mint_position, get_min_tick_and_max_tick,
+            REWARD
+        };
+        use starknet::testing::{set_contract_address, set_block_timestamp};
+
+        use yas_core::numbers::signed_integer::{i32::i32, integer_trait::IntegerTrait};
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        use yas_periphery::yas_nft_position_manager::{
+            IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait
+        };
+        use yas_periphery::yas_staker::{IYASStakerDispatcher, IYASStakerDispatcherTrait};
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_single_staker_earns_full_reward() {
+            let (yas_staker, _, reward_token, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+
+            set_block_timestamp(key.end_time);
+            let (expected_reward, _) = yas_staker.get_reward_info(key, token_id);
+            yas_staker.unstake_token(key, token_id);
+
+            let reward = yas_staker.rewards(reward_token.contract_address, WALLET());
+            assert(reward == expected_reward, 'wrong reward info');
+            // rounded down
+            assert(reward == REWARD || reward == REWARD - 1, 'wrong reward');
+
+            let incentive_id = yas_staker.compute_incentive_id(key);
+            let incentive = yas_staker.incentives(incentive_id);
+            assert(incentive.total_reward_unclaimed == REWARD - reward, 'wrong reward unclaimed');
+            assert(incentive.number_of_stakes == 0, 'wrong number of stakes');
+            assert(yas_staker.deposits(token_id).number_of_stakes == 0, 'wrong deposit stakes');
+            assert(yas_staker.stakes(token_id, incentive_id).liquidity == 0, 'stake not cleared');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_half_time_staked_earns_half_reward() {
+            let (yas_staker, _, reward_token, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+
+            set_block_timestamp(600);
+            yas_staker.unstake_token(key, token_id);
+
+            let reward = yas_staker.rewards(reward_token.contract_address, WALLET());
+            assert(reward == REWARD / 2 || reward == REWARD / 2 - 1, 'wrong reward');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_equal_stakers_share_reward() {
+            let (yas_staker, nft_position_manager, reward_token, key, token_id) = setup();
+            let (_, pool_key) = nft_position_manager.positions(token_id);
+            let (min_tick, max_tick) = get_min_tick_and_max_tick();
+            let other_token_id = mint_position(
+                nft_position_manager, pool_key, min_tick, max_tick, 100, 100
+            );
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+            deposit_and_stake(yas_staker, key, other_token_id);
+
+            set_block_timestamp(key.end_time);
+            yas_staker.unstake_token(key, token_id);
+            let reward = yas_staker.rewards(reward_token.contract_address, WALLET());
+            yas_staker.unstake_token(key, other_token_id);
+            let other_reward = yas_staker.rewards(reward_token.contract_address, WALLET())
+                - reward;
+
+            assert(reward >= REWARD / 2 - 1 && reward <= REWARD / 2, 'wrong reward');
+            assert(
+                other_reward >= REWARD / 2 - 1 && other_reward <= REWARD / 2 + 1,
+                'wrong other reward'
+            );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_out_of_range_position_earns_nothing() {
+            let (yas_staker, nft_position_manager, reward_token, key, token_id) = setup();
+            let (_, pool_key) = nft_position_manager.positions(token_id);
+            // above the current tick, only token_0
+            let out_of_range_token_id = mint_position(
+                nft_position_manager,
+                pool_key,
+                IntegerTrait::<i32>::new(60, false),
+                IntegerTrait::<i32>::new(120, false),
+                100,
+                0
+            );
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, out_of_range_token_id);
+
+            set_block_timestamp(key.end_time);
+            yas_staker.unstake_token(key, out_of_range_token_id);
+
+            assert(
+                yas_staker.rewards(reward_token.contract_address, WALLET()) == 0, 'wrong reward'
+            );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_anyone_can_unstake_after_end_time() {
+            let (yas_staker, _, reward_token, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+
+            set_block_timestamp(key.end_time);
+            set_contract_address(OTHER());
+            yas_staker.unstake_token(key, token_id);
+
+            // the reward goes to the deposit owner
+            assert(yas_staker.rewards(reward_token.contract_address, WALLET()) > 0, 'wrong reward');
+            assert(
+                yas_staker.rewards(reward_token.contract_address, OTHER()) == 0,
+                'wrong OTHER reward'
+            );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Only owner can unstake early', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_not_owner_before_end_time() {
+            let (yas_staker, _, _, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+
+            set_contract_address(OTHER());
+            yas_staker.unstake_token(key, token_id);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Token not staked', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_not_staked() {
+            let (yas_staker, _, _, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+
+            set_contract_address(WALLET());
+            yas_staker.deposit_token(token_id);
+            yas_staker.unstake_token(key, token_id);
+        }
+    }
+
+    mod ClaimReward {
+        use super::{setup, create_incentive, deposit_and_stake};
+        use starknet::testing::set_block_timestamp;
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        use yas_periphery::yas_staker::{IYASStakerDispatcher, IYASStakerDispatcherTrait};
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_claims_all_rewards() {
+            let (yas_staker, _, reward_token, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+            set_block_timestamp(key.end_time);
+            yas_staker.unstake_token(key, token_id);
+            let reward = yas_staker.rewards(reward_token.contract_address, WALLET());
+
+            let claimed = yas_staker.claim_reward(reward_token.contract_address, OTHER(), 0);
+
+            assert(claimed == reward, 'wrong claimed');
+            assert(reward_token.balanceOf(OTHER()) == reward, 'wrong OTHER balance');
+            assert(
+                yas_staker.rewards(reward_token.contract_address, WALLET()) == 0, 'wrong rewards'
+            );
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_claims_requested_amount() {
+            let (yas_staker, _, reward_token, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+            set_block_timestamp(key.end_time);
+            yas_staker.unstake_token(key, token_id);
+            let reward = yas_staker.rewards(reward_token.contract_address, WALLET());
+
+            let claimed = yas_staker.claim_reward(reward_token.contract_address, WALLET(), 100);
+
+            assert(claimed == 100, 'wrong claimed');
+            assert(reward_token.balanceOf(WALLET()) == 100, 'wrong WALLET balance');
+            assert(
+                yas_staker.rewards(reward_token.contract_address, WALLET()) == reward - 100,
+                'wrong rewards'
+            );
+        }
+    }
+
+    mod EndIncentive {
+        use super::{setup, create_incentive, deposit_and_stake, REWARD};
+        use starknet::testing::{set_contract_address, set_block_timestamp};
+
+        use yas_core::contracts::yas_erc20::{IERC20Dispatcher, IERC20DispatcherTrait};
+        use yas_core::tests::utils::constants::FactoryConstants::OWNER;
+        use yas_core::tests::utils::constants::PoolConstants::{WALLET, OTHER};
+
+        use yas_periphery::yas_staker::{IYASStakerDispatcher, IYASStakerDispatcherTrait};
+
+        #[test]
+        #[available_gas(2000000000)]
+        fn test_refunds_unclaimed_reward() {
+            let (yas_staker, _, reward_token, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+            // staked for the first half only
+            set_block_timestamp(600);
+            yas_staker.unstake_token(key, token_id);
+            let reward = yas_staker.rewards(reward_token.contract_address, WALLET());
+
+            set_block_timestamp(key.end_time);
+            set_contract_address(OTHER());
+            let refund = yas_staker.end_incentive(key);
+
+            assert(refund == REWARD - reward, 'wrong refund');
+            assert(
+                reward_token.balanceOf(OWNER()) == 1000000 - REWARD + refund, 'wrong OWNER balance'
+            );
+            let incentive = yas_staker.incentives(yas_staker.compute_incentive_id(key));
+            assert(incentive.total_reward_unclaimed == 0, 'wrong reward unclaimed');
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Cannot end before end time', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_before_end_time() {
+            let (yas_staker, _, _, key, _) = setup();
+            create_incentive(yas_staker, key);
+
+            set_block_timestamp(key.end_time - 1);
+            yas_staker.end_incentive(key);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('Cannot end while staked', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_while_staked() {
+            let (yas_staker, _, _, key, token_id) = setup();
+            create_incentive(yas_staker, key);
+            deposit_and_stake(yas_staker, key, token_id);
+
+            set_block_timestamp(key.end_time);
+            yas_staker.end_incentive(key);
+        }
+
+        #[test]
+        #[available_gas(2000000000)]
+        #[should_panic(expected: ('No refund available', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_already_ended() {
+            let (yas_staker, _, _, key, _) = setup();
+            create_incentive(yas_staker, key);
+
+            set_block_timestamp(key.end_time);
+            yas_staker.end_incentive(key);
+            yas_staker.end_incentive(key);
+        }
+    }
+}
@@ -0,0 +1,345 @@
+mod YASTickLensTests {
+    use starknet::SyscallResultTrait;
+    use starknet::syscalls::deploy_syscall;
+    use starknet::testing::set_contract_address;
+    use integer::BoundedInt;
+
+    use yas_core::contracts::yas_erc20::IERC20DispatcherTrait;
+    use yas_core::contracts::yas_factory::IYASFactoryDispatcherTrait;
+    use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+    use yas_core::contracts::yas_router::IYASRouterDispatcherTrait;
+    use yas_core::numbers::signed_integer::{i32::i32, integer_trait::IntegerTrait};
+    use yas_core::tests::utils::constants::FactoryConstants::{
+        POOL_CLASS_HASH, FeeAmount, fee_amount, OWNER
+    };
+    use yas_core::tests::utils::constants::PoolConstants::{WALLET, encode_price_sqrt_1_1};
+    use yas_core::tests::utils::deployers::Deployers::{
+        deploy_factory, deploy_yas_router, deploy_erc20, get_min_tick_and_max_tick
+    };
+
+    use yas_periphery::yas_tick_lens::{
+        YASTickLens, IYASTickLensDispatcher, IYASTickLensDispatcherTrait
+    };
+
+    const LIQUIDITY: u128 = 1000000000000000000;
+    const POSITION_LIQUIDITY: u128 = 100;
+
+    // Initializes the ticks MIN_TICK, -120, 60 and MAX_TICK of a pool with a tick spacing of 60
+    fn setup() -> (IYASTickLensDispatcher, IYASPoolDispatcher) {
+        let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH()); // 0x1
+        let yas_router = deploy_yas_router(yas_factory.contract_address); // 0x2
+        let yas_tick_lens = deploy_yas_tick_lens(); // 0x3
+
+        let token_0 = deploy_erc20('YAS0', '$YAS0', BoundedInt::max(), OWNER()); // 0x4
+        let token_1 = deploy_erc20('YAS1', '$YAS1', BoundedInt::max(), OWNER()); // 0x5
+
+        set_contract_address(OWNER());
+        token_0.transfer(WALLET(), BoundedInt::max());
+        token_1.transfer(WALLET(), BoundedInt::max());
+
+        // Give permissions to expend WALLET() tokens
+        set_contract_address(WALLET());
+        token_0.approve(yas_router.contract_address, BoundedInt::max());
+        token_1.approve(yas_router.contract_address, BoundedInt::max());
+
+        let yas_pool_address = yas_factory
+            .create_pool(
+                token_0.contract_address, token_1.contract_address, fee_amount(FeeAmount::MEDIUM)
+            ); // 0x6
+        let yas_pool = IYASPoolDispatcher { contract_address: yas_pool_address };
+        yas_pool.initialize(encode_price_sqrt_1_1());
+
+        let (min_tick, max_tick) = get_min_tick_and_max_tick();
+        yas_router.mint(yas_pool_address, WALLET(), min_tick, max_tick, LIQUIDITY);
+        yas_router
+            .mint(
+                yas_pool_address,
+                WALLET(),
+                IntegerTrait::<i32>::new(120, true),
+                IntegerTrait::<i32>::new(60, false),
+                POSITION_LIQUIDITY
+            );
+
+        (yas_tick_lens, yas_pool)
+    }
+
+    // Initializes the ticks -700, -512 and -414 of a pool with a tick spacing of 1, which live in
+    // the words -3, -2 and -2
+    fn setup_negative_ticks() -> (IYASTickLensDispatcher, IYASPoolDispatcher) {
+        let yas_factory = deploy_factory(OWNER(), POOL_CLASS_HASH());
+        let yas_router = deploy_yas_router(yas_factory.contract_address);
+        let yas_tick_lens = deploy_yas_tick_lens();
+
+        let token_0 = deploy_erc20('YAS0', '$YAS0', BoundedInt::max(), OWNER());
+        let token_1 = deploy_erc20('YAS1', '$YAS1', BoundedInt::max(), OWNER());
+
+        set_contract_address(OWNER());
+        yas_factory.enable_fee_amount(100, IntegerTrait::<i32>::new(1, false));
+        token_0.transfer(WALLET(), BoundedInt::max());
+        token_1.transfer(WALLET(), BoundedInt::max());
+
+        set_contract_address(WALLET());
+        token_0.approve(yas_router.contract_address, BoundedInt::max());
+        token_1.approve(yas_router.contract_address, BoundedInt::max());
+
+        let yas_pool_address = yas_factory
+            .create_pool(token_0.contract_address, token_1.contract_address, 100);
+        let yas_pool = IYASPoolDispatcher { contract_address: yas_pool_address };
+        yas_pool.initialize(encode_price_sqrt_1_1());
+
+        yas_router
+            .mint(
+                yas_pool_address,
+                WALLET(),
+                IntegerTrait::<i32>::new(700, true),
+                IntegerTrait::<i32>::new(414, true),
+                POSITION_LIQUIDITY
+            );
+        yas_router
+            .mint(
+                yas_pool_address,
+                WALLET(),
+                IntegerTrait::<i32>::new(512, true),
+                IntegerTrait::<i32>::new(414, true),
+                POSITION_LIQUIDITY
+            );
+
+        (yas_tick_lens, yas_pool)
+    }
+
+    fn deploy_yas_tick_lens() -> IYASTickLensDispatcher {
+        let (address, _) = deploy_syscall(
+            YASTickLens::TEST_CLASS_HASH.try_into().unwrap(), 0, array![].span(), true
+        )
+            .unwrap_syscall();
+
+        return IYASTickLensDispatcher { contract_address: address };
+    }
+
+    mod GetPopulatedTicksInWord {
+        use super::{
+            setup, setup_negative_ticks, get_min_tick_and_max_tick, LIQUIDITY, POSITION_LIQUIDITY
+        };
+
+        use yas_core::libraries::tick_bitmap::TickBitmap::position;
+        use yas_core::numbers::signed_integer::{
+            i16::i16, i32::i32, i128::i128, integer_trait::IntegerTrait
+        };
+
+        use yas_periphery::yas_tick_lens::IYASTickLensDispatcherTrait;
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_returns_the_populated_ticks_of_a_word() {
+            let (yas_tick_lens, yas_pool) = setup();
+
+            // tick -120 has the compressed tick -2, which lives in word -1
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_word(
+                    yas_pool.contract_address, IntegerTrait::<i16>::new(1, true)
+                );
+            assert(populated_ticks.len() == 1, 'wrong populated ticks len');
+            let populated_tick = *populated_ticks.at(0);
+            assert(populated_tick.tick == IntegerTrait::<i32>::new(120, true), 'wrong tick');
+            assert(
+                populated_tick.liquidity_net.mag == POSITION_LIQUIDITY
+                    && !populated_tick.liquidity_net.sign,
+                'wrong liquidity_net'
+            );
+            assert(populated_tick.liquidity_gross == POSITION_LIQUIDITY, 'wrong liquidity_gross');
+
+            // tick 60 has the compressed tick 1, which lives in word 0
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_word(
+                    yas_pool.contract_address, IntegerTrait::<i16>::new(0, false)
+                );
+            assert(populated_ticks.len() == 1, 'wrong populated ticks len');
+            let populated_tick = *populated_ticks.at(0);
+            assert(populated_tick.tick == IntegerTrait::<i32>::new(60, false), 'wrong tick');
+            assert(
+                populated_tick.liquidity_net == IntegerTrait::<i128>::new(POSITION_LIQUIDITY, true),
+                'wrong liquidity_net'
+            );
+            assert(populated_tick.liquidity_gross == POSITION_LIQUIDITY, 'wrong liquidity_gross');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_returns_the_min_tick() {
+            let (yas_tick_lens, yas_pool) = setup();
+            let (min_tick, _) = get_min_tick_and_max_tick();
+            let (word_pos, _) = position(min_tick / IntegerTrait::<i32>::new(60, false));
+
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_word(yas_pool.contract_address, word_pos);
+            assert(populated_ticks.len() == 1, 'wrong populated ticks len');
+            let populated_tick = *populated_ticks.at(0);
+            assert(populated_tick.tick == min_tick, 'wrong tick');
+            assert(
+                populated_tick.liquidity_net == IntegerTrait::<i128>::new(LIQUIDITY, false),
+                'wrong liquidity_net'
+            );
+            assert(populated_tick.liquidity_gross == LIQUIDITY, 'wrong liquidity_gross');
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_returns_negative_ticks_of_a_word() {
+            let (yas_tick_lens, yas_pool) = setup_negative_ticks();
+
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_word(
+                    yas_pool.contract_address, IntegerTrait::<i16>::new(2, true)
+                );
+            assert(populated_ticks.len() == 2, 'wrong populated ticks len');
+            let populated_tick = *populated_ticks.at(0);
+            assert(populated_tick.tick == IntegerTrait::<i32>::new(512, true), 'wrong first tick');
+            assert(
+                populated_tick
+                    .liquidity_net == IntegerTrait::<i128>::new(POSITION_LIQUIDITY, false),
+                'wrong first liquidity_net'
+            );
+            let populated_tick = *populated_ticks.at(1);
+            assert(populated_tick.tick == IntegerTrait::<i32>::new(414, true), 'wrong second tick');
+            assert(
+                populated_tick
+                    .liquidity_net == IntegerTrait::<i128>::new(2 * POSITION_LIQUIDITY, true),
+                'wrong second liquidity_net'
+            );
+
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_word(
+                    yas_pool.contract_address, IntegerTrait::<i16>::new(3, true)
+                );
+            assert(populated_ticks.len() == 1, 'wrong populated ticks len');
+            assert(
+                (*populated_ticks.at(0)).tick == IntegerTrait::<i32>::new(700, true), 'wrong tick'
+            );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_returns_nothing_for_an_empty_word() {
+            let (yas_tick_lens, yas_pool) = setup();
+
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_word(
+                    yas_pool.contract_address, IntegerTrait::<i16>::new(1, false)
+                );
+            assert(populated_ticks.len() == 0, 'wrong populated ticks len');
+        }
+    }
+
+    mod GetPopulatedTicksInRange {
+        use super::{setup, setup_negative_ticks, get_min_tick_and_max_tick, LIQUIDITY};
+
+        use yas_core::numbers::signed_integer::{i32::i32, i128::i128, integer_trait::IntegerTrait};
+
+        use yas_periphery::yas_tick_lens::IYASTickLensDispatcherTrait;
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_returns_the_ticks_in_range_sorted() {
+            let (yas_tick_lens, yas_pool) = setup();
+
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_range(
+                    yas_pool.contract_address,
+                    IntegerTrait::<i32>::new(120, true),
+                    IntegerTrait::<i32>::new(60, false)
+                );
+            assert(populated_ticks.len() == 2, 'wrong populated ticks len');
+            assert(
+                (*populated_ticks.at(0)).tick == IntegerTrait::<i32>::new(120, true),
+                'wrong first tick'
+            );
+            assert(
+                (*populated_ticks.at(1)).tick == IntegerTrait::<i32>::new(60, false),
+                'wrong second tick'
+            );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_returns_negative_ticks_across_words() {
+            let (yas_tick_lens, yas_pool) = setup_negative_ticks();
+
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_range(
+                    yas_pool.contract_address,
+                    IntegerTrait::<i32>::new(700, true),
+                    IntegerTrait::<i32>::new(414, true)
+                );
+            assert(populated_ticks.len() == 3, 'wrong populated ticks len');
+            assert(
+                (*populated_ticks.at(0)).tick == IntegerTrait::<i32>::new(700, true),
+                'wrong first tick'
+            );
+            assert(
+                (*populated_ticks.at(1)).tick == IntegerTrait::<i32>::new(512, true),
+                'wrong second tick'
+            );
+            assert(
+                (*populated_ticks.at(2)).tick == IntegerTrait::<i32>::new(414, true),
+                'wrong third tick'
+            );
+
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_range(
+                    yas_pool.contract_address,
+                    IntegerTrait::<i32>::new(600, true),
+                    IntegerTrait::<i32>::new(500, true)
+                );
+            assert(populated_ticks.len() == 1, 'wrong populated ticks len');
+            assert(
+                (*populated_ticks.at(0)).tick == IntegerTrait::<i32>::new(512, true), 'wrong tick'
+            );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        fn test_excludes_ticks_outside_of_the_range() {
+            let (yas_tick_lens, yas_pool) = setup();
+
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_range(
+                    yas_pool.contract_address,
+                    IntegerTrait::<i32>::new(119, true),
+                    IntegerTrait::<i32>::new(59, false)
+                );
+            assert(populated_ticks.len() == 0, 'wrong populated ticks len');
+        }
+
+        #[test]
+        #[available_gas(2000000000000)]
+        fn test_returns_every_tick_for_the_full_range() {
+            let (yas_tick_lens, yas_pool) = setup();
+            let (min_tick, max_tick) = get_min_tick_and_max_tick();
+
+            let populated_ticks = yas_tick_lens
+                .get_populated_ticks_in_range(yas_pool.contract_address, min_tick, max_tick);
+            assert(populated_ticks.len() == 4, 'wrong populated ticks len');
+            assert((*populated_ticks.at(0)).tick == min_tick, 'wrong first tick');
+            assert((*populated_ticks.at(3)).tick == max_tick, 'wrong last tick');
+            assert(
+                (*populated_ticks.at(3)).liquidity_net.mag == LIQUIDITY
+                    && (*populated_ticks.at(3)).liquidity_net.sign,
+                'wrong last liquidity_net'
+            );
+        }
+
+        #[test]
+        #[available_gas(200000000000)]
+        #[should_panic(expected: ('invalid tick range', 'ENTRYPOINT_FAILED'))]
+        fn test_fails_if_range_is_inverted() {
+            let (yas_tick_lens, yas_pool) = setup();
+
+            yas_tick_lens
+                .get_populated_ticks_in_range(
+                    yas_pool.contract_address,
+                    IntegerTrait::<i32>::new(60, false),
+                    IntegerTrait::<i32>::new(120, true)
+                );
+        }
+    }
+}
@@ -93,14 +93,26 @@ trait IYASNFTPositionManager<TContractState> {
     fn increase_liquidity(
         ref self: TContractState, params: IncreaseLiquidityParams
     ) -> (u128, u256, u256);
+    fn decrease_liquidity(
+        ref self: TContractState, params: DecreaseLiquidityParams
+    ) -> (u256, u256);
+    fn collect(
+        ref self: TContractState,
+        token_id: u256,
+        recipient: ContractAddress,
+        amount_0_max: u128,
+        amount_1_max: u128
+    ) -> (u256, u256);
+    fn burn(ref self: TContractState, token_id: u256);
     fn yas_mint_callback(
         ref self: TContractState, amount_0_owed: u256, amount_1_owed: u256, data: Array<felt252>
     );
+    fn multicall(ref self: TContractState, calls: Array<Span<felt252>>) -> Array<Span<felt252>>;
     // ERC721
     fn supports_interface(self: @TContractState, interface_id: felt252) -> bool;
     fn name(self: @TContractState) -> felt252;
     fn symbol(self: @TContractState) -> felt252;
-    fn token_uri(self: @TContractState, token_id: u256) -> felt252;
+    fn token_uri(self: @TContractState, token_id: u256) -> Array<felt252>;
     fn balance_of(self: @TContractState, account: ContractAddress) -> u256;
     fn owner_of(self: @TContractState, token_id: u256) -> ContractAddress;
     fn transfer_from(
@@ -119,6 +131,19 @@ trait IYASNFTPositionManager<TContractState> {
     fn is_approved_for_all(
         self: @TContractState, owner: ContractAddress, operator: ContractAddress
     ) -> bool;
+    // ERC721 Enumerable
+    fn total_supply(self: @TContractState) -> u256;
+    fn token_by_index(self: @TContractState, index: u256) -> u256;
+    fn token_of_owner_by_index(self: @TContractState, owner: ContractAddress, index: u256) -> u256;
+    // ERC721 Permit
+    fn nonces(self: @TContractState, token_id: u256) -> felt252;
+    fn permit(
+        ref self: TContractState,
+        spender: ContractAddress,
+        token_id: u256,
+        deadline: u256,
+        signature: Array<felt252>
+    );
 }
 
 #[starknet::contract]
@@ -128,10 +153,13 @@ mod YASNFTPositionManager {
         DecreaseLiquidityParams, MintParams, Position, PoolKey
     };
     use starknet::{
-        ContractAddress, get_contract_address, contract_address_const, get_caller_address
+        ContractAddress, get_block_timestamp, get_contract_address, contract_address_const,
+        get_caller_address, get_tx_info
     };
     use openzeppelin::token::erc721::ERC721;
 
+    use yas_periphery::nft_descriptor::{ConstructTokenURIParams, NFTDescriptor};
+
     use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
     use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
     use yas_core::interfaces::interface_ERC20::{IERC20Dispatcher, IERC20DispatcherTrait};
@@ -143,11 +171,39 @@ mod YASNFTPositionManager {
         FixedType, FixedTrait, FP64x96PartialOrd, FP64x96PartialEq, FP64x96Impl, FP64x96Zeroable,
         FP64x96Sub, ONE
     };
-    use yas_core::numbers::signed_integer::{i32::i32};
+    use yas_core::numbers::signed_integer::{i32::i32, integer_trait::IntegerTrait};
     use yas_core::utils::math_utils::Constants::Q128;
     use yas_core::utils::math_utils::FullMath;
+    use yas_core::utils::typed_data::TypedData::{
+        StarknetDomain, compute_hash_on_elements, hash_u256, hash_message, is_valid_signature
+    };
     use yas_core::utils::utils::ContractAddressPartialOrd;
 
+    // sn_keccak('Permit(spender:felt,token_id:u256,nonce:felt,deadline:u256,verifying_contract:felt)u256(low:felt,high:felt)')
+    const PERMIT_TYPE_HASH: felt252 =
+        0x14929d60210e9e5c33c19e586e57dcba84b3be8ff734890381570b11867bf03;
+    // the permit domain is the token name and this version
+    const PERMIT_VERSION: felt252 = '1';
+
+    mod Errors {
+        const INVALID_CALLDATA: felt252 = 'invalid calldata';
+        const UNSUPPORTED_SELECTOR: felt252 = 'unsupported selector';
+    }
+
+    // entry points that can be batched with multicall, as sn_keccak of their names
+    mod Selectors {
+        const CREATE_AND_INITIALIZE_POOL_IF_NECESSARY: felt252 =
+            0x1bd054c974309df53e6841e4df0ad1d6ec076936c75317994373b57fbf0c5b1;
+        const MINT: felt252 = 0x2f0b3c5710379609eb5495f1ecd348cb28167711b73609fe565a72734550354;
+        const INCREASE_LIQUIDITY: felt252 =
+            0x2d27a01d6ab1a3af3204bc20743c243a4cb52234dc93a907e7c8da4097acfa8;
+        const DECREASE_LIQUIDITY: felt252 =
+            0x3bc75403f0d735653dce6c92437af5f88d5a4b49c6ef582454a66d3a033d429;
+        const COLLECT: felt252 = 0x284134db6f39215e1d0a0a3dbd382fd7d28af6b3e3d5b77bba35c3a1d0da316;
+        const BURN: felt252 = 0x3e8cfd4725c1e28fa4a6e3e468b4fcf75367166b850ac5f04e33ec843e82c1;
+        const PERMIT: felt252 = 0xec05aa319b6958a87b81f9887ffc395d1d4a9e3203fefda7f12ae099375a8f;
+    }
+
     #[event]
     #[derive(Drop, starknet::Event)]
     enum Event {
@@ -202,7 +258,15 @@ mod YASNFTPositionManager {
         pool_id_to_pool_key: LegacyMap<u128, PoolKey>,
         positions: LegacyMap<u256, Position>,
         next_id: u256,
-        next_pool_id: u128
+        next_pool_id: u128,
+        // ERC721 Enumerable
+        owned_tokens: LegacyMap<(ContractAddress, u256), u256>,
+        owned_tokens_index: LegacyMap<u256, u256>,
+        all_tokens: LegacyMap<u256, u256>,
+        all_tokens_index: LegacyMap<u256, u256>,
+        all_tokens_len: u256,
+        // ERC721 Permit
+        permit_nonces: LegacyMap<u256, felt252>
     }
 
     #[constructor]
@@ -229,6 +293,8 @@ mod YASNFTPositionManager {
         }
 
         fn mint(ref self: ContractState, params: MintParams) -> (u256, u128, u256, u256) {
+            self.check_deadline(params.deadline);
+
             let (liquidity, amount_0, amount_1, pool_dispatcher) = self
                 .add_liquidity(
                     AddLiquidityParams {
@@ -247,6 +313,8 @@ mod YASNFTPositionManager {
 
             let token_id = self.next_id.read();
             self.next_id.write(token_id + 1);
+            self.add_token_to_owner_enumeration(params.recipient, token_id);
+            self.add_token_to_all_tokens_enumeration(token_id);
             let mut state = ERC721::unsafe_new_contract_state();
             ERC721::InternalImpl::_mint(ref state, params.recipient, token_id);
 
@@ -290,6 +358,8 @@ mod YASNFTPositionManager {
         fn increase_liquidity(
             ref self: ContractState, params: IncreaseLiquidityParams
         ) -> (u128, u256, u256) {
+            self.check_deadline(params.deadline);
+
             let mut position = self.positions.read(params.token_id);
             let pool_key = self.pool_id_to_pool_key.read(position.pool_id);
 
@@ -352,6 +422,219 @@ mod YASNFTPositionManager {
             (liquidity, amount_0, amount_1)
         }
 
+        /// @notice Decreases the amount of liquidity in a position and accounts it to the position
+        /// @param params token_id The ID of the token for which liquidity is being decreased,
+        /// liquidity The amount by which liquidity will be decreased,
+        /// amount_0_min The minimum amount of token0 that should be accounted for the burned liquidity,
+        /// amount_1_min The minimum amount of token1 that should be accounted for the burned liquidity,
+        /// deadline The time by which the transaction must be included to effect the change
+        /// @return amount_0 The amount of token0 accounted to the position's tokens owed
+        /// @return amount_1 The amount of token1 accounted to the position's tokens owed
+        fn decrease_liquidity(
+            ref self: ContractState, params: DecreaseLiquidityParams
+        ) -> (u256, u256) {
+            self.check_authorized_for_token(params.token_id);
+            self.check_deadline(params.deadline);
+            assert(params.liquidity > 0, 'Invalid liquidity');
+
+            let mut position = self.positions.read(params.token_id);
+            let position_liquidity = position.liquidity;
+            assert(position_liquidity >= params.liquidity, 'Not enough liquidity');
+
+            let pool_dispatcher = self.get_pool(self.pool_id_to_pool_key.read(position.pool_id));
+            let (amount_0, amount_1) = pool_dispatcher
+                .burn(position.tick_lower, position.tick_upper, params.liquidity);
+
+            assert(
+                amount_0 >= params.amount_0_min && amount_1 >= params.amount_1_min,
+                'Price slippage check'
+            );
+
+            // this is now updated to the current transaction
+            let info = pool_dispatcher
+                .get_position(
+                    PositionKey {
+                        owner: get_contract_address(),
+                        tick_lower: position.tick_lower,
+                        tick_upper: position.tick_upper
+                    }
+                );
+
+            let fees_owed_0: u128 = FullMath::mul_div(
+                info.fee_growth_inside_0_last_X128 - position.fee_growth_inside_0_last_X128,
+                position_liquidity.into(),
+                Q128
+            )
+                .try_into()
+                .unwrap();
+            let fees_owed_1: u128 = FullMath::mul_div(
+                info.fee_growth_inside_1_last_X128 - position.fee_growth_inside_1_last_X128,
+                position_liquidity.into(),
+                Q128
+            )
+                .try_into()
+                .unwrap();
+
+            position.tokens_owed_0 += amount_0.try_into().unwrap() + fees_owed_0;
+            position.tokens_owed_1 += amount_1.try_into().unwrap() + fees_owed_1;
+
+            position.fee_growth_inside_0_last_X128 = info.fee_growth_inside_0_last_X128;
+            position.fee_growth_inside_1_last_X128 = info.fee_growth_inside_1_last_X128;
+            // subtraction is safe because we checked position_liquidity is gte params.liquidity
+            position.liquidity = position_liquidity - params.liquidity;
+
+            self.positions.write(params.token_id, position);
+
+            self
+                .emit(
+                    DecreaseLiquidity {
+                        token_id: params.token_id, liquidity: params.liquidity, amount_0, amount_1
+                    }
+                );
+            (amount_0, amount_1)
+        }
+
+        /// @notice Collects up to a maximum amount of fees owed to a specific position to the recipient
+        /// @param token_id The ID of the NFT for which tokens are being collected
+        /// @param recipient The account that should receive the tokens, zero collects to the manager itself
+        /// @param amount_0_max The maximum amount of token0 to collect
+        /// @param amount_1_max The maximum amount of token1 to collect
+        /// @return amount_0 The amount of fees collected in token0
+        /// @return amount_1 The amount of fees collected in token1
+        fn collect(
+            ref self: ContractState,
+            token_id: u256,
+            recipient: ContractAddress,
+            amount_0_max: u128,
+            amount_1_max: u128
+        ) -> (u256, u256) {
+            self.check_authorized_for_token(token_id);
+            assert(amount_0_max > 0 || amount_1_max > 0, 'Invalid amounts to collect');
+
+            // allow collecting to the nft position manager address with address 0
+            let recipient = if recipient.is_zero() {
+                get_contract_address()
+            } else {
+                recipient
+            };
+
+            let mut position = self.positions.read(token_id);
+            let pool_dispatcher = self.get_pool(self.pool_id_to_pool_key.read(position.pool_id));
+
+            let mut tokens_owed_0 = position.tokens_owed_0;
+            let mut tokens_owed_1 = position.tokens_owed_1;
+
+            // trigger an update of the position fees owed and fee growth snapshots if it has any liquidity
+            if position.liquidity > 0 {
+                pool_dispatcher.burn(position.tick_lower, position.tick_upper, 0);
+                let info = pool_dispatcher
+                    .get_position(
+                        PositionKey {
+                            owner: get_contract_address(),
+                            tick_lower: position.tick_lower,
+                            tick_upper: position.tick_upper
+                        }
+                    );
+
+                tokens_owed_0 +=
+                    FullMath::mul_div(
+                        info.fee_growth_inside_0_last_X128 - position.fee_growth_inside_0_last_X128,
+                        position.liquidity.into(),
+                        Q128
+                    )
+                    .try_into()
+                    .unwrap();
+                tokens_owed_1 +=
+                    FullMath::mul_div(
+                        info.fee_growth_inside_1_last_X128 - position.fee_growth_inside_1_last_X128,
+                        position.liquidity.into(),
+                        Q128
+                    )
+                    .try_into()
+                    .unwrap();
+
+                position.fee_growth_inside_0_last_X128 = info.fee_growth_inside_0_last_X128;
+                position.fee_growth_inside_1_last_X128 = info.fee_growth_inside_1_last_X128;
+            }
+
+            // compute the arguments to give to the pool collect method
+            let amount_0_collect = if amount_0_max > tokens_owed_0 {
+                tokens_owed_0
+            } else {
+                amount_0_max
+            };
+            let amount_1_collect = if amount_1_max > tokens_owed_1 {
+                tokens_owed_1
+            } else {
+                amount_1_max
+            };
+
+            // the actual amounts collected are returned
+            let (amount_0, amount_1) = pool_dispatcher
+                .collect(
+                    recipient,
+                    position.tick_lower,
+                    position.tick_upper,
+                    amount_0_collect,
+                    amount_1_collect
+                );
+
+            // sometimes there will be a few less wei than expected due to rounding down in core,
+            // but we just subtract the full amount expected
+            position.tokens_owed_0 = tokens_owed_0 - amount_0_collect;
+            position.tokens_owed_1 = tokens_owed_1 - amount_1_collect;
+            self.positions.write(token_id, position);
+
+            self
+                .emit(
+                    Collect {
+                        token_id,
+                        recipient,
+                        amount_0_collect: amount_0_collect.into(),
+                        amount_1_collect: amount_1_collect.into()
+                    }
+                );
+            (amount_0.into(), amount_1.into())
+        }
+
+        /// @notice Burns a token ID, which deletes it from the NFT contract. The token must have 0 liquidity and all tokens
+        /// must be collected first.
+        /// @param token_id The ID of the token that is being burned
+        fn burn(ref self: ContractState, token_id: u256) {
+            self.check_authorized_for_token(token_id);
+
+            let position = self.positions.read(token_id);
+            assert(
+                position.liquidity == 0
+                    && position.tokens_owed_0 == 0
+                    && position.tokens_owed_1 == 0,
+                'Not cleared'
+            );
+
+            self
+                .positions
+                .write(
+                    token_id,
+                    Position {
+                        operator: contract_address_const::<0>(),
+                        pool_id: 0,
+                        tick_lower: IntegerTrait::<i32>::new(0, false),
+                        tick_upper: IntegerTrait::<i32>::new(0, false),
+                        liquidity: 0,
+                        fee_growth_inside_0_last_X128: 0,
+                        fee_growth_inside_1_last_X128: 0,
+                        tokens_owed_0: 0,
+                        tokens_owed_1: 0
+                    }
+                );
+
+            let mut state = ERC721::unsafe_new_contract_state();
+            let owner = ERC721::InternalImpl::_owner_of(@state, token_id);
+            self.remove_token_from_owner_enumeration(owner, token_id);
+            self.remove_token_from_all_tokens_enumeration(token_id);
+            ERC721::InternalImpl::_burn(ref state, token_id);
+        }
+
         fn create_and_initialize_pool_if_necessary(
             ref self: ContractState,
             token_0: ContractAddress,
@@ -401,6 +684,29 @@ mod YASNFTPositionManager {
             }
         }
 
+        /// @notice Calls several entry points of this contract in a single transaction
+        /// @dev Each call is encoded as [selector, ...calldata] and is executed in place, so the
+        /// caller stays the same for every call. Reverts if any of the calls reverts
+        /// @param calls The encoded calls, see `Selectors` for the entry points that can be batched
+        /// @return results The serialized return value of each call
+        fn multicall(
+            ref self: ContractState, calls: Array<Span<felt252>>
+        ) -> Array<Span<felt252>> {
+            let mut calls = calls.span();
+            let mut results = array![];
+            loop {
+                match calls.pop_front() {
+                    Option::Some(call) => {
+                        results.append(self.execute_call(*call));
+                    },
+                    Option::None => {
+                        break;
+                    }
+                };
+            };
+            results
+        }
+
         // ERC721
         fn supports_interface(self: @ContractState, interface_id: felt252) -> bool {
             let state = ERC721::unsafe_new_contract_state();
@@ -417,11 +723,25 @@ mod YASNFTPositionManager {
             ERC721::ERC721MetadataImpl::symbol(@state)
         }
 
-        fn token_uri(self: @ContractState, token_id: u256) -> felt252 {
+        /// @notice Returns a data URI holding the JSON metadata of the position, split in short strings
+        /// @dev The metadata is the concatenation of every element of the returned array
+        fn token_uri(self: @ContractState, token_id: u256) -> Array<felt252> {
             let state = ERC721::unsafe_new_contract_state();
             assert(ERC721::InternalImpl::_exists(@state, token_id), 'ERC721: invalid token ID');
-            // TODO: url
-            1
+
+            let (position, pool_key) = self.positions(token_id);
+            NFTDescriptor::construct_token_uri(
+                ConstructTokenURIParams {
+                    token_id,
+                    pool: self.get_pool(pool_key).contract_address,
+                    token_0: pool_key.token_0,
+                    token_1: pool_key.token_1,
+                    fee: pool_key.fee,
+                    tick_lower: position.tick_lower,
+                    tick_upper: position.tick_upper,
+                    liquidity: position.liquidity
+                }
+            )
         }
 
         fn balance_of(self: @ContractState, account: ContractAddress) -> u256 {
@@ -437,8 +757,15 @@ mod YASNFTPositionManager {
         fn transfer_from(
             ref self: ContractState, from: ContractAddress, to: ContractAddress, token_id: u256
         ) {
+            assert(
+                self.is_approved_or_owner(get_caller_address(), token_id),
+                ERC721::Errors::UNAUTHORIZED
+            );
+            self.update_owner_enumeration(from, to, token_id);
+            // the operator of the previous owner can't manage the position anymore
+            self.approve_operator(from, contract_address_const::<0>(), token_id);
             let mut state = ERC721::unsafe_new_contract_state();
-            ERC721::ERC721Impl::transfer_from(ref state, from, to, token_id);
+            ERC721::InternalImpl::_transfer(ref state, from, to, token_id);
         }
 
         fn safe_transfer_from(
@@ -448,8 +775,14 @@ mod YASNFTPositionManager {
             token_id: u256,
             data: Span<felt252>
         ) {
+            assert(
+                self.is_approved_or_owner(get_caller_address(), token_id),
+                ERC721::Errors::UNAUTHORIZED
+            );
+            self.update_owner_enumeration(from, to, token_id);
+            self.approve_operator(from, contract_address_const::<0>(), token_id);
             let mut state = ERC721::unsafe_new_contract_state();
-            ERC721::ERC721Impl::safe_transfer_from(ref state, from, to, token_id, data);
+            ERC721::InternalImpl::_safe_transfer(ref state, from, to, token_id, data);
         }
 
         fn approve(ref self: ContractState, to: ContractAddress, token_id: u256) {
@@ -462,11 +795,7 @@ mod YASNFTPositionManager {
                 ERC721::Errors::UNAUTHORIZED
             );
 
-            let mut position = self.positions.read(token_id);
-            position.operator = to;
-            self.positions.write(token_id, position);
-
-            self.emit(Approval { owner, to, token_id });
+            self.approve_operator(owner, to, token_id);
         }
 
         fn set_approval_for_all(
@@ -488,11 +817,271 @@ mod YASNFTPositionManager {
             let state = ERC721::unsafe_new_contract_state();
             ERC721::ERC721Impl::is_approved_for_all(@state, owner, operator)
         }
+
+        // ERC721 Enumerable
+        /// @notice Returns the number of positions currently minted and not burned
+        fn total_supply(self: @ContractState) -> u256 {
+            self.all_tokens_len.read()
+        }
+
+        /// @notice Returns the token ID stored at `index` among all the existing positions
+        /// @dev The order of the tokens changes when a token is burned
+        fn token_by_index(self: @ContractState, index: u256) -> u256 {
+            assert(index < self.total_supply(), 'ERC721: index out of bounds');
+            self.all_tokens.read(index)
+        }
+
+        /// @notice Returns the token ID stored at `index` among the positions owned by `owner`
+        /// @dev The order of the tokens changes when a token of `owner` is transferred or burned
+        fn token_of_owner_by_index(
+            self: @ContractState, owner: ContractAddress, index: u256
+        ) -> u256 {
+            assert(index < self.balance_of(owner), 'ERC721: index out of bounds');
+            self.owned_tokens.read((owner, index))
+        }
+
+        // ERC721 Permit
+        /// @notice Returns the nonce the next permit of `token_id` must be signed with
+        fn nonces(self: @ContractState, token_id: u256) -> felt252 {
+            self.permit_nonces.read(token_id)
+        }
+
+        /// @notice Approves `spender` to manage and transfer `token_id` with a signature of its owner
+        /// @dev The owner signs the SNIP-12 typed data
+        /// Permit(spender: felt, token_id: u256, nonce: felt, deadline: u256,
+        /// verifying_contract: felt), in the domain { name: name(), version: '1', chainId }, where
+        /// verifying_contract is the address of this position manager. The signature is checked
+        /// by the owner account through `is_valid_signature`, and can only be used once
+        /// @param spender The account that is approved
+        /// @param token_id The ID of the token that is approved for spending
+        /// @param deadline The timestamp after which the signature is no longer valid
+        /// @param signature The signature of the owner, in the format expected by its account
+        fn permit(
+            ref self: ContractState,
+            spender: ContractAddress,
+            token_id: u256,
+            deadline: u256,
+            signature: Array<felt252>
+        ) {
+            let block_timestamp: u256 = get_block_timestamp().into();
+            assert(block_timestamp <= deadline, 'Permit expired');
+
+            let state = ERC721::unsafe_new_contract_state();
+            let owner = ERC721::InternalImpl::_owner_of(@state, token_id);
+            assert(spender != owner, 'Approval to current owner');
+
+            let nonce = self.permit_nonces.read(token_id);
+            self.permit_nonces.write(token_id, nonce + 1);
+
+            let hash = self.permit_message_hash(owner, spender, token_id, nonce, deadline);
+            assert(is_valid_signature(owner, hash, signature), 'Invalid signature');
+
+            self.approve_operator(owner, spender, token_id);
+        }
     }
 
     #[generate_trait]
     impl InternalFunctions of InternalFunctionsTrait {
-        // @dev Caches a pool key
+        /// @dev Decodes a multicall entry and runs the entry point it selects
+        fn execute_call(ref self: ContractState, call: Span<felt252>) -> Span<felt252> {
+            let mut calldata = call;
+            let selector = *calldata.pop_front().expect(Errors::INVALID_CALLDATA);
+
+            let mut result = array![];
+            if selector == Selectors::CREATE_AND_INITIALIZE_POOL_IF_NECESSARY {
+                let token_0 = Serde::<ContractAddress>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let token_1 = Serde::<ContractAddress>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let fee = Serde::<u32>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let sqrt_price_X96 = Serde::<FixedType>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let pool = self
+                    .create_and_initialize_pool_if_necessary(token_0, token_1, fee, sqrt_price_X96);
+                Serde::serialize(@pool, ref result);
+            } else if selector == Selectors::MINT {
+                let params = Serde::<MintParams>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                Serde::serialize(@self.mint(params), ref result);
+            } else if selector == Selectors::INCREASE_LIQUIDITY {
+                let params = Serde::<IncreaseLiquidityParams>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                Serde::serialize(@self.increase_liquidity(params), ref result);
+            } else if selector == Selectors::DECREASE_LIQUIDITY {
+                let params = Serde::<DecreaseLiquidityParams>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                Serde::serialize(@self.decrease_liquidity(params), ref result);
+            } else if selector == Selectors::COLLECT {
+                let token_id = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let recipient = Serde::<ContractAddress>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let amount_0_max = Serde::<u128>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let amount_1_max = Serde::<u128>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                Serde::serialize(
+                    @self.collect(token_id, recipient, amount_0_max, amount_1_max), ref result
+                );
+            } else if selector == Selectors::BURN {
+                let token_id = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                self.burn(token_id);
+            } else if selector == Selectors::PERMIT {
+                let spender = Serde::<ContractAddress>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let token_id = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let deadline = Serde::<u256>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                let signature = Serde::<Array<felt252>>::deserialize(ref calldata)
+                    .expect(Errors::INVALID_CALLDATA);
+                self.permit(spender, token_id, deadline, signature);
+            } else {
+                panic_with_felt252(Errors::UNSUPPORTED_SELECTOR);
+            }
+
+            assert(calldata.is_empty(), Errors::INVALID_CALLDATA);
+            result.span()
+        }
+
+        /// @dev Asserts that the caller is the owner of the token or is approved to manage it
+        fn check_authorized_for_token(self: @ContractState, token_id: u256) {
+            assert(self.is_approved_or_owner(get_caller_address(), token_id), 'Not approved');
+        }
+
+        /// @dev Returns whether `spender` owns token_id, is its operator or is an operator of all
+        /// the tokens of its owner. The position operator is the only per token approval, it
+        /// authorizes both managing the position and transferring the token
+        fn is_approved_or_owner(
+            self: @ContractState, spender: ContractAddress, token_id: u256
+        ) -> bool {
+            let state = ERC721::unsafe_new_contract_state();
+            let owner = ERC721::InternalImpl::_owner_of(@state, token_id);
+            owner == spender
+                || self.positions.read(token_id).operator == spender
+                || ERC721::ERC721Impl::is_approved_for_all(@state, owner, spender)
+        }
+
+        /// @dev Sets `to` as the operator of token_id, which must be owned by `owner`
+        fn approve_operator(
+            ref self: ContractState, owner: ContractAddress, to: ContractAddress, token_id: u256
+        ) {
+            let mut position = self.positions.read(token_id);
+            position.operator = to;
+            self.positions.write(token_id, position);
+
+            self.emit(Approval { owner, to, token_id });
+        }
+
+        /// @dev Returns the SNIP-12 message hash `owner` signs to permit `spender` on token_id
+        fn permit_message_hash(
+            self: @ContractState,
+            owner: ContractAddress,
+            spender: ContractAddress,
+            token_id: u256,
+            nonce: felt252,
+            deadline: u256
+        ) -> felt252 {
+            let domain = StarknetDomain {
+                name: self.name(), version: PERMIT_VERSION, chain_id: get_tx_info().unbox().chain_id
+            };
+            let struct_hash = compute_hash_on_elements(
+                array![
+                    PERMIT_TYPE_HASH,
+                    spender.into(),
+                    hash_u256(token_id),
+                    nonce,
+                    hash_u256(deadline),
+                    get_contract_address().into()
+                ]
+                    .span()
+            );
+            hash_message(domain, owner, struct_hash)
+        }
+
+        /// @dev Moves token_id between the owner enumerations. Must be called before the ERC721
+        /// transfer, which checks that `from` owns the token and reverts everything otherwise
+        fn update_owner_enumeration(
+            ref self: ContractState, from: ContractAddress, to: ContractAddress, token_id: u256
+        ) {
+            let state = ERC721::unsafe_new_contract_state();
+            let owner = ERC721::InternalImpl::_owner_of(@state, token_id);
+            if owner == from && from != to {
+                self.remove_token_from_owner_enumeration(from, token_id);
+                self.add_token_to_owner_enumeration(to, token_id);
+            }
+        }
+
+        /// @dev Appends token_id to the tokens of `to`. Must be called before the balance of `to`
+        /// is increased
+        fn add_token_to_owner_enumeration(
+            ref self: ContractState, to: ContractAddress, token_id: u256
+        ) {
+            let state = ERC721::unsafe_new_contract_state();
+            let length = ERC721::ERC721Impl::balance_of(@state, to);
+            self.owned_tokens.write((to, length), token_id);
+            self.owned_tokens_index.write(token_id, length);
+        }
+
+        /// @dev Removes token_id from the tokens of `from` by swapping it with the last one. Must
+        /// be called before the balance of `from` is decreased
+        fn remove_token_from_owner_enumeration(
+            ref self: ContractState, from: ContractAddress, token_id: u256
+        ) {
+            let state = ERC721::unsafe_new_contract_state();
+            let last_token_index = ERC721::ERC721Impl::balance_of(@state, from) - 1;
+            let token_index = self.owned_tokens_index.read(token_id);
+
+            if token_index != last_token_index {
+                let last_token_id = self.owned_tokens.read((from, last_token_index));
+                self.owned_tokens.write((from, token_index), last_token_id);
+                self.owned_tokens_index.write(last_token_id, token_index);
+            }
+
+            self.owned_tokens.write((from, last_token_index), 0);
+            self.owned_tokens_index.write(token_id, 0);
+        }
+
+        /// @dev Appends token_id to the list of all the existing tokens
+        fn add_token_to_all_tokens_enumeration(ref self: ContractState, token_id: u256) {
+            let length = self.all_tokens_len.read();
+            self.all_tokens.write(length, token_id);
+            self.all_tokens_index.write(token_id, length);
+            self.all_tokens_len.write(length + 1);
+        }
+
+        /// @dev Removes token_id from the list of all the existing tokens by swapping it with the
+        /// last one
+        fn remove_token_from_all_tokens_enumeration(ref self: ContractState, token_id: u256) {
+            let last_token_index = self.all_tokens_len.read() - 1;
+            let token_index = self.all_tokens_index.read(token_id);
+
+            let last_token_id = self.all_tokens.read(last_token_index);
+            self.all_tokens.write(token_index, last_token_id);
+            self.all_tokens_index.write(last_token_id, token_index);
+
+            self.all_tokens.write(last_token_index, 0);
+            self.all_tokens_index.write(token_id, 0);
+            self.all_tokens_len.write(last_token_index);
+        }
+
+        /// @dev Asserts that the transaction is included before the deadline
+        fn check_deadline(self: @ContractState, deadline: u256) {
+            let block_timestamp: u256 = get_block_timestamp().into();
+            assert(block_timestamp <= deadline, 'Transaction too old');
+        }
+
+        /// @dev Returns the pool for the given pool key
+        fn get_pool(self: @ContractState, pool_key: PoolKey) -> IYASPoolDispatcher {
+            IYASPoolDispatcher {
+                contract_address: IYASFactoryDispatcher { contract_address: self.factory.read() }
+                    .pool(pool_key.token_0, pool_key.token_1, pool_key.fee)
+            }
+        }
+
+        /// @dev Caches a pool key
         fn cache_pool_key(
             ref self: ContractState, pool: ContractAddress, pool_key: PoolKey
         ) -> u128 {
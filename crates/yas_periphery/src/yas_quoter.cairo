@@ -0,0 +1,375 @@
+use starknet::ContractAddress;
+
+use yas_core::numbers::fixed_point::implementations::impl_64x96::FixedType;
+use yas_core::numbers::signed_integer::i256::i256;
+
+#[derive(Copy, Drop, Serde)]
+struct QuoteExactInputSingleParams {
+    token_in: ContractAddress,
+    token_out: ContractAddress,
+    fee: u32,
+    amount_in: u256,
+    sqrt_price_limit_X96: FixedType
+}
+
+#[derive(Copy, Drop, Serde)]
+struct QuoteExactOutputSingleParams {
+    token_in: ContractAddress,
+    token_out: ContractAddress,
+    fee: u32,
+    amount: u256,
+    sqrt_price_limit_X96: FixedType
+}
+
+/// @notice Allows getting the expected amount out or amount in for a given swap without executing the swap
+/// @dev The swaps are simulated from the state the pools expose through their view functions, so
+/// the quotes can be computed with an RPC `call` as well as from another contract.
+/// The simulation must be kept in sync with `YASPool::swap`.
+#[starknet::interface]
+trait IYASQuoter<TContractState> {
+    fn quote_exact_input_single(
+        self: @TContractState, params: QuoteExactInputSingleParams
+    ) -> (u256, FixedType, u32);
+    fn quote_exact_output_single(
+        self: @TContractState, params: QuoteExactOutputSingleParams
+    ) -> (u256, FixedType, u32);
+    fn quote_exact_input(
+        self: @TContractState, path: Array<felt252>, amount_in: u256
+    ) -> (u256, Array<FixedType>, Array<u32>);
+    fn quote_exact_output(
+        self: @TContractState, path: Array<felt252>, amount_out: u256
+    ) -> (u256, Array<FixedType>, Array<u32>);
+    fn get_factory(self: @TContractState) -> ContractAddress;
+}
+
+#[starknet::contract]
+mod YASQuoter {
+    use super::{IYASQuoter, QuoteExactInputSingleParams, QuoteExactOutputSingleParams};
+
+    use starknet::ContractAddress;
+
+    use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
+    use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+    use yas_core::libraries::liquidity_math::LiquidityMath;
+    use yas_core::libraries::path::Path;
+    use yas_core::libraries::swap_math::SwapMath;
+    use yas_core::libraries::tick_bitmap::TickBitmap::{
+        word_to_search, next_initialized_tick_in_word
+    };
+    use yas_core::libraries::tick_math::TickMath::{
+        get_sqrt_ratio_at_tick, MAX_SQRT_RATIO, MIN_SQRT_RATIO, MAX_TICK, MIN_TICK
+    };
+    use yas_core::numbers::fixed_point::implementations::impl_64x96::{
+        FixedType, FP64x96Impl, FP64x96PartialEq, FP64x96PartialOrd, FP64x96Zeroable
+    };
+    use yas_core::numbers::signed_integer::{i32::i32, i256::i256, integer_trait::IntegerTrait};
+    use yas_core::utils::utils::ContractAddressPartialOrd;
+
+    mod Errors {
+        const AMOUNT_SPECIFIED_ZERO: felt252 = 'AS';
+        const INVALID_SQRT_PRICE_LIMIT: felt252 = 'SPL';
+        const POOL_PAUSED: felt252 = 'paused';
+        const ZERO_LIQUIDITY_SWAP: felt252 = 'both amount deltas are negative';
+        const INVALID_AMOUNT_OUT: felt252 = 'invalid amount out';
+    }
+
+    // the result of a simulated swap
+    #[derive(Copy, Drop, Serde)]
+    struct QuoteResult {
+        amount_0: i256,
+        amount_1: i256,
+        sqrt_price_X96_after: FixedType,
+        initialized_ticks_crossed: u32
+    }
+
+    #[storage]
+    struct Storage {
+        factory: ContractAddress
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, factory: ContractAddress) {
+        self.factory.write(factory);
+    }
+
+    #[external(v0)]
+    impl YASQuoterImpl of IYASQuoter<ContractState> {
+        /// @notice Returns the amount out received for a given exact input but for a swap of a single pool
+        /// @param params The params for the quote, encoded as `QuoteExactInputSingleParams`
+        /// @return amount_out The amount of `token_out` that would be received
+        /// @return sqrt_price_X96_after The sqrt price of the pool after the swap
+        /// @return initialized_ticks_crossed The number of initialized ticks that the swap crossed
+        fn quote_exact_input_single(
+            self: @ContractState, params: QuoteExactInputSingleParams
+        ) -> (u256, FixedType, u32) {
+            let zero_for_one = params.token_in < params.token_out;
+
+            let pool = self.get_pool(params.token_in, params.token_out, params.fee);
+            let result = quote(
+                pool,
+                zero_for_one,
+                IntegerTrait::<i256>::new(params.amount_in, false),
+                params.sqrt_price_limit_X96
+            );
+
+            let amount_out = if zero_for_one {
+                result.amount_1.mag
+            } else {
+                result.amount_0.mag
+            };
+            (amount_out, result.sqrt_price_X96_after, result.initialized_ticks_crossed)
+        }
+
+        /// @notice Returns the amount in required to receive the given exact output amount but for a swap of a single pool
+        /// @param params The params for the quote, encoded as `QuoteExactOutputSingleParams`
+        /// @return amount_in The amount of `token_in` that would be required
+        /// @return sqrt_price_X96_after The sqrt price of the pool after the swap
+        /// @return initialized_ticks_crossed The number of initialized ticks that the swap crossed
+        fn quote_exact_output_single(
+            self: @ContractState, params: QuoteExactOutputSingleParams
+        ) -> (u256, FixedType, u32) {
+            let zero_for_one = params.token_in < params.token_out;
+
+            let pool = self.get_pool(params.token_in, params.token_out, params.fee);
+            let result = quote(
+                pool,
+                zero_for_one,
+                IntegerTrait::<i256>::new(params.amount, true),
+                params.sqrt_price_limit_X96
+            );
+
+            let (amount_in, amount_received) = if zero_for_one {
+                (result.amount_0.mag, result.amount_1.mag)
+            } else {
+                (result.amount_1.mag, result.amount_0.mag)
+            };
+            // it's technically possible to not receive the full output amount,
+            // so if no price limit has been specified, require this possibility away
+            if params.sqrt_price_limit_X96.is_zero() {
+                assert(amount_received == params.amount, Errors::INVALID_AMOUNT_OUT);
+            }
+            (amount_in, result.sqrt_price_X96_after, result.initialized_ticks_crossed)
+        }
+
+        /// @notice Returns the amount out received for a given exact input swap without executing the swap
+        /// @param path The encoded path [token_in, fee, token, fee, ..., token_out]
+        /// @param amount_in The amount of the first token to swap
+        /// @return amount_out The amount of the last token that would be received
+        /// @return sqrt_price_X96_after_list List of the sqrt price after the swap for each pool in the path
+        /// @return initialized_ticks_crossed_list List of the initialized ticks that the swap crossed for each pool in the path
+        fn quote_exact_input(
+            self: @ContractState, path: Array<felt252>, amount_in: u256
+        ) -> (u256, Array<FixedType>, Array<u32>) {
+            let mut path = path.span();
+            let mut amount_in = amount_in;
+            let mut sqrt_price_X96_after_list = array![];
+            let mut initialized_ticks_crossed_list = array![];
+
+            loop {
+                let (token_in, token_out, fee) = Path::decode_first_pool(path);
+
+                let (amount_out, sqrt_price_X96_after, initialized_ticks_crossed) = self
+                    .quote_exact_input_single(
+                        QuoteExactInputSingleParams {
+                            token_in,
+                            token_out,
+                            fee,
+                            amount_in,
+                            sqrt_price_limit_X96: Zeroable::zero()
+                        }
+                    );
+                sqrt_price_X96_after_list.append(sqrt_price_X96_after);
+                initialized_ticks_crossed_list.append(initialized_ticks_crossed);
+
+                // the output of the prior swap becomes the input to the next one
+                amount_in = amount_out;
+
+                if !Path::has_multiple_pools(path) {
+                    break;
+                }
+                path = Path::skip_token(path);
+            };
+
+            (amount_in, sqrt_price_X96_after_list, initialized_ticks_crossed_list)
+        }
+
+        /// @notice Returns the amount in required for a given exact output swap without executing the swap
+        /// @param path The encoded path in reverse order [token_out, fee, token, fee, ..., token_in]
+        /// @param amount_out The amount of the first token of the path to receive
+        /// @return amount_in The amount of the last token of the path that would be required
+        /// @return sqrt_price_X96_after_list List of the sqrt price after the swap for each pool in the path
+        /// @return initialized_ticks_crossed_list List of the initialized ticks that the swap crossed for each pool in the path
+        fn quote_exact_output(
+            self: @ContractState, path: Array<felt252>, amount_out: u256
+        ) -> (u256, Array<FixedType>, Array<u32>) {
+            let mut path = path.span();
+            let mut amount_out = amount_out;
+            let mut sqrt_price_X96_after_list = array![];
+            let mut initialized_ticks_crossed_list = array![];
+
+            loop {
+                let (token_out, token_in, fee) = Path::decode_first_pool(path);
+
+                let (amount_in, sqrt_price_X96_after, initialized_ticks_crossed) = self
+                    .quote_exact_output_single(
+                        QuoteExactOutputSingleParams {
+                            token_in,
+                            token_out,
+                            fee,
+                            amount: amount_out,
+                            sqrt_price_limit_X96: Zeroable::zero()
+                        }
+                    );
+                sqrt_price_X96_after_list.append(sqrt_price_X96_after);
+                initialized_ticks_crossed_list.append(initialized_ticks_crossed);
+
+                // the input of the prior swap becomes the output of the next one
+                amount_out = amount_in;
+
+                if !Path::has_multiple_pools(path) {
+                    break;
+                }
+                path = Path::skip_token(path);
+            };
+
+            (amount_out, sqrt_price_X96_after_list, initialized_ticks_crossed_list)
+        }
+
+        fn get_factory(self: @ContractState) -> ContractAddress {
+            self.factory.read()
+        }
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        /// @dev Returns the pool for the given token pair and fee. The pool contract may or may not exist.
+        fn get_pool(
+            self: @ContractState, token_a: ContractAddress, token_b: ContractAddress, fee: u32
+        ) -> IYASPoolDispatcher {
+            IYASPoolDispatcher {
+                contract_address: IYASFactoryDispatcher { contract_address: self.factory.read() }
+                    .pool(token_a, token_b, fee)
+            }
+        }
+    }
+
+    /// @dev Simulates a swap on `pool` from its current state, the same way `YASPool::swap` executes
+    /// it, and returns its amounts as the pool would
+    fn quote(
+        pool: IYASPoolDispatcher,
+        zero_for_one: bool,
+        amount_specified: i256,
+        sqrt_price_limit_X96: FixedType
+    ) -> QuoteResult {
+        assert(amount_specified.is_non_zero(), Errors::AMOUNT_SPECIFIED_ZERO);
+
+        let pool_state = pool.get_pool_state();
+        assert(!pool_state.paused, Errors::POOL_PAUSED);
+
+        let sqrt_price_limit_X96 = if sqrt_price_limit_X96.is_zero() {
+            default_sqrt_price_limit(zero_for_one)
+        } else {
+            sqrt_price_limit_X96
+        };
+        assert(
+            if zero_for_one {
+                sqrt_price_limit_X96 < pool_state.slot_0.sqrt_price_X96
+                    && sqrt_price_limit_X96 > get_sqrt_ratio_at_tick(MIN_TICK())
+            } else {
+                sqrt_price_limit_X96 > pool_state.slot_0.sqrt_price_X96
+                    && sqrt_price_limit_X96 < get_sqrt_ratio_at_tick(MAX_TICK())
+            },
+            Errors::INVALID_SQRT_PRICE_LIMIT
+        );
+
+        let exact_input = amount_specified > Zeroable::zero();
+
+        let mut amount_specified_remaining = amount_specified;
+        let mut amount_calculated: i256 = Zeroable::zero();
+        let mut sqrt_price_X96 = pool_state.slot_0.sqrt_price_X96;
+        let mut tick = pool_state.slot_0.tick;
+        let mut liquidity = pool_state.liquidity;
+        let mut initialized_ticks_crossed: u32 = 0;
+
+        // continue swapping as long as we haven't used the entire input/output and haven't reached the price limit
+        loop {
+            if amount_specified_remaining.is_zero() || sqrt_price_X96 == sqrt_price_limit_X96 {
+                break;
+            }
+
+            let (step_tick_next, step_initialized) = next_initialized_tick_within_one_word(
+                pool, tick, pool_state.tick_spacing, zero_for_one
+            );
+
+            let step = SwapMath::compute_swap_step_to_tick(
+                sqrt_price_X96,
+                tick,
+                step_tick_next,
+                sqrt_price_limit_X96,
+                liquidity,
+                amount_specified_remaining,
+                pool_state.fee,
+                zero_for_one
+            );
+
+            sqrt_price_X96 = step.sqrt_price_X96;
+
+            if exact_input {
+                amount_specified_remaining -=
+                    IntegerTrait::<i256>::new(step.amount_in + step.fee_amount, false);
+                amount_calculated -= step.amount_out.into();
+            } else {
+                amount_specified_remaining += step.amount_out.into();
+                amount_calculated = amount_calculated
+                    + IntegerTrait::<i256>::new(step.amount_in + step.fee_amount, false);
+            };
+
+            // if the tick is initialized, run the tick transition
+            if step.reached_tick_next && step_initialized {
+                let mut liquidity_net = pool.get_tick(step.tick_next).liquidity_net;
+
+                // if we're moving leftward, we interpret liquidityNet as the opposite sign
+                if zero_for_one {
+                    liquidity_net = -liquidity_net;
+                };
+
+                liquidity = LiquidityMath::add_delta(liquidity, liquidity_net);
+                initialized_ticks_crossed += 1;
+            };
+
+            tick = step.tick;
+        };
+
+        let (amount_0, amount_1) = if zero_for_one == exact_input {
+            (amount_specified - amount_specified_remaining, amount_calculated)
+        } else {
+            (amount_calculated, amount_specified - amount_specified_remaining)
+        };
+
+        // swaps entirely within 0-liquidity regions are not supported
+        assert(
+            amount_0 > Zeroable::zero() || amount_1 > Zeroable::zero(), Errors::ZERO_LIQUIDITY_SWAP
+        );
+
+        QuoteResult {
+            amount_0, amount_1, sqrt_price_X96_after: sqrt_price_X96, initialized_ticks_crossed
+        }
+    }
+
+    fn default_sqrt_price_limit(zero_for_one: bool) -> FixedType {
+        if zero_for_one {
+            FP64x96Impl::new(MIN_SQRT_RATIO + 1, false)
+        } else {
+            FP64x96Impl::new(MAX_SQRT_RATIO - 1, false)
+        }
+    }
+
+    /// @dev Same as `TickBitmap::next_initialized_tick_within_one_word`, reading the words of the
+    /// tick bitmap of `pool`
+    fn next_initialized_tick_within_one_word(
+        pool: IYASPoolDispatcher, tick: i32, tick_spacing: i32, lte: bool
+    ) -> (i32, bool) {
+        let word = pool.get_tick_bitmap(word_to_search(tick, tick_spacing, lte));
+        next_initialized_tick_in_word(word, tick, tick_spacing, lte)
+    }
+}
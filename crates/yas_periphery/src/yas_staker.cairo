@@ -0,0 +1,552 @@
+use starknet::ContractAddress;
+
+use yas_core::numbers::signed_integer::i32::i32;
+
+// identifies an incentive program, rewarding the positions of `pool` in `reward_token`
+#[derive(Copy, Drop, Serde)]
+struct IncentiveKey {
+    // the token distributed as reward
+    reward_token: ContractAddress,
+    // the pool whose positions are rewarded
+    pool: ContractAddress,
+    // the time window in which positions earn rewards
+    start_time: u64,
+    end_time: u64,
+    // the address refunded with the rewards left when the incentive ends
+    refundee: ContractAddress
+}
+
+// state of an incentive program
+#[derive(Copy, Drop, Serde, starknet::Store)]
+struct Incentive {
+    // the rewards not yet accrued to any staker
+    total_reward_unclaimed: u256,
+    // the seconds in range, times the liquidity staked, already rewarded, as a Q128.128
+    total_seconds_claimed_X128: u256,
+    number_of_stakes: u32
+}
+
+// a position NFT held by the staker
+#[derive(Copy, Drop, Serde, starknet::Store)]
+struct Deposit {
+    // the address the NFT can be withdrawn by
+    owner: ContractAddress,
+    // the number of incentives the NFT is staked in
+    number_of_stakes: u32,
+    // the tick range of the position
+    tick_lower: i32,
+    tick_upper: i32
+}
+
+// a position NFT staked in an incentive
+#[derive(Copy, Drop, Serde, starknet::Store)]
+struct Stake {
+    // the seconds per liquidity inside the position range when the NFT was staked
+    seconds_per_liquidity_inside_initial_X128: u256,
+    // the liquidity of the position when the NFT was staked, 0 if it is not staked
+    liquidity: u128
+}
+
+#[starknet::interface]
+trait IYASStaker<TContractState> {
+    fn get_factory(self: @TContractState) -> ContractAddress;
+    fn get_position_manager(self: @TContractState) -> ContractAddress;
+    fn get_max_incentive_start_lead_time(self: @TContractState) -> u64;
+    fn get_max_incentive_duration(self: @TContractState) -> u64;
+    fn compute_incentive_id(self: @TContractState, key: IncentiveKey) -> felt252;
+    fn incentives(self: @TContractState, incentive_id: felt252) -> Incentive;
+    fn deposits(self: @TContractState, token_id: u256) -> Deposit;
+    fn stakes(self: @TContractState, token_id: u256, incentive_id: felt252) -> Stake;
+    fn rewards(
+        self: @TContractState, reward_token: ContractAddress, owner: ContractAddress
+    ) -> u256;
+    fn create_incentive(ref self: TContractState, key: IncentiveKey, reward: u256);
+    fn end_incentive(ref self: TContractState, key: IncentiveKey) -> u256;
+    fn deposit_token(ref self: TContractState, token_id: u256);
+    fn transfer_deposit(ref self: TContractState, token_id: u256, to: ContractAddress);
+    fn withdraw_token(ref self: TContractState, token_id: u256, to: ContractAddress);
+    fn stake_token(ref self: TContractState, key: IncentiveKey, token_id: u256);
+    fn unstake_token(ref self: TContractState, key: IncentiveKey, token_id: u256);
+    fn claim_reward(
+        ref self: TContractState,
+        reward_token: ContractAddress,
+        to: ContractAddress,
+        amount_requested: u256
+    ) -> u256;
+    fn get_reward_info(
+        self: @TContractState, key: IncentiveKey, token_id: u256
+    ) -> (u256, u256);
+}
+
+/// Liquidity mining for YAS positions.
+/// Incentive creators deposit reward tokens for a pool and a time window. Position NFTs deposited
+/// in the staker and staked in the incentive share its rewards in proportion to the seconds their
+/// liquidity spent in range, as tracked by the pool seconds per liquidity.
+/// Rewards left by the time not covered by any stake are refunded when the incentive ends.
+#[starknet::contract]
+mod YASStaker {
+    use super::{IYASStaker, IncentiveKey, Incentive, Deposit, Stake};
+
+    use poseidon::poseidon_hash_span;
+    use starknet::{ContractAddress, get_block_timestamp, get_caller_address, get_contract_address};
+
+    use yas_core::contracts::yas_factory::{IYASFactoryDispatcher, IYASFactoryDispatcherTrait};
+    use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+    use yas_core::interfaces::interface_ERC20::{IERC20Dispatcher, IERC20DispatcherTrait};
+    use yas_core::numbers::signed_integer::{i32::i32, integer_trait::IntegerTrait};
+    use yas_core::utils::math_utils::Constants::Q128;
+    use yas_core::utils::math_utils::{FullMath, mod_subtraction};
+    use yas_periphery::yas_nft_position_manager::{
+        IYASNFTPositionManagerDispatcher, IYASNFTPositionManagerDispatcherTrait
+    };
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        IncentiveCreated: IncentiveCreated,
+        IncentiveEnded: IncentiveEnded,
+        DepositTransferred: DepositTransferred,
+        TokenStaked: TokenStaked,
+        TokenUnstaked: TokenUnstaked,
+        RewardClaimed: RewardClaimed
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct IncentiveCreated {
+        reward_token: ContractAddress,
+        pool: ContractAddress,
+        start_time: u64,
+        end_time: u64,
+        refundee: ContractAddress,
+        reward: u256
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct IncentiveEnded {
+        incentive_id: felt252,
+        refund: u256
+    }
+
+    // emitted with a zero old owner on deposit and a zero new owner on withdrawal
+    #[derive(Drop, starknet::Event)]
+    struct DepositTransferred {
+        token_id: u256,
+        old_owner: ContractAddress,
+        new_owner: ContractAddress
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct TokenStaked {
+        token_id: u256,
+        incentive_id: felt252,
+        liquidity: u128
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct TokenUnstaked {
+        token_id: u256,
+        incentive_id: felt252
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct RewardClaimed {
+        to: ContractAddress,
+        reward: u256
+    }
+
+    #[storage]
+    struct Storage {
+        factory: ContractAddress,
+        position_manager: ContractAddress,
+        // the max seconds between the creation of an incentive and its start
+        max_incentive_start_lead_time: u64,
+        // the max seconds an incentive can last
+        max_incentive_duration: u64,
+        incentives: LegacyMap<felt252, Incentive>,
+        deposits: LegacyMap<u256, Deposit>,
+        stakes: LegacyMap<(u256, felt252), Stake>,
+        // (reward token, owner) -> the rewards owner can claim
+        rewards: LegacyMap<(ContractAddress, ContractAddress), u256>
+    }
+
+    #[constructor]
+    fn constructor(
+        ref self: ContractState,
+        factory: ContractAddress,
+        position_manager: ContractAddress,
+        max_incentive_start_lead_time: u64,
+        max_incentive_duration: u64
+    ) {
+        self.factory.write(factory);
+        self.position_manager.write(position_manager);
+        self.max_incentive_start_lead_time.write(max_incentive_start_lead_time);
+        self.max_incentive_duration.write(max_incentive_duration);
+    }
+
+    #[external(v0)]
+    impl YASStakerImpl of IYASStaker<ContractState> {
+        fn get_factory(self: @ContractState) -> ContractAddress {
+            self.factory.read()
+        }
+
+        fn get_position_manager(self: @ContractState) -> ContractAddress {
+            self.position_manager.read()
+        }
+
+        fn get_max_incentive_start_lead_time(self: @ContractState) -> u64 {
+            self.max_incentive_start_lead_time.read()
+        }
+
+        fn get_max_incentive_duration(self: @ContractState) -> u64 {
+            self.max_incentive_duration.read()
+        }
+
+        fn compute_incentive_id(self: @ContractState, key: IncentiveKey) -> felt252 {
+            let mut data = array![];
+            key.serialize(ref data);
+            poseidon_hash_span(data.span())
+        }
+
+        fn incentives(self: @ContractState, incentive_id: felt252) -> Incentive {
+            self.incentives.read(incentive_id)
+        }
+
+        fn deposits(self: @ContractState, token_id: u256) -> Deposit {
+            self.deposits.read(token_id)
+        }
+
+        fn stakes(self: @ContractState, token_id: u256, incentive_id: felt252) -> Stake {
+            self.stakes.read((token_id, incentive_id))
+        }
+
+        fn rewards(
+            self: @ContractState, reward_token: ContractAddress, owner: ContractAddress
+        ) -> u256 {
+            self.rewards.read((reward_token, owner))
+        }
+
+        // Creates an incentive, or adds reward to an existing one, paid by the caller
+        fn create_incentive(ref self: ContractState, key: IncentiveKey, reward: u256) {
+            assert(reward > 0, 'Reward must be positive');
+            let block_timestamp = get_block_timestamp();
+            assert(block_timestamp <= key.start_time, 'Start time in the past');
+            assert(
+                key.start_time - block_timestamp <= self.max_incentive_start_lead_time.read(),
+                'Start time too far in future'
+            );
+            assert(key.start_time < key.end_time, 'Start time after end time');
+            assert(
+                key.end_time - key.start_time <= self.max_incentive_duration.read(),
+                'Incentive duration too long'
+            );
+
+            let incentive_id = self.compute_incentive_id(key);
+            let mut incentive = self.incentives.read(incentive_id);
+            incentive.total_reward_unclaimed += reward;
+            self.incentives.write(incentive_id, incentive);
+
+            IERC20Dispatcher { contract_address: key.reward_token }
+                .transferFrom(get_caller_address(), get_contract_address(), reward);
+
+            self
+                .emit(
+                    IncentiveCreated {
+                        reward_token: key.reward_token,
+                        pool: key.pool,
+                        start_time: key.start_time,
+                        end_time: key.end_time,
+                        refundee: key.refundee,
+                        reward
+                    }
+                );
+        }
+
+        // Refunds the unclaimed rewards of an incentive once it is over and every NFT is unstaked
+        fn end_incentive(ref self: ContractState, key: IncentiveKey) -> u256 {
+            assert(get_block_timestamp() >= key.end_time, 'Cannot end before end time');
+
+            let incentive_id = self.compute_incentive_id(key);
+            let mut incentive = self.incentives.read(incentive_id);
+            let refund = incentive.total_reward_unclaimed;
+            assert(refund > 0, 'No refund available');
+            assert(incentive.number_of_stakes == 0, 'Cannot end while staked');
+
+            // the seconds claimed are kept, they don't matter anymore
+            incentive.total_reward_unclaimed = 0;
+            self.incentives.write(incentive_id, incentive);
+
+            IERC20Dispatcher { contract_address: key.reward_token }.transfer(key.refundee, refund);
+
+            self.emit(IncentiveEnded { incentive_id, refund });
+            refund
+        }
+
+        // Moves a position NFT of the caller to the staker.
+        // The staker must be approved for all the NFTs of the caller
+        fn deposit_token(ref self: ContractState, token_id: u256) {
+            let caller = get_caller_address();
+            let position_manager = self.position_manager_dispatcher();
+            position_manager.transfer_from(caller, get_contract_address(), token_id);
+
+            let (position, _) = position_manager.positions(token_id);
+            self
+                .deposits
+                .write(
+                    token_id,
+                    Deposit {
+                        owner: caller,
+                        number_of_stakes: 0,
+                        tick_lower: position.tick_lower,
+                        tick_upper: position.tick_upper
+                    }
+                );
+
+            self
+                .emit(
+                    DepositTransferred {
+                        token_id, old_owner: Zeroable::zero(), new_owner: caller
+                    }
+                );
+        }
+
+        fn transfer_deposit(ref self: ContractState, token_id: u256, to: ContractAddress) {
+            assert(to.is_non_zero(), 'Invalid recipient');
+            let mut deposit = self.deposits.read(token_id);
+            let owner = deposit.owner;
+            assert(owner == get_caller_address(), 'Not the deposit owner');
+
+            deposit.owner = to;
+            self.deposits.write(token_id, deposit);
+
+            self.emit(DepositTransferred { token_id, old_owner: owner, new_owner: to });
+        }
+
+        // Sends a deposited NFT, staked in no incentive, back to `to`
+        fn withdraw_token(ref self: ContractState, token_id: u256, to: ContractAddress) {
+            assert(to != get_contract_address(), 'Cannot withdraw to staker');
+            let deposit = self.deposits.read(token_id);
+            assert(deposit.owner == get_caller_address(), 'Not the deposit owner');
+            assert(deposit.number_of_stakes == 0, 'Cannot withdraw while staked');
+
+            self
+                .deposits
+                .write(
+                    token_id,
+                    Deposit {
+                        owner: Zeroable::zero(),
+                        number_of_stakes: 0,
+                        tick_lower: IntegerTrait::<i32>::new(0, false),
+                        tick_upper: IntegerTrait::<i32>::new(0, false)
+                    }
+                );
+            self
+                .emit(
+                    DepositTransferred {
+                        token_id, old_owner: deposit.owner, new_owner: Zeroable::zero()
+                    }
+                );
+
+            self.position_manager_dispatcher().transfer_from(get_contract_address(), to, token_id);
+        }
+
+        fn stake_token(ref self: ContractState, key: IncentiveKey, token_id: u256) {
+            assert(
+                self.deposits.read(token_id).owner == get_caller_address(), 'Not the deposit owner'
+            );
+            self.stake(key, token_id);
+        }
+
+        // Unstakes an NFT, accruing its rewards to the deposit owner.
+        // Only the owner can unstake before the incentive ends, anyone can afterwards
+        fn unstake_token(ref self: ContractState, key: IncentiveKey, token_id: u256) {
+            let mut deposit = self.deposits.read(token_id);
+            if get_block_timestamp() < key.end_time {
+                assert(deposit.owner == get_caller_address(), 'Only owner can unstake early');
+            }
+
+            let incentive_id = self.compute_incentive_id(key);
+            let stake = self.stakes.read((token_id, incentive_id));
+            assert(stake.liquidity != 0, 'Token not staked');
+
+            let mut incentive = self.incentives.read(incentive_id);
+            let (reward, seconds_inside_X128) = self
+                .reward_info(key, incentive, deposit, stake);
+
+            deposit.number_of_stakes -= 1;
+            self.deposits.write(token_id, deposit);
+
+            incentive.number_of_stakes -= 1;
+            incentive.total_seconds_claimed_X128 += seconds_inside_X128;
+            incentive.total_reward_unclaimed -= reward;
+            self.incentives.write(incentive_id, incentive);
+
+            let owed = self.rewards.read((key.reward_token, deposit.owner));
+            self.rewards.write((key.reward_token, deposit.owner), owed + reward);
+
+            self
+                .stakes
+                .write(
+                    (token_id, incentive_id),
+                    Stake { seconds_per_liquidity_inside_initial_X128: 0, liquidity: 0 }
+                );
+
+            self.emit(TokenUnstaked { token_id, incentive_id });
+        }
+
+        // Transfers up to `amount_requested` of the rewards of the caller to `to`, all of them
+        // if `amount_requested` is 0
+        fn claim_reward(
+            ref self: ContractState,
+            reward_token: ContractAddress,
+            to: ContractAddress,
+            amount_requested: u256
+        ) -> u256 {
+            let caller = get_caller_address();
+            let owed = self.rewards.read((reward_token, caller));
+            let reward = if amount_requested != 0 && amount_requested < owed {
+                amount_requested
+            } else {
+                owed
+            };
+
+            self.rewards.write((reward_token, caller), owed - reward);
+            IERC20Dispatcher { contract_address: reward_token }.transfer(to, reward);
+
+            self.emit(RewardClaimed { to, reward });
+            reward
+        }
+
+        // Returns the reward a staked NFT would accrue if it was unstaked now, and the seconds
+        // its liquidity spent in range since it was staked, as a Q128.128
+        fn get_reward_info(
+            self: @ContractState, key: IncentiveKey, token_id: u256
+        ) -> (u256, u256) {
+            let incentive_id = self.compute_incentive_id(key);
+            let stake = self.stakes.read((token_id, incentive_id));
+            assert(stake.liquidity != 0, 'Token not staked');
+
+            self
+                .reward_info(
+                    key, self.incentives.read(incentive_id), self.deposits.read(token_id), stake
+                )
+        }
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn stake(ref self: ContractState, key: IncentiveKey, token_id: u256) {
+            let block_timestamp = get_block_timestamp();
+            assert(block_timestamp >= key.start_time, 'Incentive not started');
+            assert(block_timestamp < key.end_time, 'Incentive ended');
+
+            let incentive_id = self.compute_incentive_id(key);
+            let mut incentive = self.incentives.read(incentive_id);
+            assert(incentive.total_reward_unclaimed > 0, 'Incentive does not exist');
+            let stake = self.stakes.read((token_id, incentive_id));
+            assert(stake.liquidity == 0, 'Token already staked');
+
+            let (position, pool_key) = self.position_manager_dispatcher().positions(token_id);
+            let pool = IYASFactoryDispatcher { contract_address: self.factory.read() }
+                .pool(pool_key.token_0, pool_key.token_1, pool_key.fee);
+            assert(pool == key.pool, 'Token pool is not incentivized');
+            assert(position.liquidity > 0, 'Cannot stake without liquidity');
+
+            let mut deposit = self.deposits.read(token_id);
+            deposit.number_of_stakes += 1;
+            self.deposits.write(token_id, deposit);
+
+            incentive.number_of_stakes += 1;
+            self.incentives.write(incentive_id, incentive);
+
+            // reverts for pools initialized before they tracked seconds per liquidity
+            let pool_dispatcher = IYASPoolDispatcher { contract_address: pool };
+            let seconds_per_liquidity_inside_initial_X128 = pool_dispatcher
+                .get_seconds_per_liquidity_inside(position.tick_lower, position.tick_upper);
+            self
+                .stakes
+                .write(
+                    (token_id, incentive_id),
+                    Stake {
+                        seconds_per_liquidity_inside_initial_X128, liquidity: position.liquidity
+                    }
+                );
+
+            self.emit(TokenStaked { token_id, incentive_id, liquidity: position.liquidity });
+        }
+
+        fn reward_info(
+            self: @ContractState,
+            key: IncentiveKey,
+            incentive: Incentive,
+            deposit: Deposit,
+            stake: Stake
+        ) -> (u256, u256) {
+            let pool = IYASPoolDispatcher { contract_address: key.pool };
+            let seconds_per_liquidity_inside_X128 = pool
+                .get_seconds_per_liquidity_inside(deposit.tick_lower, deposit.tick_upper);
+
+            compute_reward_amount(
+                incentive.total_reward_unclaimed,
+                incentive.total_seconds_claimed_X128,
+                key.start_time,
+                key.end_time,
+                stake.liquidity,
+                stake.seconds_per_liquidity_inside_initial_X128,
+                seconds_per_liquidity_inside_X128,
+                get_block_timestamp()
+            )
+        }
+
+        fn position_manager_dispatcher(self: @ContractState) -> IYASNFTPositionManagerDispatcher {
+            IYASNFTPositionManagerDispatcher { contract_address: self.position_manager.read() }
+        }
+    }
+
+    /// @notice Computes the reward owed to a stake
+    /// @dev The unclaimed reward is shared over the seconds of the incentive not claimed yet, the
+    /// ones elapsed after the end included, so stakes left after the end keep a fair share
+    /// @param total_reward_unclaimed The reward of the incentive not accrued to any staker yet
+    /// @param total_seconds_claimed_X128 The seconds times liquidity already rewarded, as a Q128.128
+    /// @param start_time When the incentive started
+    /// @param end_time When the incentive ends
+    /// @param liquidity The liquidity of the stake
+    /// @param seconds_per_liquidity_inside_initial_X128 The seconds per liquidity inside the position
+    /// range when it was staked
+    /// @param seconds_per_liquidity_inside_X128 The current seconds per liquidity inside the range
+    /// @param current_time The current block timestamp
+    /// @return reward The reward owed to the stake
+    /// @return seconds_inside_X128 The seconds the liquidity of the stake spent in range, times the
+    /// liquidity, as a Q128.128
+    fn compute_reward_amount(
+        total_reward_unclaimed: u256,
+        total_seconds_claimed_X128: u256,
+        start_time: u64,
+        end_time: u64,
+        liquidity: u128,
+        seconds_per_liquidity_inside_initial_X128: u256,
+        seconds_per_liquidity_inside_X128: u256,
+        current_time: u64
+    ) -> (u256, u256) {
+        // stakes are only created after the start of the incentive
+        assert(current_time >= start_time, 'Incentive not started');
+
+        let liquidity: u256 = liquidity.into();
+        let seconds_inside_X128 = mod_subtraction(
+            seconds_per_liquidity_inside_X128, seconds_per_liquidity_inside_initial_X128
+        )
+            * liquidity;
+
+        let last_time = if current_time > end_time {
+            current_time
+        } else {
+            end_time
+        };
+        let total_seconds: u256 = (last_time - start_time).into();
+        let total_seconds_unclaimed_X128 = total_seconds * Q128 - total_seconds_claimed_X128;
+
+        let reward = FullMath::mul_div(
+            total_reward_unclaimed, seconds_inside_X128, total_seconds_unclaimed_X128
+        );
+        (reward, seconds_inside_X128)
+    }
+}
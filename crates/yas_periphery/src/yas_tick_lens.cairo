@@ -0,0 +1,137 @@
+use starknet::ContractAddress;
+
+use yas_core::numbers::signed_integer::{i16::i16, i32::i32, i128::i128};
+
+#[derive(Copy, Drop, Serde)]
+struct PopulatedTick {
+    tick: i32,
+    liquidity_net: i128,
+    liquidity_gross: u128
+}
+
+/// @notice Provides functions for fetching chunks of tick data for a pool
+/// @dev This avoids the waterfall of fetching the tick bitmap, parsing the bitmap to know which ticks to fetch, and
+/// then sending a separate call for each tick
+#[starknet::interface]
+trait IYASTickLens<TContractState> {
+    fn get_populated_ticks_in_word(
+        self: @TContractState, pool: ContractAddress, word_pos: i16
+    ) -> Array<PopulatedTick>;
+    fn get_populated_ticks_in_range(
+        self: @TContractState, pool: ContractAddress, tick_lower: i32, tick_upper: i32
+    ) -> Array<PopulatedTick>;
+}
+
+#[starknet::contract]
+mod YASTickLens {
+    use super::{IYASTickLens, PopulatedTick};
+
+    use starknet::ContractAddress;
+
+    use yas_core::contracts::yas_pool::{IYASPoolDispatcher, IYASPoolDispatcherTrait};
+    use yas_core::libraries::tick_bitmap::TickBitmap::position;
+    use yas_core::libraries::tick_math::TickMath::{MIN_TICK, MAX_TICK};
+    use yas_core::numbers::signed_integer::{
+        i16::i16, i32::{i32, i32_div_no_round, u8Intoi32}, integer_trait::IntegerTrait
+    };
+    use yas_core::utils::math_utils::BitShift::BitShiftTrait;
+
+    mod Errors {
+        const INVALID_TICK_RANGE: felt252 = 'invalid tick range';
+    }
+
+    #[storage]
+    struct Storage {}
+
+    #[external(v0)]
+    impl YASTickLensImpl of IYASTickLens<ContractState> {
+        /// @notice Get all the tick data for the populated ticks from a word of the tick bitmap of a pool
+        /// @param pool The address of the pool for which to fetch populated tick data
+        /// @param word_pos The index of the word in the tick bitmap for which to parse the bitmap and
+        /// fetch all the populated ticks, as returned by `TickBitmap::position`
+        /// @return populated_ticks An array of tick data for the given word in the tick bitmap, sorted
+        /// by ascending tick
+        fn get_populated_ticks_in_word(
+            self: @ContractState, pool: ContractAddress, word_pos: i16
+        ) -> Array<PopulatedTick> {
+            let pool = IYASPoolDispatcher { contract_address: pool };
+            let mut populated_ticks = array![];
+            append_populated_ticks_in_word(
+                ref populated_ticks, pool, pool.get_tick_spacing(), word_pos, MIN_TICK(), MAX_TICK()
+            );
+            populated_ticks
+        }
+
+        /// @notice Get all the tick data for the populated ticks of a pool between two ticks
+        /// @dev Reads every word of the tick bitmap covering the range, so wide ranges on pools with a
+        /// small tick spacing are expensive
+        /// @param pool The address of the pool for which to fetch populated tick data
+        /// @param tick_lower The lowest tick to include
+        /// @param tick_upper The highest tick to include
+        /// @return populated_ticks An array of tick data for the range, sorted by ascending tick
+        fn get_populated_ticks_in_range(
+            self: @ContractState, pool: ContractAddress, tick_lower: i32, tick_upper: i32
+        ) -> Array<PopulatedTick> {
+            assert(tick_lower <= tick_upper, Errors::INVALID_TICK_RANGE);
+
+            let pool = IYASPoolDispatcher { contract_address: pool };
+            let tick_spacing = pool.get_tick_spacing();
+            let (word_pos_lower, _) = position(i32_div_no_round(tick_lower, tick_spacing));
+            let (word_pos_upper, _) = position(i32_div_no_round(tick_upper, tick_spacing));
+
+            let mut populated_ticks = array![];
+            let mut word_pos = word_pos_lower;
+            loop {
+                if word_pos > word_pos_upper {
+                    break;
+                }
+                append_populated_ticks_in_word(
+                    ref populated_ticks, pool, tick_spacing, word_pos, tick_lower, tick_upper
+                );
+                word_pos += IntegerTrait::<i16>::new(1, false);
+            };
+            populated_ticks
+        }
+    }
+
+    // @dev Appends the initialized ticks of a bitmap word that lie in [tick_lower, tick_upper].
+    // Bit `bit_pos` of word `word_pos` flags the tick `(word_pos * 256 + bit_pos) * tick_spacing`
+    fn append_populated_ticks_in_word(
+        ref populated_ticks: Array<PopulatedTick>,
+        pool: IYASPoolDispatcher,
+        tick_spacing: i32,
+        word_pos: i16,
+        tick_lower: i32,
+        tick_upper: i32
+    ) {
+        let mut bitmap = pool.get_tick_bitmap(word_pos);
+        let first_compressed_tick = IntegerTrait::<i32>::new(word_pos.mag.into(), word_pos.sign)
+            * IntegerTrait::<i32>::new(256, false);
+
+        let mut bit_pos: u8 = 0;
+        loop {
+            if bitmap == 0 {
+                break;
+            }
+            if (bitmap & 1) == 1 {
+                let tick = (first_compressed_tick + bit_pos.into()) * tick_spacing;
+                if tick >= tick_lower && tick <= tick_upper {
+                    let info = pool.get_tick(tick);
+                    populated_ticks
+                        .append(
+                            PopulatedTick {
+                                tick,
+                                liquidity_net: info.liquidity_net,
+                                liquidity_gross: info.liquidity_gross
+                            }
+                        );
+                }
+            }
+            bitmap = bitmap.shr(1);
+            // the word is empty once the last bit is shifted out, so bit_pos never overflows
+            if bitmap != 0 {
+                bit_pos += 1;
+            }
+        };
+    }
+}
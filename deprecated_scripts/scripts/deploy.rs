@@ -11,6 +11,7 @@ use starknet::core::types::{BlockId, BlockTag, FieldElement, StarknetError};
 use starknet::providers::jsonrpc::{HttpTransport, JsonRpcClient};
 use starknet::providers::{MaybeUnknownErrorCode, Provider, ProviderError, StarknetErrorWithMessage};
 use starknet::signers::{LocalWallet, SigningKey};
+use yas::pool_address::{compute_pool_address, PoolKey};
 
 const BUILD_PATH_PREFIX: &str = "target/dev/yas_";
 // TODO: Update to New once account contracts are migrated to v1
@@ -19,6 +20,10 @@ const ENCODING: ExecutionEncoding = ExecutionEncoding::Legacy;
 const POSITIVE: bool = false;
 const NEGATIVE: bool = true;
 
+// Medium fee tier, enabled by the factory constructor
+const POOL_FEE: u32 = 3000;
+const POOL_TICK_SPACING: i32 = 60;
+
 /// Create a StarkNet provider.
 /// If the `STARKNET_RPC` environment variable is set, it will be used as the RPC URL.
 /// Otherwise, the default URL will be used.
@@ -128,7 +133,8 @@ async fn declare_contract(
 /// * `name` - The name of the ERC20 token.
 /// * `symbol` - The symbol of the ERC20 token.
 /// * `total_supply` - The total supply of the ERC20 token.
-/// * `recipient` - The initial recipient of the total supply.
+/// * `decimals` - The decimals of the ERC20 token, 18.
+/// * `recipient` - The initial recipient of the total supply, which also owns the token.
 ///
 /// # Returns
 ///
@@ -142,13 +148,13 @@ async fn deploy_erc20(
     let erc20_factory = ContractFactory::new(erc20_class_hash, account);
     let unique = true;
     let salt = account.get_nonce().await?;
-    let erc20_token_0_contract_deployment = erc20_factory.deploy(vec![FieldElement::from_hex_be("0x5459415330").unwrap(), FieldElement::from_hex_be("0x2459415330").unwrap(), FieldElement::from_hex_be("0x3782dace9d900000").unwrap(), FieldElement::ZERO, recipient], salt, unique);
+    let erc20_token_0_contract_deployment = erc20_factory.deploy(vec![FieldElement::from_hex_be("0x5459415330").unwrap(), FieldElement::from_hex_be("0x2459415330").unwrap(), FieldElement::from(18_u8), FieldElement::from_hex_be("0x3782dace9d900000").unwrap(), FieldElement::ZERO, recipient, recipient], salt, unique);
     let erc20_token_0_deployed_address = erc20_token_0_contract_deployment.deployed_address();
     println!("Token TYAS0 Address: {}", format!("{:#064x}", erc20_token_0_deployed_address));
     let estimated_fee = erc20_token_0_contract_deployment.estimate_fee().await?.overall_fee * 3 / 2;
     erc20_token_0_contract_deployment.max_fee(estimated_fee.into()).send().await?.transaction_hash;
 
-    let erc20_token_1_contract_deployment = erc20_factory.deploy(vec![FieldElement::from_hex_be("0x5459415331").unwrap(), FieldElement::from_hex_be("0x2459415331").unwrap(),  FieldElement::from_hex_be("0x3782dace9d900000").unwrap(), FieldElement::ZERO, recipient], salt, unique);
+    let erc20_token_1_contract_deployment = erc20_factory.deploy(vec![FieldElement::from_hex_be("0x5459415331").unwrap(), FieldElement::from_hex_be("0x2459415331").unwrap(), FieldElement::from(18_u8),  FieldElement::from_hex_be("0x3782dace9d900000").unwrap(), FieldElement::ZERO, recipient, recipient], salt, unique);
     let erc20_token_1_deployed_address = erc20_token_1_contract_deployment.deployed_address();
     println!("Token TYAS1 Address: {}", format!("{:#064x}", erc20_token_1_deployed_address));
     let estimated_fee = erc20_token_1_contract_deployment.estimate_fee().await?.overall_fee * 3 / 2;
@@ -157,6 +163,39 @@ async fn deploy_erc20(
     Ok((erc20_token_0_deployed_address, erc20_token_1_deployed_address))
 }
 
+/// Create a YAS pool through the factory, which deploys and registers it.
+///
+/// # Arguments
+///
+/// * `account` - The reference to a `SingleOwnerAccount` with a `JsonRpcClient` and `LocalWallet`.
+/// * `factory_address` - The address of the YAS factory.
+/// * `token_0` - One of the two tokens of the pool.
+/// * `token_1` - The other token of the pool.
+/// * `fee` - The fee tier of the pool, it must be enabled in the factory.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure. The `Ok(())` variant is returned on success, and the `Err` variant contains an error description.
+async fn create_pool(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    factory_address: FieldElement,
+    token_0: FieldElement,
+    token_1: FieldElement,
+    fee: u32,
+) -> Result<()> {
+    let invoke_result = account
+        .execute(vec![Call {
+            to: factory_address,
+            selector: get_selector_from_name("create_pool").unwrap(),
+            calldata: vec![token_0, token_1, FieldElement::from(fee)],
+        }])
+        .send()
+        .await?;
+
+    println!("Transaction Hash: {}", format!("{:#064x}", invoke_result.transaction_hash));
+    Ok(())
+}
+
 /// Asynchronously initializes a liquidity pool using the provided parameters.
 ///
 /// # Arguments
@@ -240,7 +279,7 @@ pub async fn main() -> Result<()> {
     println!("\n==> Deploying Router Contract");
     let salt = account.get_nonce().await?;
     let yas_router_contract_factory = ContractFactory::new(router_class_hash, &account);
-    let contract_deployment = yas_router_contract_factory.deploy(vec![], salt, unique);
+    let contract_deployment = yas_router_contract_factory.deploy(vec![factory_address], salt, unique);
     let router_address = contract_deployment.deployed_address();
     println!("Router Contract Address: {}", format!("{:#064x}", router_address));
 
@@ -249,19 +288,12 @@ pub async fn main() -> Result<()> {
     let tx = contract_deployment.max_fee(estimated_fee.into()).send().await?.transaction_hash;
     println!("Transaction Hash: {}", format!("{:#064x}", tx));
 
-    // Instantiate the contract factory.
-    println!("\n==> Deploying Pool Contract");
-    let salt = account.get_nonce().await?;
-    let yas_pool_contract_factory = ContractFactory::new(pool_class_hash, &account);
-    let contract_deployment = yas_pool_contract_factory.deploy(vec![factory_address, token_0, token_1, FieldElement::from_hex_be("0x0bb8").unwrap(), FieldElement::from_hex_be("0x3c").unwrap(), FieldElement::ZERO], salt, unique);
-    let pool_address = contract_deployment.deployed_address();
+    println!("\n==> Creating Pool");
+    create_pool(&account, factory_address, token_0, token_1, POOL_FEE).await?;
+    let pool_key = PoolKey::new(token_0, token_1, POOL_FEE, POOL_TICK_SPACING);
+    let pool_address = compute_pool_address(factory_address, pool_class_hash, &pool_key);
     println!("Pool Contract Address: {}", format!("{:#064x}", pool_address));
 
-    // Estimate the deployment fee and deploy the contract.
-    let estimated_fee = contract_deployment.estimate_fee().await?.overall_fee * 3 / 2; // add buffer
-    let tx = contract_deployment.max_fee(estimated_fee.into()).send().await?.transaction_hash;
-    println!("Transaction Hash: {}", format!("{:#064x}", tx));
-
     println!("\n==> Initialize Pool");
     initialize_pool(
         &account,
@@ -0,0 +1,524 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::{env, fs};
+
+use dotenv::dotenv;
+use eyre::{eyre, Result};
+use serde_json::{json, Value};
+use starknet::accounts::{Account, Call, ConnectedAccount, ExecutionEncoding, SingleOwnerAccount};
+use starknet::contract::ContractFactory;
+use starknet::core::types::contract::SierraClass;
+use starknet::core::types::{
+    BlockId, BlockTag, FieldElement, FunctionCall, MaybePendingBlockWithTxHashes, StarknetError,
+};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::{HttpTransport, JsonRpcClient};
+use starknet::providers::{MaybeUnknownErrorCode, Provider, ProviderError, StarknetErrorWithMessage};
+use starknet::signers::{LocalWallet, SigningKey};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const BUILD_PATH_PREFIX: &str = "target/dev/yas_faucet_";
+// TODO: Update to New once account contracts are migrated to v1
+const ENCODING: ExecutionEncoding = ExecutionEncoding::Legacy;
+
+const DEFAULT_PORT: u16 = 8080;
+
+const USAGE: &str = "Usage: faucet <command> [ARGS]
+  deploy <TOKEN_ADDRESS> <WITHDRAWAL_AMOUNT> <WAIT_TIME>  Declare and deploy a YASFaucet owned by OWNER_ADDRESS
+  configure <WITHDRAWAL_AMOUNT> <WAIT_TIME>              Set the drip amount and cooldown of the default token
+  fund <AMOUNT>                                          Transfer AMOUNT of the default token to the faucet
+  set-relayer <RELAYER_ADDRESS>                          Allow RELAYER_ADDRESS to drip on behalf of users
+  status [USER_ADDRESS]                                  Show the faucet balance, settings and the user cooldown
+  serve [PORT]                                           Serve `POST /drip/<USER_ADDRESS>` through the relayer
+Every command but deploy targets the faucet at FAUCET_ADDRESS.";
+
+/// Create a StarkNet provider.
+/// If the `STARKNET_RPC` environment variable is set, it will be used as the RPC URL.
+/// Otherwise, the default URL will be used.
+fn jsonrpc_client() -> JsonRpcClient<HttpTransport> {
+    let rpc_url = env::var("STARKNET_RPC").unwrap_or("https://rpc-goerli-1.starknet.rs/rpc/v0.4".into());
+    JsonRpcClient::new(HttpTransport::new(url::Url::parse(&rpc_url).unwrap()))
+}
+
+/// Get the contract artifact from the build directory.
+/// # Arguments
+/// * `contract_name` - The contract name.
+/// # Returns
+/// The contract artifact.
+fn contract_artifact(contract_name: &str) -> Result<SierraClass> {
+    let artifact_path = format!("{BUILD_PATH_PREFIX}{contract_name}.sierra.json");
+    let file = fs::File::open(artifact_path)
+        .unwrap_or_else(|_| panic!("Compiled contract {} not found: run `make build`", contract_name));
+    serde_json::from_reader(file).map_err(Into::into)
+}
+
+/// Fetch the private key from the `PRIVATE_KEY` environment variable or prompt the user for input.
+/// # Returns
+/// The private key.
+fn private_key_from_env_or_input() -> FieldElement {
+    if let Ok(pk) = env::var("PRIVATE_KEY") {
+        FieldElement::from_hex_be(&pk).expect("Invalid Private Key")
+    } else {
+        let input_key = rpassword::prompt_password("Enter private key: ").unwrap();
+        FieldElement::from_hex_be(&input_key).expect("Invalid Private Key")
+    }
+}
+
+/// Initialize a StarkNet account.
+/// # Arguments
+/// * `signer` - The StarkNet signer.
+/// * `account_address` - The StarkNet account address.
+/// # Returns
+/// The StarkNet account.
+async fn initialize_starknet_account(
+    signer: LocalWallet,
+    account_address: FieldElement,
+) -> Result<SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>> {
+    let provider = jsonrpc_client();
+    let chain_id = provider.chain_id().await?;
+    let mut account = SingleOwnerAccount::new(provider, signer, account_address, chain_id, ENCODING);
+    account.set_block_id(BlockId::Tag(BlockTag::Pending));
+    Ok(account)
+}
+
+/// Declare a contract class. If the contract class is already declared, do nothing.
+/// # Arguments
+/// * `account` - The StarkNet account.
+/// * `contract_name` - The contract name.
+/// # Returns
+/// The contract class hash.
+async fn declare_contract(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    contract_name: &str,
+) -> Result<FieldElement> {
+    let contract_artifact = contract_artifact(contract_name)?;
+    let class_hash = contract_artifact.class_hash()?;
+
+    match account.provider().get_class(BlockId::Tag(BlockTag::Pending), class_hash).await {
+        Ok(_) => println!("Not declaring class as it's already declared."),
+        Err(ProviderError::StarknetError(StarknetErrorWithMessage {
+            code: MaybeUnknownErrorCode::Known(StarknetError::ClassHashNotFound),
+            ..
+        })) => {
+            println!("\n==> Declaring Contract: {contract_name}");
+            let flattened_class = contract_artifact.flatten()?;
+            account.declare(Arc::new(flattened_class), class_hash).send().await?;
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    println!("Class Hash: {}", format!("{:#064x}", class_hash));
+    Ok(class_hash)
+}
+
+/// Read an address from an environment variable.
+fn address_from_env(name: &str) -> FieldElement {
+    FieldElement::from_hex_be(&env::var(name).unwrap_or_else(|_| panic!("{name} not set")))
+        .unwrap_or_else(|_| panic!("Invalid {name}"))
+}
+
+/// Read the account configured through `PRIVATE_KEY` and `ACCOUNT_ADDRESS`.
+async fn account_from_env() -> Result<SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>> {
+    // Create signer from private key.
+    let private_key = private_key_from_env_or_input();
+    let signer = LocalWallet::from(SigningKey::from_secret_scalar(private_key));
+
+    // Create a StarkNet account.
+    initialize_starknet_account(signer, address_from_env("ACCOUNT_ADDRESS")).await
+}
+
+/// Parse a decimal command line argument.
+fn parse_arg<T: std::str::FromStr>(args: &[String], index: usize, name: &str) -> Result<T> {
+    args.get(index)
+        .ok_or_else(|| eyre!(USAGE))?
+        .parse()
+        .map_err(|_| eyre!("Invalid {name}: {}", args[index]))
+}
+
+/// Serialize a `u256` whose high part is zero.
+fn u256_calldata(value: u128) -> [FieldElement; 2] {
+    [FieldElement::from(value), FieldElement::ZERO]
+}
+
+/// Deserialize a `u256` returned by a contract call.
+/// # Returns
+/// The value, or an error if it does not fit in a `u128`.
+fn u256_from_result(result: &[FieldElement]) -> Result<u128> {
+    match result {
+        [low, high] if *high == FieldElement::ZERO => {
+            u128::try_from(*low).map_err(|_| eyre!("Invalid u256 low part {low}"))
+        }
+        _ => Err(eyre!("Expected a u256 smaller than 2^128, got {result:?}")),
+    }
+}
+
+/// Call a view function of the faucet or of one of its tokens.
+async fn call<P: Provider>(
+    provider: &P,
+    contract_address: FieldElement,
+    entrypoint: &str,
+    calldata: Vec<FieldElement>,
+) -> Result<Vec<FieldElement>>
+where
+    P::Error: 'static,
+{
+    let function_call =
+        FunctionCall { contract_address, entry_point_selector: get_selector_from_name(entrypoint).unwrap(), calldata };
+    Ok(provider.call(function_call, BlockId::Tag(BlockTag::Latest)).await?)
+}
+
+/// Read the balance of the default token held by the faucet.
+async fn get_amount_faucet<P: Provider>(provider: &P, faucet_address: FieldElement) -> Result<u128>
+where
+    P::Error: 'static,
+{
+    u256_from_result(&call(provider, faucet_address, "get_amount_faucet", vec![]).await?)
+}
+
+/// Read the amount of the default token sent on each drip.
+async fn get_withdrawal_amount<P: Provider>(provider: &P, faucet_address: FieldElement) -> Result<u128>
+where
+    P::Error: 'static,
+{
+    u256_from_result(&call(provider, faucet_address, "get_withdrawal_amount", vec![]).await?)
+}
+
+/// Read the seconds a user has to wait between two drips of the default token.
+async fn get_wait_time<P: Provider>(provider: &P, faucet_address: FieldElement) -> Result<u64>
+where
+    P::Error: 'static,
+{
+    felt_to_u64(call(provider, faucet_address, "get_wait_time", vec![]).await?)
+}
+
+/// Read the timestamp after which `user` can drip the default token again, 0 if it never dripped.
+async fn get_user_unlock_time<P: Provider>(
+    provider: &P,
+    faucet_address: FieldElement,
+    user: FieldElement,
+) -> Result<u64>
+where
+    P::Error: 'static,
+{
+    felt_to_u64(call(provider, faucet_address, "get_user_unlock_time", vec![user]).await?)
+}
+
+fn felt_to_u64(result: Vec<FieldElement>) -> Result<u64> {
+    let value = result.first().ok_or_else(|| eyre!("Empty call result"))?;
+    u64::try_from(*value).map_err(|_| eyre!("Invalid u64 {value}"))
+}
+
+/// Read the timestamp of the latest block, the clock the faucet cooldowns are checked against.
+async fn block_timestamp<P: Provider>(provider: &P) -> Result<u64>
+where
+    P::Error: 'static,
+{
+    Ok(match provider.get_block_with_tx_hashes(BlockId::Tag(BlockTag::Latest)).await? {
+        MaybePendingBlockWithTxHashes::Block(block) => block.timestamp,
+        MaybePendingBlockWithTxHashes::PendingBlock(block) => block.timestamp,
+    })
+}
+
+/// Send a single invoke transaction and print its hash.
+async fn invoke(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    calls: Vec<Call>,
+) -> Result<FieldElement> {
+    let invoke_result = account.execute(calls).send().await?;
+    println!("Transaction Hash: {}", format!("{:#064x}", invoke_result.transaction_hash));
+    Ok(invoke_result.transaction_hash)
+}
+
+fn faucet_call(faucet_address: FieldElement, entrypoint: &str, calldata: Vec<FieldElement>) -> Call {
+    Call { to: faucet_address, selector: get_selector_from_name(entrypoint).unwrap(), calldata }
+}
+
+/// Declare and deploy a faucet dripping `token_address`.
+/// # Arguments
+/// * `account` - The deployer account.
+/// * `owner` - The faucet owner, allowed to configure and empty it.
+/// * `token_address` - The default token of the faucet.
+/// * `withdrawal_amount` - The amount sent to a user on each drip.
+/// * `wait_time` - The seconds a user has to wait between two drips.
+/// # Returns
+/// The faucet address.
+async fn deploy_faucet(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    owner: FieldElement,
+    token_address: FieldElement,
+    withdrawal_amount: u128,
+    wait_time: u64,
+) -> Result<FieldElement> {
+    let class_hash = declare_contract(account, "YASFaucet").await?;
+
+    println!("\n==> Deploying Faucet Contract");
+    let mut calldata = vec![owner, token_address];
+    calldata.extend(u256_calldata(withdrawal_amount));
+    calldata.push(FieldElement::from(wait_time));
+
+    let salt = account.get_nonce().await?;
+    let contract_deployment = ContractFactory::new(class_hash, account).deploy(calldata, salt, true);
+    let faucet_address = contract_deployment.deployed_address();
+    println!("Faucet Contract Address: {}", format!("{:#064x}", faucet_address));
+
+    // Estimate the deployment fee and deploy the contract.
+    let estimated_fee = contract_deployment.estimate_fee().await?.overall_fee * 3 / 2; // add buffer
+    let tx = contract_deployment.max_fee(estimated_fee.into()).send().await?.transaction_hash;
+    println!("Transaction Hash: {}", format!("{:#064x}", tx));
+
+    Ok(faucet_address)
+}
+
+/// Print the faucet balance and settings, and the cooldown of `user` if any.
+async fn print_status<P: Provider>(
+    provider: &P,
+    faucet_address: FieldElement,
+    user: Option<FieldElement>,
+) -> Result<()>
+where
+    P::Error: 'static,
+{
+    let token_address = call(provider, faucet_address, "get_token_address", vec![]).await?;
+    println!("Token: {}", format!("{:#064x}", token_address[0]));
+    println!("Balance: {}", get_amount_faucet(provider, faucet_address).await?);
+    println!("Withdrawal amount: {}", get_withdrawal_amount(provider, faucet_address).await?);
+    println!("Wait time: {}s", get_wait_time(provider, faucet_address).await?);
+
+    let relayer = call(provider, faucet_address, "get_relayer", vec![]).await?;
+    println!("Relayer: {}", format!("{:#064x}", relayer[0]));
+
+    if let Some(user) = user {
+        let unlock_time = get_user_unlock_time(provider, faucet_address, user).await?;
+        let now = block_timestamp(provider).await?;
+        if unlock_time < now {
+            println!("User can drip now");
+        } else {
+            println!("User can drip after {unlock_time} ({}s left)", unlock_time - now + 1);
+        }
+    }
+    Ok(())
+}
+
+/// Drips the faucet to the users requesting it, through a relayer account set with `set_relayer`.
+/// Requests are handled one at a time, so the relayer nonce can be tracked locally and a user
+/// can't get two drips in flight before the first one updates its on-chain cooldown.
+struct DripService {
+    relayer: SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    faucet_address: FieldElement,
+    nonce: Option<FieldElement>,
+    // user -> unlock time of the drips sent by this service, which may not be on-chain yet
+    pending_unlock_times: HashMap<FieldElement, u64>,
+}
+
+impl DripService {
+    async fn serve(&mut self, port: u16) -> Result<()> {
+        let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+        println!("Serving drips of {} on http://127.0.0.1:{port}", format!("{:#064x}", self.faucet_address));
+
+        loop {
+            let (mut stream, _) = listener.accept().await?;
+            if let Err(err) = self.handle_connection(&mut stream).await {
+                eprintln!("Failed to handle request: {err}");
+            }
+        }
+    }
+
+    async fn handle_connection(&mut self, stream: &mut TcpStream) -> Result<()> {
+        let mut buffer = [0; 4096];
+        let read = stream.read(&mut buffer).await?;
+        let request = String::from_utf8_lossy(&buffer[..read]);
+        let request_line = request.lines().next().unwrap_or_default();
+
+        let (status, body) = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+            ["GET", "/status", ..] => self.status().await,
+            ["POST", path, ..] if path.starts_with("/drip/") => match FieldElement::from_hex_be(&path[6..]) {
+                Ok(user) => self.drip(user).await,
+                Err(_) => (400, json!({ "error": "Invalid user address" })),
+            },
+            _ => (404, json!({ "error": "Not found" })),
+        };
+
+        println!("{request_line} -> {status}");
+        let body = body.to_string();
+        let response = format!(
+            "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: \
+             close\r\n\r\n{body}",
+            reason_phrase(status),
+            body.len(),
+        );
+        stream.write_all(response.as_bytes()).await?;
+        Ok(())
+    }
+
+    async fn status(&self) -> (u16, Value) {
+        match self.faucet_state().await {
+            Ok((balance, withdrawal_amount, wait_time)) => (
+                200,
+                json!({
+                    "balance": balance.to_string(),
+                    "withdrawal_amount": withdrawal_amount.to_string(),
+                    "wait_time": wait_time,
+                }),
+            ),
+            Err(err) => (500, json!({ "error": err.to_string() })),
+        }
+    }
+
+    async fn drip(&mut self, user: FieldElement) -> (u16, Value) {
+        match self.try_drip(user).await {
+            Ok(response) => response,
+            Err(err) => {
+                // the transaction may or may not have consumed the nonce, fetch it again
+                self.nonce = None;
+                (500, json!({ "error": err.to_string() }))
+            }
+        }
+    }
+
+    async fn try_drip(&mut self, user: FieldElement) -> Result<(u16, Value)> {
+        let provider = self.relayer.provider();
+        let now = block_timestamp(provider).await?;
+
+        let on_chain_unlock_time = get_user_unlock_time(provider, self.faucet_address, user).await?;
+        let pending_unlock_time = self.pending_unlock_times.get(&user).copied().unwrap_or_default();
+        let unlock_time = on_chain_unlock_time.max(pending_unlock_time);
+        // same check as the faucet: a user is locked until its unlock time has passed
+        if unlock_time != 0 && unlock_time >= now {
+            return Ok((429, json!({ "error": "Not allowed to withdraw yet", "unlock_time": unlock_time })));
+        }
+
+        let (balance, withdrawal_amount, wait_time) = self.faucet_state().await?;
+        if balance <= withdrawal_amount {
+            return Ok((503, json!({ "error": "There is not enough balance", "balance": balance.to_string() })));
+        }
+
+        let nonce = match self.nonce {
+            Some(nonce) => nonce,
+            None => self.relayer.get_nonce().await?,
+        };
+        let call = faucet_call(self.faucet_address, "faucet_mint_to", vec![user]);
+        let invoke_result = self.relayer.execute(vec![call]).nonce(nonce).send().await?;
+        self.nonce = Some(nonce + FieldElement::ONE);
+        self.pending_unlock_times.insert(user, now + wait_time);
+
+        Ok((
+            200,
+            json!({
+                "transaction_hash": format!("{:#064x}", invoke_result.transaction_hash),
+                "amount": withdrawal_amount.to_string(),
+                "remaining_balance": (balance - withdrawal_amount).to_string(),
+                "unlock_time": now + wait_time,
+            }),
+        ))
+    }
+
+    /// Read the balance, withdrawal amount and wait time of the faucet.
+    async fn faucet_state(&self) -> Result<(u128, u128, u64)> {
+        let provider = self.relayer.provider();
+        Ok((
+            get_amount_faucet(provider, self.faucet_address).await?,
+            get_withdrawal_amount(provider, self.faucet_address).await?,
+            get_wait_time(provider, self.faucet_address).await?,
+        ))
+    }
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        429 => "Too Many Requests",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+#[tokio::main]
+pub async fn main() -> Result<()> {
+    dotenv().ok();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = args.first().ok_or_else(|| eyre!(USAGE))?.as_str();
+
+    if command == "deploy" {
+        let token_address = FieldElement::from_hex_be(args.get(1).ok_or_else(|| eyre!(USAGE))?)
+            .map_err(|_| eyre!("Invalid Token Address"))?;
+        let withdrawal_amount = parse_arg(&args, 2, "withdrawal amount")?;
+        let wait_time = parse_arg(&args, 3, "wait time")?;
+
+        let account = account_from_env().await?;
+        let owner = match env::var("OWNER_ADDRESS") {
+            Ok(_) => address_from_env("OWNER_ADDRESS"),
+            Err(_) => account.address(),
+        };
+        deploy_faucet(&account, owner, token_address, withdrawal_amount, wait_time).await?;
+        return Ok(());
+    }
+
+    let faucet_address = address_from_env("FAUCET_ADDRESS");
+
+    match command {
+        "status" => {
+            let user = match args.get(1) {
+                Some(user) => Some(FieldElement::from_hex_be(user).map_err(|_| eyre!("Invalid User Address"))?),
+                None => None,
+            };
+            print_status(&jsonrpc_client(), faucet_address, user).await?;
+        }
+        "configure" => {
+            let withdrawal_amount = parse_arg(&args, 1, "withdrawal amount")?;
+            let wait_time: u64 = parse_arg(&args, 2, "wait time")?;
+
+            let account = account_from_env().await?;
+            println!("\n==> Configuring Faucet");
+            let calls = vec![
+                faucet_call(faucet_address, "set_withdrawal_amount", u256_calldata(withdrawal_amount).to_vec()),
+                faucet_call(faucet_address, "set_wait_time", vec![FieldElement::from(wait_time)]),
+            ];
+            invoke(&account, calls).await?;
+        }
+        "fund" => {
+            let amount = parse_arg(&args, 1, "amount")?;
+
+            let account = account_from_env().await?;
+            let token_address = call(account.provider(), faucet_address, "get_token_address", vec![]).await?[0];
+            println!("\n==> Funding Faucet");
+            let mut calldata = vec![faucet_address];
+            calldata.extend(u256_calldata(amount));
+            let transfer = Call { to: token_address, selector: get_selector_from_name("transfer").unwrap(), calldata };
+            invoke(&account, vec![transfer]).await?;
+        }
+        "set-relayer" => {
+            let relayer = FieldElement::from_hex_be(args.get(1).ok_or_else(|| eyre!(USAGE))?)
+                .map_err(|_| eyre!("Invalid Relayer Address"))?;
+
+            let account = account_from_env().await?;
+            println!("\n==> Setting Relayer");
+            invoke(&account, vec![faucet_call(faucet_address, "set_relayer", vec![relayer])]).await?;
+        }
+        "serve" => {
+            let port = match args.get(1) {
+                Some(_) => parse_arg(&args, 1, "port")?,
+                None => DEFAULT_PORT,
+            };
+
+            // The account sending the drips, it must be the relayer of the faucet.
+            let relayer = account_from_env().await?;
+            let expected_relayer = call(relayer.provider(), faucet_address, "get_relayer", vec![]).await?[0];
+            if expected_relayer != relayer.address() {
+                return Err(eyre!(
+                    "ACCOUNT_ADDRESS is not the faucet relayer, set it with `faucet set-relayer` from the owner"
+                ));
+            }
+
+            let mut service =
+                DripService { relayer, faucet_address, nonce: None, pending_unlock_times: HashMap::new() };
+            service.serve(port).await?;
+        }
+        _ => return Err(eyre!(USAGE)),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use std::env;
+
+use dotenv::dotenv;
+use eyre::{eyre, Result};
+use starknet::accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+use starknet::core::types::{BlockId, BlockTag, FieldElement, FunctionCall};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::{HttpTransport, JsonRpcClient};
+use starknet::providers::Provider;
+use starknet::signers::{LocalWallet, SigningKey};
+
+// TODO: Update to New once account contracts are migrated to v1
+const ENCODING: ExecutionEncoding = ExecutionEncoding::Legacy;
+
+const USAGE: &str = "Usage: pause <status|pause|unpause> [POOL_ADDRESS]
+Without POOL_ADDRESS the command targets every pool through the factory at FACTORY_ADDRESS.";
+
+/// Create a StarkNet provider.
+/// If the `STARKNET_RPC` environment variable is set, it will be used as the RPC URL.
+/// Otherwise, the default URL will be used.
+fn jsonrpc_client() -> JsonRpcClient<HttpTransport> {
+    let rpc_url = env::var("STARKNET_RPC").unwrap_or("https://rpc-goerli-1.starknet.rs/rpc/v0.4".into());
+    JsonRpcClient::new(HttpTransport::new(url::Url::parse(&rpc_url).unwrap()))
+}
+
+/// Fetch the private key from the `PRIVATE_KEY` environment variable or prompt the user for input.
+/// # Returns
+/// The private key.
+fn private_key_from_env_or_input() -> FieldElement {
+    if let Ok(pk) = env::var("PRIVATE_KEY") {
+        FieldElement::from_hex_be(&pk).expect("Invalid Private Key")
+    } else {
+        let input_key = rpassword::prompt_password("Enter private key: ").unwrap();
+        FieldElement::from_hex_be(&input_key).expect("Invalid Private Key")
+    }
+}
+
+/// Initialize a StarkNet account.
+/// # Arguments
+/// * `signer` - The StarkNet signer.
+/// * `account_address` - The StarkNet account address.
+/// # Returns
+/// The StarkNet account.
+async fn initialize_starknet_account(
+    signer: LocalWallet,
+    account_address: FieldElement,
+) -> Result<SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>> {
+    let provider = jsonrpc_client();
+    let chain_id = provider.chain_id().await?;
+    let mut account = SingleOwnerAccount::new(provider, signer, account_address, chain_id, ENCODING);
+    account.set_block_id(BlockId::Tag(BlockTag::Pending));
+    Ok(account)
+}
+
+/// Read a pause flag from a YAS contract.
+/// # Arguments
+/// * `contract_address` - The factory or pool address.
+/// # Returns
+/// `true` if the contract reports itself as paused. For a pool this includes the global pause.
+async fn is_paused(contract_address: FieldElement) -> Result<bool> {
+    let result = jsonrpc_client()
+        .call(
+            FunctionCall {
+                contract_address,
+                entry_point_selector: get_selector_from_name("is_paused").unwrap(),
+                calldata: vec![],
+            },
+            BlockId::Tag(BlockTag::Latest),
+        )
+        .await?;
+
+    Ok(result.first().map_or(false, |paused| *paused != FieldElement::ZERO))
+}
+
+/// Pause or unpause a YAS contract. The account must be the factory guardian.
+/// # Arguments
+/// * `account` - The guardian account.
+/// * `contract_address` - The factory (global pause) or pool address.
+/// * `entrypoint` - Either `pause` or `unpause`.
+async fn set_paused(
+    account: &SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>,
+    contract_address: FieldElement,
+    entrypoint: &str,
+) -> Result<()> {
+    let invoke_result = account
+        .execute(vec![Call {
+            to: contract_address,
+            selector: get_selector_from_name(entrypoint).unwrap(),
+            calldata: vec![],
+        }])
+        .send()
+        .await?;
+
+    println!("Transaction Hash: {}", format!("{:#064x}", invoke_result.transaction_hash));
+    Ok(())
+}
+
+#[tokio::main]
+pub async fn main() -> Result<()> {
+    dotenv().ok();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = args.first().ok_or_else(|| eyre!(USAGE))?.as_str();
+
+    let factory_address = FieldElement::from_hex_be(&env::var("FACTORY_ADDRESS").expect("FACTORY_ADDRESS not set"))
+        .expect("Invalid Factory Address");
+    let target = match args.get(1) {
+        Some(pool) => FieldElement::from_hex_be(pool).expect("Invalid Pool Address"),
+        None => factory_address,
+    };
+
+    match command {
+        "status" => {
+            println!("Factory paused: {}", is_paused(factory_address).await?);
+            if target != factory_address {
+                println!("Pool paused: {}", is_paused(target).await?);
+            }
+        }
+        "pause" | "unpause" => {
+            // Create signer from private key.
+            let private_key = private_key_from_env_or_input();
+            let signer = LocalWallet::from(SigningKey::from_secret_scalar(private_key));
+
+            // Create a StarkNet account.
+            let account_address =
+                FieldElement::from_hex_be(&env::var("ACCOUNT_ADDRESS").expect("ACCOUNT_ADDRESS not set"))
+                    .expect("Invalid Account Address");
+            let account = initialize_starknet_account(signer, account_address).await?;
+
+            println!("\n==> Sending {command} to {}", format!("{:#064x}", target));
+            set_paused(&account, target, command).await?;
+        }
+        _ => return Err(eyre!(USAGE)),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+use eyre::{eyre, Result};
+use starknet::core::crypto::compute_hash_on_elements;
+use starknet::core::types::FieldElement;
+use starknet::core::utils::cairo_short_string_to_felt;
+use starknet::signers::SigningKey;
+
+/// Prefix of every SNIP-12 message hash.
+const STARKNET_MESSAGE: &str = "StarkNet Message";
+/// `sn_keccak("StarkNetDomain(name:felt,version:felt,chainId:felt)")`
+const STARKNET_DOMAIN_TYPE_HASH: &str = "0x1bfc207425a47a5dfa1a50a4f5241203f50624ca5fdf5e18755765416b8e288";
+/// `sn_keccak("u256(low:felt,high:felt)")`
+const U256_TYPE_HASH: &str = "0x2ee86241508f9ca7043fb572033e45c445012a8dbb2b929391d37fc44fbfceb";
+/// `sn_keccak("Permit(spender:felt,token_id:u256,nonce:felt,deadline:u256,verifying_contract:felt)u256(low:felt,high:felt)")`
+const NFT_PERMIT_TYPE_HASH: &str = "0x14929d60210e9e5c33c19e586e57dcba84b3be8ff734890381570b11867bf03";
+/// `sn_keccak("Permit(spender:felt,value:u256,nonce:felt,deadline:u256,verifying_contract:felt)u256(low:felt,high:felt)")`
+const ERC20_PERMIT_TYPE_HASH: &str = "0x1d9f943c233387a5fff70487d33144ed93a639fa31efbc4f693bc3b5170c999";
+
+/// Name of the `YASNFTPositionManager` token, used as the name of its permit domain.
+pub const NFT_POSITION_MANAGER_NAME: &str = "YAS Positions NFT-V1";
+/// Version of the permit domain of the YAS contracts.
+pub const PERMIT_VERSION: &str = "1";
+
+/// SNIP-12 domain separating the signed messages of a contract from any other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StarknetDomain {
+    pub name: FieldElement,
+    pub version: FieldElement,
+    pub chain_id: FieldElement,
+}
+
+impl StarknetDomain {
+    /// Build a domain from its short string name and version.
+    /// # Arguments
+    /// * `name` - The domain name, at most 31 ASCII characters.
+    /// * `version` - The domain version, at most 31 ASCII characters.
+    /// * `chain_id` - The chain the signature is valid on, e.g. `chain_id::TESTNET`.
+    /// # Returns
+    /// The domain, or an error if `name` or `version` is not a valid short string.
+    pub fn new(name: &str, version: &str, chain_id: FieldElement) -> Result<Self> {
+        Ok(Self { name: short_string(name)?, version: short_string(version)?, chain_id })
+    }
+
+    /// Hash the domain the way `TypedData::hash_domain` does.
+    pub fn hash(&self) -> FieldElement {
+        compute_hash_on_elements(&[felt(STARKNET_DOMAIN_TYPE_HASH), self.name, self.version, self.chain_id])
+    }
+}
+
+/// Approval of `spender` to manage and transfer the YAS position `token_id`, see `YASNFTPositionManager::permit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NftPermit {
+    pub spender: FieldElement,
+    /// The position to permit. The contract takes a `u256`, this is its low part: the high part is
+    /// always signed and sent as zero, so ids above `u128::MAX` can't be permitted.
+    pub token_id: u128,
+    /// The current `nonces(token_id)` of the position manager.
+    pub nonce: FieldElement,
+    /// The timestamp after which the permit is no longer valid. Signed and sent as a `u256` whose
+    /// high part is zero.
+    pub deadline: u64,
+    /// The address of the position manager the permit is submitted to.
+    pub verifying_contract: FieldElement,
+}
+
+impl NftPermit {
+    /// Hash the permit struct the way `YASNFTPositionManager` does.
+    pub fn struct_hash(&self) -> FieldElement {
+        compute_hash_on_elements(&[
+            felt(NFT_PERMIT_TYPE_HASH),
+            self.spender,
+            hash_u256(self.token_id),
+            self.nonce,
+            hash_u256(self.deadline.into()),
+            self.verifying_contract,
+        ])
+    }
+}
+
+/// Allowance of `spender` over the tokens of the signer, see `ERC20::permit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Erc20Permit {
+    pub spender: FieldElement,
+    /// The allowance. Signed as a `u256` whose high part is zero, so it is at most `u128::MAX`.
+    pub value: u128,
+    /// The current `nonces(owner)` of the token.
+    pub nonce: FieldElement,
+    /// The timestamp after which the permit is no longer valid. Signed as a `u256` whose high part
+    /// is zero.
+    pub deadline: u64,
+    /// The address of the token the permit is submitted to.
+    pub verifying_contract: FieldElement,
+}
+
+impl Erc20Permit {
+    /// Hash the permit struct the way `ERC20` does.
+    pub fn struct_hash(&self) -> FieldElement {
+        compute_hash_on_elements(&[
+            felt(ERC20_PERMIT_TYPE_HASH),
+            self.spender,
+            hash_u256(self.value),
+            self.nonce,
+            hash_u256(self.deadline.into()),
+            self.verifying_contract,
+        ])
+    }
+}
+
+/// Hash a `u256` whose high part is zero, the way `TypedData::hash_u256` does.
+/// The permit amounts, ids and deadlines are typed so that their high part is always zero.
+pub fn hash_u256(value: u128) -> FieldElement {
+    compute_hash_on_elements(&[felt(U256_TYPE_HASH), FieldElement::from(value), FieldElement::ZERO])
+}
+
+/// Compute the hash an account signs to authorize a message, the way `TypedData::hash_message` does.
+/// # Arguments
+/// * `domain` - The domain of the contract verifying the message.
+/// * `account` - The address of the signing account.
+/// * `struct_hash` - The hash of the message struct.
+/// # Returns
+/// The message hash.
+pub fn message_hash(domain: &StarknetDomain, account: FieldElement, struct_hash: FieldElement) -> FieldElement {
+    compute_hash_on_elements(&[short_string(STARKNET_MESSAGE).unwrap(), domain.hash(), account, struct_hash])
+}
+
+/// Sign a position permit for `YASNFTPositionManager::permit`.
+/// The signature is a plain `[r, s]` stark curve signature, as expected by OpenZeppelin and Argent
+/// accounts whose single owner is `signing_key`.
+/// # Arguments
+/// * `signing_key` - The private key of the account owning the position.
+/// * `owner` - The address of the account owning the position.
+/// * `chain_id` - The chain the position manager is deployed on.
+/// * `permit` - The permit to sign.
+/// # Returns
+/// The signature, to be passed as the `signature` argument of `permit`.
+pub fn sign_nft_permit(
+    signing_key: &SigningKey,
+    owner: FieldElement,
+    chain_id: FieldElement,
+    permit: &NftPermit,
+) -> Result<Vec<FieldElement>> {
+    let domain = StarknetDomain::new(NFT_POSITION_MANAGER_NAME, PERMIT_VERSION, chain_id)?;
+    let hash = message_hash(&domain, owner, permit.struct_hash());
+    let signature = signing_key.sign(&hash).map_err(|e| eyre!("Failed to sign permit: {e}"))?;
+    Ok(vec![signature.r, signature.s])
+}
+
+/// Sign a token permit for `ERC20::permit`, or for `YASRouter::self_permit` with the router as spender.
+/// # Arguments
+/// * `signing_key` - The private key of the account owning the tokens.
+/// * `owner` - The address of the account owning the tokens.
+/// * `token_name` - The `name()` of the token, used as the name of its permit domain.
+/// * `chain_id` - The chain the token is deployed on.
+/// * `permit` - The permit to sign.
+/// # Returns
+/// The signature, to be passed as the `signature` argument of `permit` or `self_permit`.
+pub fn sign_erc20_permit(
+    signing_key: &SigningKey,
+    owner: FieldElement,
+    token_name: &str,
+    chain_id: FieldElement,
+    permit: &Erc20Permit,
+) -> Result<Vec<FieldElement>> {
+    let domain = StarknetDomain::new(token_name, PERMIT_VERSION, chain_id)?;
+    let hash = message_hash(&domain, owner, permit.struct_hash());
+    let signature = signing_key.sign(&hash).map_err(|e| eyre!("Failed to sign permit: {e}"))?;
+    Ok(vec![signature.r, signature.s])
+}
+
+/// Build the calldata of `YASNFTPositionManager::permit`.
+/// # Arguments
+/// * `permit` - The signed permit.
+/// * `signature` - The signature returned by `sign_nft_permit`.
+/// # Returns
+/// The calldata: `[spender, token_id.low, token_id.high, deadline.low, deadline.high, signature_len, ...signature]`.
+pub fn nft_permit_calldata(permit: &NftPermit, signature: &[FieldElement]) -> Vec<FieldElement> {
+    let mut calldata = vec![
+        permit.spender,
+        FieldElement::from(permit.token_id),
+        FieldElement::ZERO,
+        FieldElement::from(permit.deadline),
+        FieldElement::ZERO,
+        FieldElement::from(signature.len()),
+    ];
+    calldata.extend_from_slice(signature);
+    calldata
+}
+
+fn short_string(value: &str) -> Result<FieldElement> {
+    cairo_short_string_to_felt(value).map_err(|e| eyre!("Invalid short string {value:?}: {e}"))
+}
+
+fn felt(hex: &str) -> FieldElement {
+    FieldElement::from_hex_be(hex).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet::core::chain_id;
+
+    use super::*;
+
+    // Same permit as `test_permit_message_hash_matches_the_offline_hash` in the Cairo position manager tests,
+    // which pins this hash for a position manager deployed at `NFT_POSITION_MANAGER` on `SN_GOERLI`.
+    const MESSAGE_HASH: &str = "0x24c0233f8bb118013c630e0e7e797c7fe9858a683dc4a5564581a0ff6796af0";
+
+    #[test]
+    fn nft_permit_message_hash_matches_the_position_manager() {
+        let domain = StarknetDomain::new(NFT_POSITION_MANAGER_NAME, PERMIT_VERSION, chain_id::TESTNET).unwrap();
+        let permit = NftPermit {
+            spender: short_string("SPENDER").unwrap(),
+            token_id: 1,
+            nonce: FieldElement::ZERO,
+            deadline: 1700000000,
+            verifying_contract: short_string("NFT_POSITION_MANAGER").unwrap(),
+        };
+
+        let hash = message_hash(&domain, short_string("OWNER").unwrap(), permit.struct_hash());
+        assert_eq!(hash, felt(MESSAGE_HASH));
+    }
+}
@@ -0,0 +1,138 @@
+use starknet::core::types::FieldElement;
+use starknet::core::utils::get_contract_address;
+use starknet_crypto::poseidon_hash_many;
+
+const POSITIVE: bool = false;
+const NEGATIVE: bool = true;
+
+/// Key identifying a YAS pool: the token pair, the fee and the tick spacing the fee is enabled with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolKey {
+    pub token_0: FieldElement,
+    pub token_1: FieldElement,
+    pub fee: u32,
+    pub tick_spacing: i32,
+}
+
+impl PoolKey {
+    /// Build a pool key, sorting the tokens the same way `YASFactory::create_pool` does.
+    /// # Arguments
+    /// * `token_a` - One of the two tokens of the pool.
+    /// * `token_b` - The other token of the pool.
+    /// * `fee` - The fee of the pool, in hundredths of a bip.
+    /// * `tick_spacing` - The tick spacing enabled in the factory for `fee`.
+    /// # Returns
+    /// The pool key, with `token_0 < token_1`.
+    pub fn new(token_a: FieldElement, token_b: FieldElement, fee: u32, tick_spacing: i32) -> Self {
+        let (token_0, token_1) = if token_a < token_b { (token_a, token_b) } else { (token_b, token_a) };
+        Self { token_0, token_1, fee, tick_spacing }
+    }
+}
+
+/// Compute the salt `YASFactory::create_pool` deploys a pool with.
+/// # Arguments
+/// * `key` - The pool key.
+/// # Returns
+/// The poseidon hash of the serialized pool key: `[token_0, token_1, fee, tick_spacing.mag, tick_spacing.sign]`.
+pub fn pool_salt(key: &PoolKey) -> FieldElement {
+    let mut data = vec![key.token_0, key.token_1, FieldElement::from(key.fee)];
+    data.extend(serialize_i32(key.tick_spacing));
+    poseidon_hash_many(&data)
+}
+
+/// Serialize the pool constructor calldata the same way `YASFactory::create_pool` does.
+/// # Arguments
+/// * `factory` - The YAS factory address, passed to the pool as its factory.
+/// * `key` - The pool key.
+/// # Returns
+/// The constructor calldata: `[factory, token_0, token_1, fee, tick_spacing.mag, tick_spacing.sign]`.
+pub fn pool_constructor_calldata(factory: FieldElement, key: &PoolKey) -> Vec<FieldElement> {
+    let mut calldata = vec![factory, key.token_0, key.token_1, FieldElement::from(key.fee)];
+    calldata.extend(serialize_i32(key.tick_spacing));
+    calldata
+}
+
+/// Serialize an `i32` the way the Cairo `i32` type does: magnitude followed by the sign flag.
+fn serialize_i32(value: i32) -> [FieldElement; 2] {
+    let sign = if value < 0 { NEGATIVE } else { POSITIVE };
+    [FieldElement::from(value.unsigned_abs()), FieldElement::from(sign as u8)]
+}
+
+/// Compute the address of a YAS pool offline, without querying the factory.
+/// The factory deploys pools with `deploy_from_zero = false`, so the deployer is the factory itself.
+/// # Arguments
+/// * `factory` - The YAS factory address.
+/// * `pool_class_hash` - The pool class hash of the factory when the pool was created. For a pool not created yet,
+///   the current `pool_class_hash()`.
+/// * `key` - The pool key.
+/// # Returns
+/// The address the factory deploys (or deployed) the pool at.
+pub fn compute_pool_address(factory: FieldElement, pool_class_hash: FieldElement, key: &PoolKey) -> FieldElement {
+    get_contract_address(pool_salt(key), pool_class_hash, &pool_constructor_calldata(factory, key), factory)
+}
+
+/// Check that `pool` is the genuine YAS pool for `key`.
+/// The pool address depends on the class hash the factory deployed it from, so every class hash the factory ever
+/// used is tried: pools created before a `set_pool_class_hash` keep the address of the previous class.
+/// # Arguments
+/// * `pool` - The address to verify.
+/// * `factory` - The YAS factory address.
+/// * `pool_class_hashes` - The class hashes returned by the factory `pool_class_hashes()`.
+/// * `key` - The pool key `pool` claims to belong to.
+/// # Returns
+/// `true` if `pool` is the address the factory deployed the pool for `key` at, `false` otherwise.
+pub fn is_genuine_pool(
+    pool: FieldElement,
+    factory: FieldElement,
+    pool_class_hashes: &[FieldElement],
+    key: &PoolKey,
+) -> bool {
+    pool_class_hashes.iter().any(|class_hash| compute_pool_address(factory, *class_hash, key) == pool)
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet::core::utils::cairo_short_string_to_felt;
+
+    use super::*;
+
+    // Same pool key as `test_deploys_the_pool_at_the_offline_address` in the Cairo factory tests, which pins
+    // this salt and checks that `create_pool` deploys the pool at the address derived like below.
+    const SALT: &str = "0x6096017e1fc867b9b7fb94ad38660c217ecda43b66deceae8a098f5e57adf77";
+    const POOL: &str = "0x921f48afbd5fd5072b6c2bd1919f50146c12afa7be23a7aa8b0c1bee8fe760";
+
+    fn short_string(value: &str) -> FieldElement {
+        cairo_short_string_to_felt(value).unwrap()
+    }
+
+    fn key() -> PoolKey {
+        PoolKey::new(short_string("B"), short_string("A"), 3000, 60)
+    }
+
+    #[test]
+    fn pool_salt_matches_the_factory() {
+        assert_eq!(pool_salt(&key()), FieldElement::from_hex_be(SALT).unwrap());
+    }
+
+    #[test]
+    fn compute_pool_address_matches_the_factory() {
+        let address = compute_pool_address(short_string("FACTORY"), short_string("POOL_CLASS_HASH"), &key());
+        assert_eq!(address, FieldElement::from_hex_be(POOL).unwrap());
+    }
+
+    #[test]
+    fn is_genuine_pool_tries_every_class_hash() {
+        let pool = FieldElement::from_hex_be(POOL).unwrap();
+        let factory = short_string("FACTORY");
+        let class_hashes = [short_string("POOL_CLASS_HASH"), short_string("POOL_CLASS_HASH_V2")];
+
+        assert!(is_genuine_pool(pool, factory, &class_hashes, &key()));
+        assert!(!is_genuine_pool(pool, factory, &class_hashes[1..], &key()));
+        assert!(!is_genuine_pool(
+            pool,
+            factory,
+            &class_hashes,
+            &PoolKey::new(short_string("A"), short_string("B"), 500, 10)
+        ));
+    }
+}
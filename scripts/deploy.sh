@@ -28,6 +28,14 @@ echo -e "$GREEN\n==> Declaring YASNFTPositionManager$RESET"
 NFT_POSITION_MANAGER_CLASS_HASH=$(starkli declare --watch --private-key $ACCOUNT_PRIVATE_KEY ./target/dev/yas_periphery_YASNFTPositionManager.sierra.json)
 echo -e $GREEN$POOL_CLASS_HASH$RESET
 
+echo -e "$GREEN\n==> Declaring YASQuoter$RESET"
+QUOTER_CLASS_HASH=$(starkli declare --watch --private-key $ACCOUNT_PRIVATE_KEY ./target/dev/yas_periphery_YASQuoter.sierra.json)
+echo -e $GREEN$QUOTER_CLASS_HASH$RESET
+
+echo -e "$GREEN\n==> Declaring YASTickLens$RESET"
+TICK_LENS_CLASS_HASH=$(starkli declare --watch --private-key $ACCOUNT_PRIVATE_KEY ./target/dev/yas_periphery_YASTickLens.sierra.json)
+echo -e $GREEN$TICK_LENS_CLASS_HASH$RESET
+
 echo -e "$GREEN\n==> Deploying Factory$RESET"
 FACTORY_ADDRESS=$(starkli deploy --watch $FACTORY_CLASS_HASH --private-key $ACCOUNT_PRIVATE_KEY \
 	$ACCOUNT_ADDRESS \
@@ -35,9 +43,18 @@ FACTORY_ADDRESS=$(starkli deploy --watch $FACTORY_CLASS_HASH --private-key $ACCO
 echo -e $GREEN$FACTORY_ADDRESS$RESET
 
 echo -e "$GREEN\n==> Deploying Router$RESET"
-ROUTER_ADDRESS=$(starkli deploy --watch $ROUTER_CLASS_HASH --private-key $ACCOUNT_PRIVATE_KEY)
+ROUTER_ADDRESS=$(starkli deploy --watch $ROUTER_CLASS_HASH --private-key $ACCOUNT_PRIVATE_KEY \
+	$FACTORY_ADDRESS)
 echo -e $GREEN$ROUTER_ADDRESS$RESET
 
 echo -e "$GREEN\n==> Deploying YASNFTPositionManager$RESET"
 NFT_POSITION_MANAGER_ADDRESS=$(starkli deploy --watch $NFT_POSITION_MANAGER_CLASS_HASH $FACTORY_ADDRESS --private-key $ACCOUNT_PRIVATE_KEY)
 echo -e $GREEN$NFT_POSITION_MANAGER_ADDRESS$RESET
+
+echo -e "$GREEN\n==> Deploying YASQuoter$RESET"
+QUOTER_ADDRESS=$(starkli deploy --watch $QUOTER_CLASS_HASH $FACTORY_ADDRESS --private-key $ACCOUNT_PRIVATE_KEY)
+echo -e $GREEN$QUOTER_ADDRESS$RESET
+
+echo -e "$GREEN\n==> Deploying YASTickLens$RESET"
+TICK_LENS_ADDRESS=$(starkli deploy --watch $TICK_LENS_CLASS_HASH --private-key $ACCOUNT_PRIVATE_KEY)
+echo -e $GREEN$TICK_LENS_ADDRESS$RESET
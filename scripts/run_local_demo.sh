@@ -47,24 +47,30 @@ echo -e $GREEN$POOL_CLASS_HASH$RESET
 echo -e "$GREEN\n==> Deploying TYAS0 token$RESET"
 # name: TYAS0
 # symbol: $YAS0
+# decimals: 18
 # supply: 4000000000000000000
-# recipent: Katana account
+# recipent and owner: Katana account
 TOKEN_0_ADDRESS=$(starkli deploy --watch $ERC20_CLASS_HASH --private-key $KATANA_PRIVATE_KEY \
     362274706224 \
     156116276016 \
+    18 \
     u256:4000000000000000000 \
+    $KATANA_ACCOUNT_ADDRESS \
     $KATANA_ACCOUNT_ADDRESS)
 echo -e $GREEN$TOKEN_0_ADDRESS$RESET
 
 echo -e "$GREEN\n==> Deploying TYAS1 token$RESET"
 # name: TYAS1
 # symbol: $YAS1
+# decimals: 18
 # supply: 4000000000000000000
-# recipent: Katana account
+# recipent and owner: Katana account
 TOKEN_1_ADDRESS=$(starkli deploy --watch $ERC20_CLASS_HASH --private-key $KATANA_PRIVATE_KEY \
     362274706225 \
     156116276017 \
+    18 \
     u256:4000000000000000000 \
+    $KATANA_ACCOUNT_ADDRESS \
     $KATANA_ACCOUNT_ADDRESS)
 echo -e $GREEN$TOKEN_1_ADDRESS$RESET
 
@@ -75,16 +81,16 @@ FACTORY_ADDRESS=$(starkli deploy --watch $FACTORY_CLASS_HASH --private-key $KATA
 echo -e $GREEN$FACTORY_ADDRESS$RESET
 
 echo -e "$GREEN\n==> Deploying Router$RESET"
-ROUTER_ADDRESS=$(starkli deploy --watch $ROUTER_CLASS_HASH --private-key $KATANA_PRIVATE_KEY)
+ROUTER_ADDRESS=$(starkli deploy --watch $ROUTER_CLASS_HASH --private-key $KATANA_PRIVATE_KEY \
+	$FACTORY_ADDRESS)
 echo -e $GREEN$ROUTER_ADDRESS$RESET
 
-echo -e "$GREEN\n==> Deploying Pool$RESET"
-POOL_ADDRESS=$(starkli deploy --watch $POOL_CLASS_HASH --private-key $KATANA_PRIVATE_KEY \
-    $FACTORY_ADDRESS \
+echo -e "$GREEN\n==> Creating Pool$RESET"
+starkli invoke --watch --private-key $KATANA_PRIVATE_KEY $FACTORY_ADDRESS create_pool \
     $TOKEN_0_ADDRESS \
     $TOKEN_1_ADDRESS \
-    3000 \
-    60 0 )
+    3000
+POOL_ADDRESS=$(starkli call $FACTORY_ADDRESS pool $TOKEN_0_ADDRESS $TOKEN_1_ADDRESS 3000 | grep -o '0x[0-9a-fA-F]*')
 echo -e $GREEN$POOL_ADDRESS$RESET
 
 echo -e "$GREEN\n==> Initialize Pool$RESET"